@@ -50,6 +50,37 @@ pub enum BlackBoxFunc {
     /// Compute a recursive aggregation object when verifying a proof inside another circuit.
     /// This outputted aggregation object will then be either checked in a top-level verifier or aggregated upon again.
     RecursiveAggregation,
+    /// Applies the Poseidon2 permutation function to the given state,
+    /// outputting the permuted state.
+    Poseidon2Permutation,
+    /// Adds two points of the embedded curve on which [`FieldElement`][acir_field::FieldElement] is defined.
+    EmbeddedCurveAdd,
+    /// Doubles a point of the embedded curve on which [`FieldElement`][acir_field::FieldElement] is defined.
+    EmbeddedCurveDouble,
+    /// Applies the Keccak-f[1600] permutation to a state of 25 lanes of 64 bits.
+    Keccakf1600,
+    /// Applies the SHA-256 compression function to a block of 16 words of 32 bits,
+    /// starting from a state of 8 words of 32 bits.
+    Sha256Compression,
+    /// Adds two big integers, which must share the same modulus.
+    BigIntAdd,
+    /// Subtracts a big integer from another, which must share the same modulus.
+    BigIntSub,
+    /// Multiplies two big integers, which must share the same modulus.
+    BigIntMul,
+    /// Divides a big integer by another, which must share the same modulus and be invertible modulo it.
+    BigIntDiv,
+    /// Creates a big integer from its little-endian bytes, reduced modulo the given modulus.
+    BigIntFromLeBytes,
+    /// Returns the little-endian bytes of a big integer.
+    BigIntToLeBytes,
+    /// Recovers the public key which produced an ECDSA signature over the secp256k1 curve.
+    ///
+    /// This has no ACIR opcode and is only available in Brillig: the recovered key should be
+    /// constrained with [`BlackBoxFunc::EcdsaSecp256k1`].
+    EcdsaSecp256k1Recover,
+    /// Calculates the Blake3 hash of the inputs.
+    Blake3,
 }
 
 impl std::fmt::Display for BlackBoxFunc {
@@ -75,6 +106,19 @@ impl BlackBoxFunc {
             BlackBoxFunc::Keccak256 => "keccak256",
             BlackBoxFunc::RecursiveAggregation => "recursive_aggregation",
             BlackBoxFunc::EcdsaSecp256r1 => "ecdsa_secp256r1",
+            BlackBoxFunc::Poseidon2Permutation => "poseidon2_permutation",
+            BlackBoxFunc::EmbeddedCurveAdd => "ec_add",
+            BlackBoxFunc::EmbeddedCurveDouble => "ec_double",
+            BlackBoxFunc::Keccakf1600 => "keccakf1600",
+            BlackBoxFunc::Sha256Compression => "sha256_compression",
+            BlackBoxFunc::BigIntAdd => "bigint_add",
+            BlackBoxFunc::BigIntSub => "bigint_sub",
+            BlackBoxFunc::BigIntMul => "bigint_mul",
+            BlackBoxFunc::BigIntDiv => "bigint_div",
+            BlackBoxFunc::BigIntFromLeBytes => "bigint_from_le_bytes",
+            BlackBoxFunc::BigIntToLeBytes => "bigint_to_le_bytes",
+            BlackBoxFunc::EcdsaSecp256k1Recover => "ecdsa_secp256k1_recover",
+            BlackBoxFunc::Blake3 => "blake3",
         }
     }
     pub fn lookup(op_name: &str) -> Option<BlackBoxFunc> {
@@ -93,6 +137,19 @@ impl BlackBoxFunc {
             "range" => Some(BlackBoxFunc::RANGE),
             "keccak256" => Some(BlackBoxFunc::Keccak256),
             "recursive_aggregation" => Some(BlackBoxFunc::RecursiveAggregation),
+            "poseidon2_permutation" => Some(BlackBoxFunc::Poseidon2Permutation),
+            "ec_add" => Some(BlackBoxFunc::EmbeddedCurveAdd),
+            "ec_double" => Some(BlackBoxFunc::EmbeddedCurveDouble),
+            "keccakf1600" => Some(BlackBoxFunc::Keccakf1600),
+            "sha256_compression" => Some(BlackBoxFunc::Sha256Compression),
+            "bigint_add" => Some(BlackBoxFunc::BigIntAdd),
+            "bigint_sub" => Some(BlackBoxFunc::BigIntSub),
+            "bigint_mul" => Some(BlackBoxFunc::BigIntMul),
+            "bigint_div" => Some(BlackBoxFunc::BigIntDiv),
+            "bigint_from_le_bytes" => Some(BlackBoxFunc::BigIntFromLeBytes),
+            "bigint_to_le_bytes" => Some(BlackBoxFunc::BigIntToLeBytes),
+            "ecdsa_secp256k1_recover" => Some(BlackBoxFunc::EcdsaSecp256k1Recover),
+            "blake3" => Some(BlackBoxFunc::Blake3),
             _ => None,
         }
     }
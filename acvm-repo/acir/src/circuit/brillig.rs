@@ -1,6 +1,9 @@
 use crate::native_types::{Expression, Witness};
+use base64::Engine;
 use brillig::Opcode as BrilligOpcode;
-use serde::{Deserialize, Serialize};
+use flate2::Compression;
+use serde::{de::Error as DeserializationError, Deserialize, Deserializer, Serialize, Serializer};
+use std::io::prelude::*;
 
 /// Inputs for the Brillig VM. These are the initial inputs
 /// that the Brillig VM will use to start.
@@ -27,3 +30,55 @@ pub struct Brillig {
     /// Predicate of the Brillig execution - indicates if it should be skipped
     pub predicate: Option<Expression>,
 }
+
+/// The bytecode of an unconstrained function which is executed by [BrilligCall][super::Opcode::BrilligCall] opcodes.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct BrilligBytecode {
+    pub bytecode: Vec<BrilligOpcode>,
+}
+
+impl BrilligBytecode {
+    pub fn serialize_functions(functions: &[BrilligBytecode]) -> Vec<u8> {
+        let buf = bincode::serialize(functions).unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&buf).expect("expected unconstrained functions to be serializable");
+        encoder.finish().expect("expected unconstrained functions to be serializable")
+    }
+
+    pub fn deserialize_functions(
+        serialized_functions: &[u8],
+    ) -> std::io::Result<Vec<BrilligBytecode>> {
+        let mut gz_decoder = flate2::read::GzDecoder::new(serialized_functions);
+        let mut buf_d = Vec::new();
+        gz_decoder.read_to_end(&mut buf_d)?;
+        bincode::deserialize(&buf_d)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))
+    }
+
+    // Serialize and base64 encode a list of unconstrained functions
+    pub fn serialize_functions_base64<S>(
+        functions: &[BrilligBytecode],
+        s: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let functions_bytes = BrilligBytecode::serialize_functions(functions);
+        let encoded_b64 = base64::engine::general_purpose::STANDARD.encode(functions_bytes);
+        s.serialize_str(&encoded_b64)
+    }
+
+    // Deserialize and base64 decode a list of unconstrained functions
+    pub fn deserialize_functions_base64<'de, D>(
+        deserializer: D,
+    ) -> Result<Vec<BrilligBytecode>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytecode_b64: String = serde::Deserialize::deserialize(deserializer)?;
+        let functions_bytes = base64::engine::general_purpose::STANDARD
+            .decode(bytecode_b64)
+            .map_err(D::Error::custom)?;
+        BrilligBytecode::deserialize_functions(&functions_bytes).map_err(D::Error::custom)
+    }
+}
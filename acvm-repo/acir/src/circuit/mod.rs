@@ -174,6 +174,38 @@ impl Circuit {
         let circuit = Self::deserialize_circuit(&circuit_bytes).map_err(D::Error::custom)?;
         Ok(circuit)
     }
+
+    // Serialize and base64 encode each of a list of circuits
+    pub fn serialize_circuits_base64<S>(circuits: &[Circuit], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded_b64: Vec<String> = circuits
+            .iter()
+            .map(|circuit| {
+                let circuit_bytes = Circuit::serialize_circuit(circuit);
+                base64::engine::general_purpose::STANDARD.encode(circuit_bytes)
+            })
+            .collect();
+        encoded_b64.serialize(s)
+    }
+
+    // Deserialize and base64 decode each of a list of circuits
+    pub fn deserialize_circuits_base64<'de, D>(deserializer: D) -> Result<Vec<Circuit>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytecodes_b64: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+        bytecodes_b64
+            .into_iter()
+            .map(|bytecode_b64| {
+                let circuit_bytes = base64::engine::general_purpose::STANDARD
+                    .decode(bytecode_b64)
+                    .map_err(D::Error::custom)?;
+                Self::deserialize_circuit(&circuit_bytes).map_err(D::Error::custom)
+            })
+            .collect()
+    }
 }
 
 impl std::fmt::Display for Circuit {
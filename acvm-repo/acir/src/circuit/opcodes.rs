@@ -1,5 +1,5 @@
 use super::{
-    brillig::Brillig,
+    brillig::{Brillig, BrilligInputs, BrilligOutputs},
     directives::{Directive, QuotientDirective},
 };
 use crate::native_types::{Expression, Witness};
@@ -31,6 +31,29 @@ pub enum Opcode {
         block_id: BlockId,
         init: Vec<Witness>,
     },
+    /// Calls to another ACIR function of the program, which is solved as a separate circuit.
+    Call {
+        /// Index of the called function within the program's list of functions.
+        id: u32,
+        /// Witnesses which are passed to the function's parameters, in order.
+        inputs: Vec<Witness>,
+        /// Witnesses which are assigned the function's return values, in order.
+        outputs: Vec<Witness>,
+        /// Predicate of the call - the function is not executed and its outputs are zero if this is zero.
+        predicate: Option<Expression>,
+    },
+    /// Executes an unconstrained function whose bytecode is shared between all the opcodes which call it.
+    ///
+    /// This behaves as a [Brillig][Opcode::Brillig] opcode whose bytecode is looked up in the program's
+    /// list of unconstrained functions.
+    BrilligCall {
+        /// Index of the called function within the program's list of unconstrained functions.
+        id: u32,
+        inputs: Vec<BrilligInputs>,
+        outputs: Vec<BrilligOutputs>,
+        /// Predicate of the Brillig execution - indicates if it should be skipped
+        predicate: Option<Expression>,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -59,6 +82,8 @@ impl Opcode {
             Opcode::Brillig(_) => "brillig",
             Opcode::MemoryOp { .. } => "mem",
             Opcode::MemoryInit { .. } => "init memory block",
+            Opcode::Call { .. } => "call",
+            Opcode::BrilligCall { .. } => "brillig call",
         }
     }
 
@@ -168,6 +193,22 @@ impl std::fmt::Display for Opcode {
                 write!(f, "INIT ")?;
                 write!(f, "(id: {}, len: {}) ", block_id.0, init.len())
             }
+            Opcode::Call { id, inputs, outputs, predicate } => {
+                write!(f, "CALL func {id}: ")?;
+                if let Some(pred) = predicate {
+                    writeln!(f, "PREDICATE = {pred}")?;
+                }
+                write!(f, "inputs: {inputs:?}, ")?;
+                write!(f, "outputs: {outputs:?}")
+            }
+            Opcode::BrilligCall { id, inputs, outputs, predicate } => {
+                write!(f, "BRILLIG CALL func {id}: ")?;
+                if let Some(pred) = predicate {
+                    writeln!(f, "PREDICATE = {pred}")?;
+                }
+                writeln!(f, "inputs: {inputs:?}")?;
+                write!(f, "outputs: {outputs:?}")
+            }
         }
     }
 }
@@ -117,6 +117,89 @@ pub enum BlackBoxFuncCall {
         /// will be the input aggregation object of the next recursive aggregation.
         output_aggregation_object: Vec<Witness>,
     },
+    /// Applies the Poseidon2 permutation function to the given state,
+    /// outputting the permuted state.
+    Poseidon2Permutation {
+        /// Input state for the permutation of Poseidon2
+        inputs: Vec<FunctionInput>,
+        /// Permuted state
+        outputs: Vec<Witness>,
+        /// State length (in number of field elements)
+        /// It is the length of inputs and outputs vectors
+        len: u32,
+    },
+    /// Adds two points of the embedded curve, outputting the coordinates of their sum.
+    /// The point at infinity is represented by the coordinates `(0, 0)`.
+    EmbeddedCurveAdd {
+        input1_x: FunctionInput,
+        input1_y: FunctionInput,
+        input2_x: FunctionInput,
+        input2_y: FunctionInput,
+        outputs: (Witness, Witness),
+    },
+    /// Doubles a point of the embedded curve, outputting the coordinates of the result.
+    /// The point at infinity is represented by the coordinates `(0, 0)`.
+    EmbeddedCurveDouble {
+        input_x: FunctionInput,
+        input_y: FunctionInput,
+        outputs: (Witness, Witness),
+    },
+    /// Applies the Keccak-f[1600] permutation to the 25 lanes of 64 bits of the state,
+    /// outputting the permuted lanes.
+    Keccakf1600 {
+        inputs: Vec<FunctionInput>,
+        outputs: Vec<Witness>,
+    },
+    /// Applies the SHA-256 compression function to a block of 16 words of 32 bits,
+    /// starting from the 8 words of 32 bits of `hash_values`, outputting the new hash values.
+    Sha256Compression {
+        inputs: Vec<FunctionInput>,
+        hash_values: Vec<FunctionInput>,
+        outputs: Vec<Witness>,
+    },
+    /// Adds the big integers identified by `lhs` and `rhs`, identifying the result by `output`.
+    ///
+    /// Big integers don't fit in a witness, so they are kept by the solver under an identifier.
+    BigIntAdd {
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+    },
+    /// Subtracts the big integer `rhs` from `lhs`, identifying the result by `output`.
+    BigIntSub {
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+    },
+    /// Multiplies the big integers `lhs` and `rhs`, identifying the result by `output`.
+    BigIntMul {
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+    },
+    /// Divides the big integer `lhs` by `rhs`, identifying the result by `output`.
+    BigIntDiv {
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+    },
+    /// Creates the big integer `output` from the little-endian bytes `inputs`,
+    /// reduced modulo the little-endian bytes of `modulus`.
+    BigIntFromLeBytes {
+        inputs: Vec<FunctionInput>,
+        modulus: Vec<u8>,
+        output: u32,
+    },
+    /// Outputs the little-endian bytes of the big integer `input`,
+    /// padded with zeros to the number of outputs.
+    BigIntToLeBytes {
+        input: u32,
+        outputs: Vec<Witness>,
+    },
+    Blake3 {
+        inputs: Vec<FunctionInput>,
+        outputs: Vec<Witness>,
+    },
 }
 
 impl BlackBoxFuncCall {
@@ -186,6 +269,43 @@ impl BlackBoxFuncCall {
                 input_aggregation_object: None,
                 output_aggregation_object: vec![],
             },
+            BlackBoxFunc::Poseidon2Permutation => {
+                BlackBoxFuncCall::Poseidon2Permutation { inputs: vec![], outputs: vec![], len: 0 }
+            }
+            BlackBoxFunc::EmbeddedCurveAdd => BlackBoxFuncCall::EmbeddedCurveAdd {
+                input1_x: FunctionInput::dummy(),
+                input1_y: FunctionInput::dummy(),
+                input2_x: FunctionInput::dummy(),
+                input2_y: FunctionInput::dummy(),
+                outputs: (Witness(0), Witness(0)),
+            },
+            BlackBoxFunc::EmbeddedCurveDouble => BlackBoxFuncCall::EmbeddedCurveDouble {
+                input_x: FunctionInput::dummy(),
+                input_y: FunctionInput::dummy(),
+                outputs: (Witness(0), Witness(0)),
+            },
+            BlackBoxFunc::Keccakf1600 => {
+                BlackBoxFuncCall::Keccakf1600 { inputs: vec![], outputs: vec![] }
+            }
+            BlackBoxFunc::Sha256Compression => BlackBoxFuncCall::Sha256Compression {
+                inputs: vec![],
+                hash_values: vec![],
+                outputs: vec![],
+            },
+            BlackBoxFunc::BigIntAdd => BlackBoxFuncCall::BigIntAdd { lhs: 0, rhs: 0, output: 0 },
+            BlackBoxFunc::BigIntSub => BlackBoxFuncCall::BigIntSub { lhs: 0, rhs: 0, output: 0 },
+            BlackBoxFunc::BigIntMul => BlackBoxFuncCall::BigIntMul { lhs: 0, rhs: 0, output: 0 },
+            BlackBoxFunc::BigIntDiv => BlackBoxFuncCall::BigIntDiv { lhs: 0, rhs: 0, output: 0 },
+            BlackBoxFunc::BigIntFromLeBytes => {
+                BlackBoxFuncCall::BigIntFromLeBytes { inputs: vec![], modulus: vec![], output: 0 }
+            }
+            BlackBoxFunc::BigIntToLeBytes => {
+                BlackBoxFuncCall::BigIntToLeBytes { input: 0, outputs: vec![] }
+            }
+            BlackBoxFunc::Blake3 => BlackBoxFuncCall::Blake3 { inputs: vec![], outputs: vec![] },
+            BlackBoxFunc::EcdsaSecp256k1Recover => {
+                panic!("{bb_func} is only available in Brillig and has no ACIR opcode")
+            }
         }
     }
 
@@ -206,6 +326,18 @@ impl BlackBoxFuncCall {
             BlackBoxFuncCall::Keccak256 { .. } => BlackBoxFunc::Keccak256,
             BlackBoxFuncCall::Keccak256VariableLength { .. } => BlackBoxFunc::Keccak256,
             BlackBoxFuncCall::RecursiveAggregation { .. } => BlackBoxFunc::RecursiveAggregation,
+            BlackBoxFuncCall::Poseidon2Permutation { .. } => BlackBoxFunc::Poseidon2Permutation,
+            BlackBoxFuncCall::EmbeddedCurveAdd { .. } => BlackBoxFunc::EmbeddedCurveAdd,
+            BlackBoxFuncCall::EmbeddedCurveDouble { .. } => BlackBoxFunc::EmbeddedCurveDouble,
+            BlackBoxFuncCall::Keccakf1600 { .. } => BlackBoxFunc::Keccakf1600,
+            BlackBoxFuncCall::Sha256Compression { .. } => BlackBoxFunc::Sha256Compression,
+            BlackBoxFuncCall::BigIntAdd { .. } => BlackBoxFunc::BigIntAdd,
+            BlackBoxFuncCall::BigIntSub { .. } => BlackBoxFunc::BigIntSub,
+            BlackBoxFuncCall::BigIntMul { .. } => BlackBoxFunc::BigIntMul,
+            BlackBoxFuncCall::BigIntDiv { .. } => BlackBoxFunc::BigIntDiv,
+            BlackBoxFuncCall::BigIntFromLeBytes { .. } => BlackBoxFunc::BigIntFromLeBytes,
+            BlackBoxFuncCall::BigIntToLeBytes { .. } => BlackBoxFunc::BigIntToLeBytes,
+            BlackBoxFuncCall::Blake3 { .. } => BlackBoxFunc::Blake3,
         }
     }
 
@@ -217,14 +349,32 @@ impl BlackBoxFuncCall {
         match self {
             BlackBoxFuncCall::SHA256 { inputs, .. }
             | BlackBoxFuncCall::Blake2s { inputs, .. }
+            | BlackBoxFuncCall::Blake3 { inputs, .. }
             | BlackBoxFuncCall::Keccak256 { inputs, .. }
+            | BlackBoxFuncCall::Keccakf1600 { inputs, .. }
+            | BlackBoxFuncCall::BigIntFromLeBytes { inputs, .. }
             | BlackBoxFuncCall::PedersenCommitment { inputs, .. }
             | BlackBoxFuncCall::PedersenHash { inputs, .. }
-            | BlackBoxFuncCall::HashToField128Security { inputs, .. } => inputs.to_vec(),
+            | BlackBoxFuncCall::HashToField128Security { inputs, .. }
+            | BlackBoxFuncCall::Poseidon2Permutation { inputs, .. } => inputs.to_vec(),
             BlackBoxFuncCall::AND { lhs, rhs, .. } | BlackBoxFuncCall::XOR { lhs, rhs, .. } => {
                 vec![*lhs, *rhs]
             }
             BlackBoxFuncCall::FixedBaseScalarMul { low, high, .. } => vec![*low, *high],
+            BlackBoxFuncCall::EmbeddedCurveAdd {
+                input1_x, input1_y, input2_x, input2_y, ..
+            } => vec![*input1_x, *input1_y, *input2_x, *input2_y],
+            BlackBoxFuncCall::EmbeddedCurveDouble { input_x, input_y, .. } => {
+                vec![*input_x, *input_y]
+            }
+            BlackBoxFuncCall::Sha256Compression { inputs, hash_values, .. } => {
+                inputs.iter().chain(hash_values).copied().collect()
+            }
+            BlackBoxFuncCall::BigIntAdd { .. }
+            | BlackBoxFuncCall::BigIntSub { .. }
+            | BlackBoxFuncCall::BigIntMul { .. }
+            | BlackBoxFuncCall::BigIntDiv { .. }
+            | BlackBoxFuncCall::BigIntToLeBytes { .. } => Vec::new(),
             BlackBoxFuncCall::RANGE { input } => vec![*input],
             BlackBoxFuncCall::SchnorrVerify {
                 public_key_x,
@@ -307,7 +457,12 @@ impl BlackBoxFuncCall {
         match self {
             BlackBoxFuncCall::SHA256 { outputs, .. }
             | BlackBoxFuncCall::Blake2s { outputs, .. }
+            | BlackBoxFuncCall::Blake3 { outputs, .. }
             | BlackBoxFuncCall::Keccak256 { outputs, .. }
+            | BlackBoxFuncCall::Keccakf1600 { outputs, .. }
+            | BlackBoxFuncCall::Sha256Compression { outputs, .. }
+            | BlackBoxFuncCall::BigIntToLeBytes { outputs, .. }
+            | BlackBoxFuncCall::Poseidon2Permutation { outputs, .. }
             | BlackBoxFuncCall::RecursiveAggregation {
                 output_aggregation_object: outputs, ..
             } => outputs.to_vec(),
@@ -319,8 +474,15 @@ impl BlackBoxFuncCall {
             | BlackBoxFuncCall::PedersenHash { output, .. }
             | BlackBoxFuncCall::EcdsaSecp256r1 { output, .. } => vec![*output],
             BlackBoxFuncCall::FixedBaseScalarMul { outputs, .. }
-            | BlackBoxFuncCall::PedersenCommitment { outputs, .. } => vec![outputs.0, outputs.1],
-            BlackBoxFuncCall::RANGE { .. } => vec![],
+            | BlackBoxFuncCall::PedersenCommitment { outputs, .. }
+            | BlackBoxFuncCall::EmbeddedCurveAdd { outputs, .. }
+            | BlackBoxFuncCall::EmbeddedCurveDouble { outputs, .. } => vec![outputs.0, outputs.1],
+            BlackBoxFuncCall::RANGE { .. }
+            | BlackBoxFuncCall::BigIntAdd { .. }
+            | BlackBoxFuncCall::BigIntSub { .. }
+            | BlackBoxFuncCall::BigIntMul { .. }
+            | BlackBoxFuncCall::BigIntDiv { .. }
+            | BlackBoxFuncCall::BigIntFromLeBytes { .. } => vec![],
             BlackBoxFuncCall::Keccak256VariableLength { outputs, .. } => outputs.to_vec(),
         }
     }
@@ -6,3 +6,4 @@ pub use expression::Expression;
 pub use witness::Witness;
 pub use witness_map::WitnessMap;
 pub use witness_map::WitnessMapError;
+pub use witness_map::WitnessMapFormat;
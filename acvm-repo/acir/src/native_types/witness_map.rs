@@ -8,6 +8,8 @@ use acir_field::FieldElement;
 use flate2::bufread::GzDecoder;
 use flate2::bufread::GzEncoder;
 use flate2::Compression;
+#[cfg(feature = "witness-formats")]
+use serde::{de::Error as _, Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -17,6 +19,22 @@ use crate::native_types::Witness;
 enum SerializationError {
     #[error(transparent)]
     Deflate(#[from] std::io::Error),
+
+    #[cfg(feature = "witness-formats")]
+    #[error(transparent)]
+    Utf8(#[from] std::str::Utf8Error),
+
+    #[cfg(feature = "witness-formats")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[cfg(feature = "witness-formats")]
+    #[error(transparent)]
+    TomlSerialization(#[from] toml::ser::Error),
+
+    #[cfg(feature = "witness-formats")]
+    #[error(transparent)]
+    TomlDeserialization(#[from] toml::de::Error),
 }
 
 #[derive(Debug, Error)]
@@ -82,11 +100,7 @@ impl TryFrom<WitnessMap> for Vec<u8> {
     type Error = WitnessMapError;
 
     fn try_from(val: WitnessMap) -> Result<Self, Self::Error> {
-        let buf = bincode::serialize(&val).unwrap();
-        let mut deflater = GzEncoder::new(buf.as_slice(), Compression::best());
-        let mut buf_c = Vec::new();
-        deflater.read_to_end(&mut buf_c).map_err(|err| WitnessMapError(err.into()))?;
-        Ok(buf_c)
+        val.to_bytes()
     }
 }
 
@@ -101,3 +115,175 @@ impl TryFrom<&[u8]> for WitnessMap {
         Ok(Self(witness_map))
     }
 }
+
+/// The formats in which a [`WitnessMap`] can be written to and read from a file.
+///
+/// The JSON and TOML formats are only available with the `witness-formats` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WitnessMapFormat {
+    /// Gzip compressed bincode, as produced by converting the [`WitnessMap`] into a `Vec<u8>`.
+    #[default]
+    Binary,
+    /// A JSON object containing the value of each witness as a hex string, keyed by witness index.
+    #[cfg(feature = "witness-formats")]
+    Json,
+    /// A TOML document containing the value of each witness as a hex string, keyed by witness index.
+    #[cfg(feature = "witness-formats")]
+    Toml,
+}
+
+/// The human readable encoding of a [`WitnessMap`] used by the JSON and TOML formats.
+#[cfg(feature = "witness-formats")]
+#[derive(Serialize, Deserialize)]
+struct ReadableWitnessMap {
+    witnesses: ReadableWitnesses,
+    /// Names given to groups of witnesses, such as the ABI parameters they encode.
+    /// These are for the benefit of the reader and are ignored when decoding.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    labels: BTreeMap<String, Vec<u32>>,
+}
+
+#[cfg(feature = "witness-formats")]
+struct ReadableWitnesses(BTreeMap<Witness, FieldElement>);
+
+#[cfg(feature = "witness-formats")]
+impl Serialize for ReadableWitnesses {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // TOML only supports string keys so witness indices are written as strings.
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(witness, value)| (witness.0.to_string(), format!("0x{}", value.to_hex()))),
+        )
+    }
+}
+
+#[cfg(feature = "witness-formats")]
+impl<'de> Deserialize<'de> for ReadableWitnesses {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let readable_witnesses = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let witnesses = readable_witnesses
+            .into_iter()
+            .map(|(index, value)| {
+                let witness = index
+                    .parse()
+                    .map(Witness)
+                    .map_err(|_| D::Error::custom(format!("invalid witness index: {index}")))?;
+                let value = FieldElement::from_hex(&value)
+                    .ok_or_else(|| D::Error::custom(format!("invalid field element: {value}")))?;
+                Ok((witness, value))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(ReadableWitnesses(witnesses))
+    }
+}
+
+impl WitnessMap {
+    /// Serializes the witness map into the given format.
+    ///
+    /// `labels` names groups of witnesses, such as the ABI parameters they encode.
+    /// They are written alongside the witnesses in the JSON and TOML formats and are omitted from the binary format.
+    #[cfg_attr(not(feature = "witness-formats"), allow(unused_variables))]
+    pub fn to_format(
+        &self,
+        format: WitnessMapFormat,
+        labels: &BTreeMap<String, Vec<Witness>>,
+    ) -> Result<Vec<u8>, WitnessMapError> {
+        match format {
+            WitnessMapFormat::Binary => self.to_bytes(),
+            #[cfg(feature = "witness-formats")]
+            WitnessMapFormat::Json => serde_json::to_vec_pretty(&self.to_readable(labels))
+                .map_err(|err| WitnessMapError(err.into())),
+            #[cfg(feature = "witness-formats")]
+            WitnessMapFormat::Toml => toml::to_string(&self.to_readable(labels))
+                .map(String::into_bytes)
+                .map_err(|err| WitnessMapError(err.into())),
+        }
+    }
+
+    /// Deserializes a witness map which was serialized into the given format.
+    pub fn from_format(bytes: &[u8], format: WitnessMapFormat) -> Result<Self, WitnessMapError> {
+        match format {
+            WitnessMapFormat::Binary => Self::try_from(bytes),
+            #[cfg(feature = "witness-formats")]
+            WitnessMapFormat::Json => {
+                let readable: ReadableWitnessMap =
+                    serde_json::from_slice(bytes).map_err(|err| WitnessMapError(err.into()))?;
+                Ok(Self(readable.witnesses.0))
+            }
+            #[cfg(feature = "witness-formats")]
+            WitnessMapFormat::Toml => {
+                let toml_str =
+                    std::str::from_utf8(bytes).map_err(|err| WitnessMapError(err.into()))?;
+                let readable: ReadableWitnessMap =
+                    toml::from_str(toml_str).map_err(|err| WitnessMapError(err.into()))?;
+                Ok(Self(readable.witnesses.0))
+            }
+        }
+    }
+
+    #[cfg(feature = "witness-formats")]
+    fn to_readable(&self, labels: &BTreeMap<String, Vec<Witness>>) -> ReadableWitnessMap {
+        ReadableWitnessMap {
+            witnesses: ReadableWitnesses(self.0.clone()),
+            labels: labels
+                .iter()
+                .map(|(name, witnesses)| (name.clone(), witnesses.iter().map(|w| w.0).collect()))
+                .collect(),
+        }
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, WitnessMapError> {
+        let buf = bincode::serialize(self).unwrap();
+        let mut deflater = GzEncoder::new(buf.as_slice(), Compression::best());
+        let mut buf_c = Vec::new();
+        deflater.read_to_end(&mut buf_c).map_err(|err| WitnessMapError(err.into()))?;
+        Ok(buf_c)
+    }
+}
+
+#[cfg(all(test, feature = "witness-formats"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use acir_field::FieldElement;
+
+    use super::{WitnessMap, WitnessMapFormat};
+    use crate::native_types::Witness;
+
+    fn witness_map() -> WitnessMap {
+        WitnessMap::from(BTreeMap::from([
+            (Witness(1), FieldElement::from(2u128)),
+            (Witness(2), FieldElement::from(3u128)),
+            (Witness(10), -FieldElement::one()),
+        ]))
+    }
+
+    #[test]
+    fn round_trips_through_each_format() {
+        let witness_map = witness_map();
+        let labels = BTreeMap::from([("x".to_string(), vec![Witness(1), Witness(2)])]);
+        for format in [WitnessMapFormat::Binary, WitnessMapFormat::Json, WitnessMapFormat::Toml] {
+            let bytes = witness_map.to_format(format, &labels).unwrap();
+            assert_eq!(WitnessMap::from_format(&bytes, format).unwrap(), witness_map);
+        }
+    }
+
+    #[test]
+    fn reads_handwritten_toml() {
+        let toml = r#"
+            [witnesses]
+            1 = "0x02"
+            2 = "0x03"
+            10 = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000"
+        "#;
+        let witness_map = WitnessMap::from_format(toml.as_bytes(), WitnessMapFormat::Toml).unwrap();
+        assert_eq!(witness_map, self::witness_map());
+    }
+
+    #[test]
+    fn rejects_invalid_witness_index() {
+        let json = r#"{ "witnesses": { "x": "0x01" } }"#;
+        assert!(WitnessMap::from_format(json.as_bytes(), WitnessMapFormat::Json).is_err());
+    }
+}
@@ -1,5 +1,9 @@
 use acir::{
-    circuit::{opcodes::UnsupportedMemoryOpcode, Circuit, Opcode, OpcodeLocation},
+    circuit::{
+        brillig::{Brillig, BrilligBytecode},
+        opcodes::UnsupportedMemoryOpcode,
+        Circuit, Opcode, OpcodeLocation,
+    },
     BlackBoxFunc,
 };
 use thiserror::Error;
@@ -55,6 +59,31 @@ impl AcirTransformationMap {
     }
 }
 
+/// Replaces each [BrilligCall][Opcode::BrilligCall] opcode of the circuit with a [Brillig][Opcode::Brillig]
+/// opcode holding the bytecode of the called function, for backends which don't support calls to
+/// unconstrained functions.
+///
+/// Opcodes are replaced one for one, so their locations are unchanged.
+pub fn inline_brillig_calls(
+    mut acir: Circuit,
+    unconstrained_functions: &[BrilligBytecode],
+) -> Circuit {
+    for opcode in &mut acir.opcodes {
+        if let Opcode::BrilligCall { id, inputs, outputs, predicate } = opcode {
+            let function = unconstrained_functions
+                .get(*id as usize)
+                .unwrap_or_else(|| panic!("ICE: call to unknown unconstrained function {id}"));
+            *opcode = Opcode::Brillig(Brillig {
+                inputs: std::mem::take(inputs),
+                outputs: std::mem::take(outputs),
+                bytecode: function.bytecode.clone(),
+                predicate: predicate.take(),
+            });
+        }
+    }
+    acir
+}
+
 fn transform_assert_messages(
     assert_messages: Vec<(OpcodeLocation, String)>,
     map: &AcirTransformationMap,
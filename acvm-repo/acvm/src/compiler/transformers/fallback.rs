@@ -21,8 +21,12 @@ impl FallbackTransformer {
 
         for (idx, opcode) in acir.opcodes.into_iter().enumerate() {
             match &opcode {
-                Opcode::Arithmetic(_) | Opcode::Directive(_) | Opcode::Brillig(_) => {
-                    // directive, arithmetic expression or blocks are handled by acvm
+                Opcode::Arithmetic(_)
+                | Opcode::Directive(_)
+                | Opcode::Brillig(_)
+                | Opcode::BrilligCall { .. }
+                | Opcode::Call { .. } => {
+                    // directive, arithmetic expression, blocks or calls are handled by acvm
                     new_opcode_positions.push(opcode_positions[idx]);
                     acir_supported_opcodes.push(opcode);
                     continue;
@@ -129,6 +133,16 @@ impl FallbackTransformer {
                 )
             }
             #[cfg(feature = "unstable-fallbacks")]
+            BlackBoxFuncCall::Blake3 { inputs, outputs } => {
+                let blake3_inputs =
+                    inputs.iter().map(|input| (input.witness.into(), input.num_bits)).collect();
+                stdlib::blackbox_fallbacks::blake3(
+                    blake3_inputs,
+                    outputs.to_vec(),
+                    current_witness_idx,
+                )
+            }
+            #[cfg(feature = "unstable-fallbacks")]
             BlackBoxFuncCall::HashToField128Security { inputs, output } => {
                 let hash_to_field_inputs =
                     inputs.iter().map(|input| (input.witness.into(), input.num_bits)).collect();
@@ -148,6 +162,27 @@ impl FallbackTransformer {
                     current_witness_idx,
                 )
             }
+            #[cfg(feature = "unstable-fallbacks")]
+            BlackBoxFuncCall::Keccakf1600 { inputs, outputs } => {
+                let keccakf1600_inputs = inputs.iter().map(|input| input.witness).collect();
+                stdlib::blackbox_fallbacks::keccakf1600(
+                    keccakf1600_inputs,
+                    outputs.to_vec(),
+                    current_witness_idx,
+                )
+            }
+            #[cfg(feature = "unstable-fallbacks")]
+            BlackBoxFuncCall::Sha256Compression { inputs, hash_values, outputs } => {
+                let sha256_compression_inputs = inputs.iter().map(|input| input.witness).collect();
+                let sha256_compression_hash_values =
+                    hash_values.iter().map(|input| input.witness).collect();
+                stdlib::blackbox_fallbacks::sha256_compression(
+                    sha256_compression_inputs,
+                    sha256_compression_hash_values,
+                    outputs.to_vec(),
+                    current_witness_idx,
+                )
+            }
             _ => {
                 return Err(CompileError::UnsupportedBlackBox(gc.get_black_box_func()));
             }
@@ -0,0 +1,515 @@
+//! Replaces memory opcodes with arithmetic constraints for backends which do not support them.
+//!
+//! Each access to a memory block, including the writes of its initial values, becomes a row
+//! `(index, counter, value, is_write)` of a trace, where the counter orders the accesses as they
+//! appear in the circuit. Once the whole circuit has been seen, the trace is sorted by index and then
+//! by counter using a permutation network whose switches are solved by a `PermutationSort` directive.
+//! In the sorted trace, the accesses to each index are grouped together in circuit order, starting
+//! with the write of the index's initial value, so each read must return the value of the row before it.
+//!
+//! The values returned by reads are computed by Brillig opcodes which replay the writes made to the
+//! block so far. These are only hints, the constraints on the sorted trace check that they are correct.
+use std::collections::HashMap;
+
+use acir::{
+    brillig::{BinaryFieldOp, BinaryIntOp, Opcode as BrilligOpcode, RegisterIndex, Value},
+    circuit::{
+        brillig::{Brillig, BrilligInputs, BrilligOutputs},
+        directives::Directive,
+        opcodes::{BlackBoxFuncCall, BlockId, FunctionInput, MemOp, UnsupportedMemoryOpcode},
+        Circuit, Opcode,
+    },
+    native_types::{Expression, Witness},
+    FieldElement,
+};
+
+use super::super::CompileError;
+
+/// The bit size used by the read hints for the arithmetic on memory indices.
+const INDEX_BIT_SIZE: u32 = 64;
+
+/// Lowers the memory opcodes of a [`Circuit`] into arithmetic constraints, directives and Brillig hints
+/// if the backend doesn't support them.
+pub(crate) struct MemoryTransformer {
+    opcodes: Vec<Opcode>,
+    opcode_positions: Vec<usize>,
+    next_witness_index: u32,
+    /// The position in the original circuit of the opcode currently being lowered.
+    current_position: usize,
+}
+
+/// The accesses made to a memory block since it was initialized.
+struct MemoryTrace {
+    /// The number of elements of the block.
+    len: usize,
+    /// The initial values of the block.
+    init: Vec<Witness>,
+    /// The rows of the trace, in circuit order.
+    rows: Vec<TraceRow>,
+    /// The index and value of each write made to the block so far, in circuit order.
+    /// Writes made under a false predicate have the out of bounds index `len`.
+    writes: Vec<(Expression, Expression)>,
+    /// The position in the original circuit of the opcode which initialized the block.
+    position: usize,
+}
+
+struct TraceRow {
+    index: Expression,
+    value: Expression,
+    /// One for writes and zero for reads.
+    is_write: Expression,
+}
+
+impl MemoryTransformer {
+    /// Replaces the memory opcodes of the circuit if any of them is not supported.
+    pub(crate) fn transform(
+        acir: Circuit,
+        is_supported: impl Fn(&Opcode) -> bool,
+        opcode_positions: Vec<usize>,
+    ) -> Result<(Circuit, Vec<usize>), CompileError> {
+        let has_unsupported_memory = acir.opcodes.iter().any(|opcode| {
+            matches!(opcode, Opcode::MemoryInit { .. } | Opcode::MemoryOp { .. })
+                && !is_supported(opcode)
+        });
+        if !has_unsupported_memory {
+            return Ok((acir, opcode_positions));
+        }
+
+        let mut transformer = MemoryTransformer {
+            opcodes: Vec::with_capacity(acir.opcodes.len()),
+            opcode_positions: Vec::with_capacity(opcode_positions.len()),
+            next_witness_index: acir.current_witness_index + 1,
+            current_position: 0,
+        };
+        let mut traces: Vec<MemoryTrace> = Vec::new();
+        let mut active_traces: HashMap<BlockId, usize> = HashMap::new();
+
+        for (opcode, position) in acir.opcodes.into_iter().zip(opcode_positions) {
+            transformer.current_position = position;
+            match opcode {
+                Opcode::MemoryInit { block_id, init } => {
+                    active_traces.insert(block_id, traces.len());
+                    traces.push(MemoryTrace::new(init, position));
+                }
+                Opcode::MemoryOp { block_id, op, predicate } => {
+                    let Some(trace) = active_traces.get(&block_id).map(|index| &mut traces[*index])
+                    else {
+                        return Err(CompileError::UnsupportedMemoryOpcode(
+                            UnsupportedMemoryOpcode::MemoryOp,
+                        ));
+                    };
+                    transformer.lower_memory_op(trace, op, predicate)?;
+                }
+                opcode => transformer.push(opcode),
+            }
+        }
+
+        for trace in traces {
+            transformer.current_position = trace.position;
+            transformer.constrain_trace(trace);
+        }
+
+        let circuit = Circuit {
+            current_witness_index: transformer.next_witness_index - 1,
+            opcodes: transformer.opcodes,
+            ..acir
+        };
+        Ok((circuit, transformer.opcode_positions))
+    }
+
+    /// Adds the row of a memory operation to the trace of its block, along with a hint computing
+    /// the value returned by reads.
+    fn lower_memory_op(
+        &mut self,
+        trace: &mut MemoryTrace,
+        op: MemOp,
+        predicate: Option<Expression>,
+    ) -> Result<(), CompileError> {
+        let unsupported = CompileError::UnsupportedMemoryOpcode(UnsupportedMemoryOpcode::MemoryOp);
+        let is_write = match op.operation.to_const() {
+            Some(operation) if operation.is_zero() => false,
+            Some(operation) if operation.is_one() => true,
+            _ => return Err(unsupported),
+        };
+        let predicate = match predicate {
+            Some(predicate) if predicate.to_const() != Some(FieldElement::one()) => {
+                Some(self.linearize(predicate))
+            }
+            _ => None,
+        };
+
+        let len = FieldElement::from(trace.len as u128);
+        let index = self.linearize(op.index);
+
+        // Accesses under a false predicate are moved to the index `len`, which is otherwise out of bounds.
+        let row_index = match &predicate {
+            Some(predicate) => self.mul(predicate, &(index.clone() - len)) + len,
+            None => index.clone(),
+        };
+        // Enabled accesses must be in bounds, `len - 1 - index` is negative otherwise.
+        let is_constant_in_bounds = index
+            .to_const()
+            .and_then(|index| index.try_to_u64())
+            .map_or(false, |index| (index as usize) < trace.len);
+        if !is_constant_in_bounds {
+            let distance_to_end = &Expression::from_field(len - FieldElement::one()) - &index;
+            let distance_to_end = match &predicate {
+                Some(predicate) => self.mul(predicate, &distance_to_end),
+                None => distance_to_end,
+            };
+            self.range(distance_to_end, bit_size(trace.len));
+        }
+
+        if is_write {
+            let value = self.linearize(op.value);
+            trace.writes.push((row_index.clone(), value.clone()));
+            trace.rows.push(TraceRow { index: row_index, value, is_write: Expression::one() });
+        } else {
+            let value = op.value.to_witness().ok_or(unsupported)?;
+            self.push_read_hint(trace, index, value, predicate.clone());
+
+            // Reads under a false predicate return zero and are treated as writes to the index `len`.
+            let is_write = match &predicate {
+                Some(predicate) => {
+                    let not_predicate = &Expression::one() - predicate;
+                    self.assert_zero(
+                        (&not_predicate * &Expression::from(value))
+                            .expect("product of linear expressions should have degree two"),
+                    );
+                    not_predicate
+                }
+                None => Expression::zero(),
+            };
+            trace.rows.push(TraceRow { index: row_index, value: value.into(), is_write });
+        }
+        Ok(())
+    }
+
+    /// Adds a Brillig opcode computing the value read at `index`, by starting from the block's
+    /// initial value at this index and replaying the writes made to the block so far.
+    fn push_read_hint(
+        &mut self,
+        trace: &MemoryTrace,
+        index: Expression,
+        output: Witness,
+        predicate: Option<Expression>,
+    ) {
+        let inputs = vec![
+            BrilligInputs::Single(index),
+            BrilligInputs::Array(trace.init.iter().map(|witness| (*witness).into()).collect()),
+            BrilligInputs::Array(trace.writes.iter().map(|(index, _)| index.clone()).collect()),
+            BrilligInputs::Array(trace.writes.iter().map(|(_, value)| value.clone()).collect()),
+        ];
+        let bytecode = read_hint_bytecode(trace.len, trace.writes.len());
+        self.push(Opcode::Brillig(Brillig {
+            inputs,
+            outputs: vec![BrilligOutputs::Simple(output)],
+            bytecode,
+            predicate,
+        }));
+    }
+
+    /// Sorts the trace of a memory block and constrains each read to return the value of the
+    /// previous access to the same index.
+    fn constrain_trace(&mut self, trace: MemoryTrace) {
+        let len = FieldElement::from(trace.len as u128);
+
+        // The initial values are written first, followed by a write of zero to the index `len`
+        // which accesses under a false predicate are moved to.
+        let mut rows = Vec::with_capacity(trace.len + 1 + trace.rows.len());
+        for (index, witness) in trace.init.iter().enumerate() {
+            let index = Expression::from_field(FieldElement::from(index as u128));
+            rows.push(TraceRow { index, value: (*witness).into(), is_write: Expression::one() });
+        }
+        let index = Expression::from_field(len);
+        rows.push(TraceRow { index, value: Expression::zero(), is_write: Expression::one() });
+        rows.extend(trace.rows);
+
+        let rows: Vec<_> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(counter, row)| {
+                let counter = Expression::from_field(FieldElement::from(counter as u128));
+                vec![row.index, counter, row.value, row.is_write]
+            })
+            .collect();
+        let sorted = self.sort_rows(&rows);
+        let [indices, counters, values, is_write] = &sorted[..] else {
+            unreachable!("each row of the trace has four columns");
+        };
+
+        let row_count = rows.len();
+        self.assert_zero(indices[0].clone());
+        self.assert_zero(&indices[row_count - 1] - &Expression::from_field(len));
+
+        for row in 1..row_count {
+            // Indices are increasing one by one, so that each index from 0 to `len` appears in the trace.
+            let index_step = self.linearize(&indices[row] - &indices[row - 1]);
+            let is_boolean = (&index_step * &(index_step.clone() - FieldElement::one()))
+                .expect("product of linear expressions should have degree two");
+            self.assert_zero(is_boolean);
+            let same_index = &Expression::one() - &index_step;
+
+            // Accesses to the same index are sorted in circuit order.
+            let counter_gap = &counters[row] - &counters[row - 1] - FieldElement::one();
+            let counter_gap = self.mul(&same_index, &counter_gap);
+            self.range(counter_gap, bit_size(row_count));
+
+            // Reads return the value of the previous access to the same index.
+            let is_read = &Expression::one() - &is_write[row];
+            let is_read_of_same_index = self.mul(&same_index, &is_read);
+            let value_change = self.linearize(&values[row] - &values[row - 1]);
+            self.assert_zero(
+                (&is_read_of_same_index * &value_change)
+                    .expect("product of linear expressions should have degree two"),
+            );
+        }
+    }
+
+    /// Sorts the rows by their first column and then by their second one, returning the columns
+    /// of the sorted rows.
+    fn sort_rows(&mut self, rows: &[Vec<Expression>]) -> Vec<Vec<Expression>> {
+        let bit_count: usize =
+            (1..=rows.len()).map(|size| (size as f64).log2().ceil() as usize).sum();
+        let bits: Vec<Witness> = (0..bit_count).map(|_| self.new_witness()).collect();
+        self.push(Opcode::Directive(Directive::PermutationSort {
+            inputs: rows.to_vec(),
+            tuple: 4,
+            bits: bits.clone(),
+            sort_by: vec![0, 1],
+        }));
+        // The directive is only a hint, each switch must either swap its inputs or leave them as they are.
+        for bit in &bits {
+            let bit = Expression::from(*bit);
+            let is_boolean = &(&bit * &bit)
+                .expect("product of linear expressions should have degree two")
+                - &bit;
+            self.assert_zero(is_boolean);
+        }
+
+        (0..4)
+            .map(|column| {
+                let column: Vec<_> = rows.iter().map(|row| row[column].clone()).collect();
+                self.permutation_layer(&column, &bits).1
+            })
+            .collect()
+    }
+
+    /// Routes the inputs through the sorting network configured by `bits`, returning the number
+    /// of bits used along with the outputs of the network.
+    ///
+    /// This is the same network as the one which the `PermutationSort` directive solves the bits of.
+    fn permutation_layer(
+        &mut self,
+        inputs: &[Expression],
+        bits: &[Witness],
+    ) -> (usize, Vec<Expression>) {
+        let n = inputs.len();
+        if n == 1 {
+            return (0, inputs.to_vec());
+        }
+        let n1 = n / 2;
+        let output_switches = (n - 1) / 2;
+
+        let mut sub_inputs1 = Vec::with_capacity(n1);
+        let mut sub_inputs2 = Vec::with_capacity(n - n1);
+        for i in 0..n1 {
+            let (b1, b2) = self.switch(bits[i], &inputs[2 * i], &inputs[2 * i + 1]);
+            sub_inputs1.push(b1);
+            sub_inputs2.push(b2);
+        }
+        if n % 2 == 1 {
+            sub_inputs2.push(inputs[n - 1].clone());
+        }
+
+        let sub_bits = &bits[n1 + output_switches..];
+        let (used1, outputs1) = self.permutation_layer(&sub_inputs1, sub_bits);
+        let (used2, outputs2) = self.permutation_layer(&sub_inputs2, &sub_bits[used1..]);
+
+        let mut outputs = Vec::with_capacity(n);
+        for i in 0..output_switches {
+            let (b1, b2) = self.switch(bits[n1 + i], &outputs1[i], &outputs2[i]);
+            outputs.push(b1);
+            outputs.push(b2);
+        }
+        if n % 2 == 0 {
+            outputs.push(outputs1[n1 - 1].clone());
+        }
+        outputs.push(outputs2[outputs2.len() - 1].clone());
+
+        (n1 + output_switches + used1 + used2, outputs)
+    }
+
+    /// Swaps a1 and a2 into b1 = a1 + q and b2 = a2 - q if the switch `c` is set, where q = c * (a2 - a1).
+    ///
+    /// The outputs are witnesses, so that the switches of the next layer multiply `c` by the difference
+    /// of two witnesses rather than by the sum of every product of the previous layers.
+    fn switch(&mut self, c: Witness, a1: &Expression, a2: &Expression) -> (Expression, Expression) {
+        let swap = (&Expression::from(c) * &(a2 - a1))
+            .expect("product of linear expressions should have degree two");
+        let b1 = self.materialize(&swap + a1);
+        let b2 = self.materialize(a2 - &swap);
+        (b1, b2)
+    }
+
+    /// Returns the expression if it is a constant, or else a witness equal to it.
+    fn materialize(&mut self, expression: Expression) -> Expression {
+        if expression.is_const() {
+            expression
+        } else {
+            self.witness(expression).into()
+        }
+    }
+
+    fn push(&mut self, opcode: Opcode) {
+        self.opcodes.push(opcode);
+        self.opcode_positions.push(self.current_position);
+    }
+
+    fn new_witness(&mut self) -> Witness {
+        let witness = Witness(self.next_witness_index);
+        self.next_witness_index += 1;
+        witness
+    }
+
+    fn assert_zero(&mut self, expression: Expression) {
+        if !expression.is_zero() {
+            self.push(Opcode::Arithmetic(expression));
+        }
+    }
+
+    /// Returns a witness equal to the expression, constraining a new witness to it if necessary.
+    fn witness(&mut self, expression: Expression) -> Witness {
+        match expression.to_witness() {
+            Some(witness) => witness,
+            None => {
+                let witness = self.new_witness();
+                self.push(Opcode::Arithmetic(&expression - witness));
+                witness
+            }
+        }
+    }
+
+    /// Returns an expression of degree at most one equal to the given expression.
+    fn linearize(&mut self, expression: Expression) -> Expression {
+        if expression.is_linear() {
+            expression
+        } else {
+            self.witness(expression).into()
+        }
+    }
+
+    /// Returns the product of two expressions of degree at most one as an expression of degree at most one.
+    fn mul(&mut self, lhs: &Expression, rhs: &Expression) -> Expression {
+        let product = (lhs * rhs).expect("product of linear expressions should have degree two");
+        self.linearize(product)
+    }
+
+    /// Constrains the expression to fit in `num_bits` bits.
+    fn range(&mut self, expression: Expression, num_bits: u32) {
+        if let Some(constant) = expression.to_const() {
+            if constant.num_bits() <= num_bits {
+                return;
+            }
+        }
+        let witness = self.witness(expression);
+        self.push(Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness, num_bits },
+        }));
+    }
+}
+
+impl MemoryTrace {
+    fn new(init: Vec<Witness>, position: usize) -> Self {
+        MemoryTrace { len: init.len(), init, rows: Vec::new(), writes: Vec::new(), position }
+    }
+}
+
+/// The number of bits needed to represent the values from zero to `value`.
+fn bit_size(value: usize) -> u32 {
+    (usize::BITS - value.leading_zeros()).max(1)
+}
+
+/// Generates the bytecode of a hint which reads a memory block of length `len` at an index, after
+/// `write_count` writes. It expects the following inputs:
+///
+/// 0. The index to read.
+/// 1. A pointer to the initial values of the block.
+/// 2. A pointer to the indices of the writes.
+/// 3. A pointer to the values of the writes.
+///
+/// and returns the value read in register 0. The hint fails if the index is out of bounds.
+fn read_hint_bytecode(len: usize, write_count: usize) -> Vec<BrilligOpcode> {
+    let index = RegisterIndex::from(0);
+    let initial_values = RegisterIndex::from(1);
+    let write_indices = RegisterIndex::from(2);
+    let write_values = RegisterIndex::from(3);
+    let bound = RegisterIndex::from(4);
+    let condition = RegisterIndex::from(5);
+    let pointer = RegisterIndex::from(6);
+    let result = RegisterIndex::from(7);
+    let counter = RegisterIndex::from(8);
+    let one = RegisterIndex::from(9);
+    let write_index = RegisterIndex::from(10);
+
+    let int_op = |op, lhs, rhs, destination| BrilligOpcode::BinaryIntOp {
+        destination,
+        op,
+        bit_size: INDEX_BIT_SIZE,
+        lhs,
+        rhs,
+    };
+
+    // Fail if the index is out of bounds.
+    let mut bytecode = vec![
+        BrilligOpcode::Const { destination: bound, value: Value::from(len) },
+        int_op(BinaryIntOp::LessThan, index, bound, condition),
+    ];
+    let in_bounds_location = bytecode.len() + 2;
+    bytecode.push(BrilligOpcode::JumpIf { condition, location: in_bounds_location });
+    bytecode.push(BrilligOpcode::Trap);
+
+    // result = initial_values[index]
+    bytecode.extend([
+        int_op(BinaryIntOp::Add, initial_values, index, pointer),
+        BrilligOpcode::Load { destination: result, source_pointer: pointer },
+        BrilligOpcode::Const { destination: counter, value: Value::from(0_usize) },
+        BrilligOpcode::Const { destination: bound, value: Value::from(write_count) },
+        BrilligOpcode::Const { destination: one, value: Value::from(1_usize) },
+    ]);
+
+    // for counter in 0..write_count
+    let loop_start = bytecode.len();
+    bytecode.push(int_op(BinaryIntOp::LessThan, counter, bound, condition));
+    let exit_loop = bytecode.len();
+    bytecode.push(BrilligOpcode::JumpIfNot { condition, location: 0 });
+
+    // if write_indices[counter] == index { result = write_values[counter] }
+    bytecode.extend([
+        int_op(BinaryIntOp::Add, write_indices, counter, pointer),
+        BrilligOpcode::Load { destination: write_index, source_pointer: pointer },
+        BrilligOpcode::BinaryFieldOp {
+            destination: condition,
+            op: BinaryFieldOp::Equals,
+            lhs: write_index,
+            rhs: index,
+        },
+    ]);
+    let skip_write = bytecode.len();
+    bytecode.push(BrilligOpcode::JumpIfNot { condition, location: 0 });
+    bytecode.extend([
+        int_op(BinaryIntOp::Add, write_values, counter, pointer),
+        BrilligOpcode::Load { destination: result, source_pointer: pointer },
+    ]);
+    let skip_write_location = bytecode.len();
+    bytecode.push(int_op(BinaryIntOp::Add, counter, one, counter));
+    bytecode.push(BrilligOpcode::Jump { location: loop_start });
+
+    let loop_end = bytecode.len();
+    bytecode.push(BrilligOpcode::Mov { destination: index, source: result });
+    bytecode.push(BrilligOpcode::Stop);
+
+    bytecode[exit_loop] = BrilligOpcode::JumpIfNot { condition, location: loop_end };
+    bytecode[skip_write] = BrilligOpcode::JumpIfNot { condition, location: skip_write_location };
+    bytecode
+}
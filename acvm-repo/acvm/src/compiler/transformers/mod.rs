@@ -9,10 +9,12 @@ use crate::Language;
 
 mod csat;
 mod fallback;
+mod memory;
 mod r1cs;
 
 pub(crate) use csat::CSatTransformer;
 pub(crate) use fallback::FallbackTransformer;
+pub(crate) use memory::MemoryTransformer;
 pub(crate) use r1cs::R1CSTransformer;
 
 use super::{transform_assert_messages, AcirTransformationMap, CompileError};
@@ -44,6 +46,10 @@ pub(super) fn transform_internal(
     is_opcode_supported: impl Fn(&Opcode) -> bool,
     acir_opcode_positions: Vec<usize>,
 ) -> Result<(Circuit, AcirTransformationMap), CompileError> {
+    // Memory opcodes are lowered first so that the opcodes replacing them also get their fallbacks.
+    let (acir, acir_opcode_positions) =
+        MemoryTransformer::transform(acir, &is_opcode_supported, acir_opcode_positions)?;
+
     // Fallback transformer pass
     let (acir, acir_opcode_positions) =
         FallbackTransformer::transform(acir, is_opcode_supported, acir_opcode_positions)?;
@@ -110,7 +116,12 @@ pub(super) fn transform_internal(
                     | acir::circuit::opcodes::BlackBoxFuncCall::XOR { output, .. } => {
                         transformer.mark_solvable(*output);
                     }
-                    acir::circuit::opcodes::BlackBoxFuncCall::RANGE { .. } => (),
+                    acir::circuit::opcodes::BlackBoxFuncCall::RANGE { .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntAdd { .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntSub { .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntMul { .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntDiv { .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntFromLeBytes { .. } => (),
                     acir::circuit::opcodes::BlackBoxFuncCall::SHA256 { outputs, .. }
                     | acir::circuit::opcodes::BlackBoxFuncCall::Keccak256 { outputs, .. }
                     | acir::circuit::opcodes::BlackBoxFuncCall::Keccak256VariableLength {
@@ -121,7 +132,19 @@ pub(super) fn transform_internal(
                         output_aggregation_object: outputs,
                         ..
                     }
-                    | acir::circuit::opcodes::BlackBoxFuncCall::Blake2s { outputs, .. } => {
+                    | acir::circuit::opcodes::BlackBoxFuncCall::Blake2s { outputs, .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::Blake3 { outputs, .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::BigIntToLeBytes {
+                        outputs, ..
+                    }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::Keccakf1600 { outputs, .. }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::Sha256Compression {
+                        outputs, ..
+                    }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::Poseidon2Permutation {
+                        outputs,
+                        ..
+                    } => {
                         for witness in outputs {
                             transformer.mark_solvable(*witness);
                         }
@@ -133,6 +156,13 @@ pub(super) fn transform_internal(
                     | acir::circuit::opcodes::BlackBoxFuncCall::PedersenCommitment {
                         outputs,
                         ..
+                    }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::EmbeddedCurveAdd {
+                        outputs, ..
+                    }
+                    | acir::circuit::opcodes::BlackBoxFuncCall::EmbeddedCurveDouble {
+                        outputs,
+                        ..
                     } => {
                         transformer.mark_solvable(outputs.0);
                         transformer.mark_solvable(outputs.1);
@@ -188,6 +218,13 @@ pub(super) fn transform_internal(
                 new_acir_opcode_positions.push(acir_opcode_positions[index]);
                 transformed_opcodes.push(opcode);
             }
+            Opcode::Call { ref outputs, .. } => {
+                for witness in outputs {
+                    transformer.mark_solvable(*witness);
+                }
+                new_acir_opcode_positions.push(acir_opcode_positions[index]);
+                transformed_opcodes.push(opcode);
+            }
             Opcode::Brillig(ref brillig) => {
                 for output in &brillig.outputs {
                     match output {
@@ -202,6 +239,20 @@ pub(super) fn transform_internal(
                 new_acir_opcode_positions.push(acir_opcode_positions[index]);
                 transformed_opcodes.push(opcode);
             }
+            Opcode::BrilligCall { ref outputs, .. } => {
+                for output in outputs {
+                    match output {
+                        BrilligOutputs::Simple(w) => transformer.mark_solvable(*w),
+                        BrilligOutputs::Array(v) => {
+                            for witness in v {
+                                transformer.mark_solvable(*witness);
+                            }
+                        }
+                    }
+                }
+                new_acir_opcode_positions.push(acir_opcode_positions[index]);
+                transformed_opcodes.push(opcode);
+            }
         }
     }
 
@@ -0,0 +1,58 @@
+use acir::{
+    circuit::opcodes::FunctionInput,
+    native_types::{Witness, WitnessMap},
+    BlackBoxFunc, FieldElement,
+};
+use acvm_blackbox_solver::BigIntSolver;
+
+use crate::pwg::{insert_value, witness_to_value};
+use crate::OpcodeResolutionError;
+
+/// Attempts to solve a `BigIntFromLeBytes` opcode
+/// If successful, `bigint_solver` will hold the value of the `output` big integer.
+pub(super) fn bigint_from_le_bytes(
+    initial_witness: &WitnessMap,
+    inputs: &[FunctionInput],
+    modulus: &[u8],
+    output: u32,
+    bigint_solver: &mut BigIntSolver,
+) -> Result<(), OpcodeResolutionError> {
+    let bytes = inputs
+        .iter()
+        .map(|input| {
+            let value = witness_to_value(initial_witness, input.witness)?;
+            value.try_to_u64().and_then(|byte| u8::try_from(byte).ok()).ok_or_else(|| {
+                OpcodeResolutionError::BlackBoxFunctionFailed(
+                    BlackBoxFunc::BigIntFromLeBytes,
+                    format!("Byte {} does not fit in 8 bits", value.to_hex()),
+                )
+            })
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    bigint_solver.bigint_from_bytes(&bytes, modulus, output)?;
+    Ok(())
+}
+
+/// Attempts to solve a `BigIntToLeBytes` opcode
+/// If successful, `initial_witness` will be mutated to contain the bytes of the `input` big integer.
+pub(super) fn bigint_to_le_bytes(
+    initial_witness: &mut WitnessMap,
+    input: u32,
+    outputs: &[Witness],
+    bigint_solver: &BigIntSolver,
+) -> Result<(), OpcodeResolutionError> {
+    let bytes = bigint_solver.bigint_to_bytes(input)?;
+    if bytes.len() > outputs.len() {
+        return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
+            BlackBoxFunc::BigIntToLeBytes,
+            format!("Big integer {input} does not fit in {} bytes", outputs.len()),
+        ));
+    }
+
+    for (index, output) in outputs.iter().enumerate() {
+        let byte = bytes.get(index).copied().unwrap_or_default();
+        insert_value(output, FieldElement::from(byte as u128), initial_witness)?;
+    }
+    Ok(())
+}
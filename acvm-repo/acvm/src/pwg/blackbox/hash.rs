@@ -3,7 +3,10 @@ use acir::{
     native_types::{Witness, WitnessMap},
     BlackBoxFunc, FieldElement,
 };
-use acvm_blackbox_solver::{hash_to_field_128_security, BlackBoxResolutionError};
+use acvm_blackbox_solver::{
+    hash_to_field_128_security, keccakf1600, poseidon2_permutation, sha256_compression,
+    BlackBoxResolutionError,
+};
 
 use crate::pwg::{insert_value, witness_to_value};
 use crate::OpcodeResolutionError;
@@ -101,3 +104,116 @@ fn write_digest_to_outputs(
 
     Ok(())
 }
+
+/// Attempts to solve a `Poseidon2Permutation` opcode
+/// If successful, `initial_witness` will be mutated to contain the permuted state.
+pub(super) fn solve_poseidon2_permutation_opcode(
+    initial_witness: &mut WitnessMap,
+    inputs: &[FunctionInput],
+    outputs: &[Witness],
+    len: u32,
+) -> Result<(), OpcodeResolutionError> {
+    if outputs.len() != len as usize {
+        return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
+            BlackBoxFunc::Poseidon2Permutation,
+            format!("Expected {len} outputs but encountered {}", outputs.len()),
+        ));
+    }
+
+    let state = inputs
+        .iter()
+        .map(|input| witness_to_value(initial_witness, input.witness).copied())
+        .collect::<Result<Vec<_>, _>>()?;
+    let permuted_state = poseidon2_permutation(&state, len)?;
+
+    for (output_witness, value) in outputs.iter().zip(permuted_state) {
+        insert_value(output_witness, value, initial_witness)?;
+    }
+    Ok(())
+}
+
+/// Attempts to solve a `Keccakf1600` opcode
+/// If successful, `initial_witness` will be mutated to contain the permuted lanes of the state.
+pub(super) fn solve_keccakf1600_opcode(
+    initial_witness: &mut WitnessMap,
+    inputs: &[FunctionInput],
+    outputs: &[Witness],
+) -> Result<(), OpcodeResolutionError> {
+    if inputs.len() != 25 || outputs.len() != 25 {
+        return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
+            BlackBoxFunc::Keccakf1600,
+            format!(
+                "Expected a state of 25 lanes but encountered {} inputs and {} outputs",
+                inputs.len(),
+                outputs.len()
+            ),
+        ));
+    }
+
+    let mut state = [0; 25];
+    for (lane, input) in state.iter_mut().zip(inputs) {
+        let value = witness_to_value(initial_witness, input.witness)?;
+        *lane = value.try_to_u64().ok_or_else(|| {
+            OpcodeResolutionError::BlackBoxFunctionFailed(
+                BlackBoxFunc::Keccakf1600,
+                format!("Lane {} does not fit in 64 bits", value.to_hex()),
+            )
+        })?;
+    }
+    let permuted_state = keccakf1600(state)?;
+
+    for (output_witness, lane) in outputs.iter().zip(permuted_state) {
+        insert_value(output_witness, FieldElement::from(lane as u128), initial_witness)?;
+    }
+    Ok(())
+}
+
+/// Attempts to solve a `Sha256Compression` opcode
+/// If successful, `initial_witness` will be mutated to contain the new hash values.
+pub(super) fn solve_sha256_compression_opcode(
+    initial_witness: &mut WitnessMap,
+    inputs: &[FunctionInput],
+    hash_values: &[FunctionInput],
+    outputs: &[Witness],
+) -> Result<(), OpcodeResolutionError> {
+    if inputs.len() != 16 || hash_values.len() != 8 || outputs.len() != 8 {
+        return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
+            BlackBoxFunc::Sha256Compression,
+            format!(
+                "Expected a block of 16 words and a state of 8 words but encountered {} inputs, {} hash values and {} outputs",
+                inputs.len(),
+                hash_values.len(),
+                outputs.len()
+            ),
+        ));
+    }
+
+    let mut block = [0; 16];
+    read_u32_words(initial_witness, inputs, &mut block)?;
+    let mut state = [0; 8];
+    read_u32_words(initial_witness, hash_values, &mut state)?;
+    let state = sha256_compression(state, block)?;
+
+    for (output_witness, word) in outputs.iter().zip(state) {
+        insert_value(output_witness, FieldElement::from(word as u128), initial_witness)?;
+    }
+    Ok(())
+}
+
+fn read_u32_words(
+    initial_witness: &WitnessMap,
+    inputs: &[FunctionInput],
+    words: &mut [u32],
+) -> Result<(), OpcodeResolutionError> {
+    for (word, input) in words.iter_mut().zip(inputs) {
+        let value = witness_to_value(initial_witness, input.witness)?;
+        *word =
+            value.try_to_u64().and_then(|value| u32::try_from(value).ok()).ok_or_else(|| {
+                OpcodeResolutionError::BlackBoxFunctionFailed(
+                    BlackBoxFunc::Sha256Compression,
+                    format!("Word {} does not fit in 32 bits", value.to_hex()),
+                )
+            })?;
+    }
+    Ok(())
+}
@@ -3,13 +3,15 @@ use acir::{
     native_types::{Witness, WitnessMap},
     FieldElement,
 };
-use acvm_blackbox_solver::{blake2s, keccak256, sha256};
+use acvm_blackbox_solver::{blake2s, blake3, keccak256, sha256, BigIntSolver};
 
 use self::pedersen::pedersen_hash;
 
 use super::{insert_value, OpcodeNotSolvable, OpcodeResolutionError};
 use crate::BlackBoxFunctionSolver;
 
+mod bigint;
+mod embedded_curve_ops;
 mod fixed_base_scalar_mul;
 mod hash;
 mod logic;
@@ -17,9 +19,14 @@ mod pedersen;
 mod range;
 mod signature;
 
+use bigint::{bigint_from_le_bytes, bigint_to_le_bytes};
+use embedded_curve_ops::{embedded_curve_add, embedded_curve_double};
 use fixed_base_scalar_mul::fixed_base_scalar_mul;
 // Hash functions should eventually be exposed for external consumers.
-use hash::{solve_generic_256_hash_opcode, solve_hash_to_field};
+use hash::{
+    solve_generic_256_hash_opcode, solve_hash_to_field, solve_keccakf1600_opcode,
+    solve_poseidon2_permutation_opcode, solve_sha256_compression_opcode,
+};
 use logic::{and, xor};
 use pedersen::pedersen;
 use range::solve_range_opcode;
@@ -53,6 +60,7 @@ pub(crate) fn solve(
     backend: &impl BlackBoxFunctionSolver,
     initial_witness: &mut WitnessMap,
     bb_func: &BlackBoxFuncCall,
+    bigint_solver: &mut BigIntSolver,
 ) -> Result<(), OpcodeResolutionError> {
     let inputs = bb_func.get_inputs_vec();
     if !contains_all_inputs(initial_witness, &inputs) {
@@ -83,6 +91,14 @@ pub(crate) fn solve(
             blake2s,
             bb_func.get_black_box_func(),
         ),
+        BlackBoxFuncCall::Blake3 { inputs, outputs } => solve_generic_256_hash_opcode(
+            initial_witness,
+            inputs,
+            None,
+            outputs,
+            blake3,
+            bb_func.get_black_box_func(),
+        ),
         BlackBoxFuncCall::Keccak256 { inputs, outputs } => solve_generic_256_hash_opcode(
             initial_witness,
             inputs,
@@ -164,5 +180,41 @@ pub(crate) fn solve(
             }
             Ok(())
         }
+        BlackBoxFuncCall::Poseidon2Permutation { inputs, outputs, len } => {
+            solve_poseidon2_permutation_opcode(initial_witness, inputs, outputs, *len)
+        }
+        BlackBoxFuncCall::EmbeddedCurveAdd { input1_x, input1_y, input2_x, input2_y, outputs } => {
+            embedded_curve_add(
+                backend,
+                initial_witness,
+                *input1_x,
+                *input1_y,
+                *input2_x,
+                *input2_y,
+                *outputs,
+            )
+        }
+        BlackBoxFuncCall::EmbeddedCurveDouble { input_x, input_y, outputs } => {
+            embedded_curve_double(backend, initial_witness, *input_x, *input_y, *outputs)
+        }
+        BlackBoxFuncCall::Keccakf1600 { inputs, outputs } => {
+            solve_keccakf1600_opcode(initial_witness, inputs, outputs)
+        }
+        BlackBoxFuncCall::Sha256Compression { inputs, hash_values, outputs } => {
+            solve_sha256_compression_opcode(initial_witness, inputs, hash_values, outputs)
+        }
+        BlackBoxFuncCall::BigIntAdd { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntSub { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntMul { lhs, rhs, output }
+        | BlackBoxFuncCall::BigIntDiv { lhs, rhs, output } => {
+            bigint_solver.bigint_op(*lhs, *rhs, *output, bb_func.get_black_box_func())?;
+            Ok(())
+        }
+        BlackBoxFuncCall::BigIntFromLeBytes { inputs, modulus, output } => {
+            bigint_from_le_bytes(initial_witness, inputs, modulus, *output, bigint_solver)
+        }
+        BlackBoxFuncCall::BigIntToLeBytes { input, outputs } => {
+            bigint_to_le_bytes(initial_witness, *input, outputs, bigint_solver)
+        }
     }
 }
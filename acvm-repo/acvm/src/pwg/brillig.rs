@@ -1,14 +1,14 @@
 use acir::{
-    brillig::{ForeignCallParam, ForeignCallResult, RegisterIndex, Value},
+    brillig::{ForeignCallParam, ForeignCallResult, Opcode as BrilligOpcode, RegisterIndex, Value},
     circuit::{
-        brillig::{Brillig, BrilligInputs, BrilligOutputs},
-        OpcodeLocation,
+        brillig::{Brillig, BrilligBytecode, BrilligInputs, BrilligOutputs},
+        Opcode, OpcodeLocation,
     },
-    native_types::WitnessMap,
+    native_types::{Expression, WitnessMap},
     FieldElement,
 };
 use acvm_blackbox_solver::BlackBoxFunctionSolver;
-use brillig_vm::{Registers, VMStatus, VM};
+use brillig_vm::{ExecutionLimits, Registers, VMStatus, VM};
 
 use crate::{pwg::OpcodeNotSolvable, OpcodeResolutionError};
 
@@ -21,6 +21,43 @@ pub enum BrilligSolverStatus {
     ForeignCallWait(ForeignCallWaitInfo),
 }
 
+/// A block of Brillig bytecode to be executed by the ACVM, taken either from a [Brillig][Opcode::Brillig]
+/// opcode or from the unconstrained function called by a [BrilligCall][Opcode::BrilligCall] opcode.
+#[derive(Clone, Copy)]
+pub(super) struct BrilligBlock<'b> {
+    pub(super) inputs: &'b [BrilligInputs],
+    pub(super) outputs: &'b [BrilligOutputs],
+    pub(super) bytecode: &'b [BrilligOpcode],
+    pub(super) predicate: Option<&'b Expression>,
+}
+
+impl<'b> BrilligBlock<'b> {
+    /// Returns the Brillig block executed by `opcode`, or `None` if `opcode` does not execute Brillig bytecode.
+    pub(super) fn from_opcode(
+        opcode: &'b Opcode,
+        unconstrained_functions: &'b [BrilligBytecode],
+    ) -> Option<Result<Self, OpcodeResolutionError>> {
+        match opcode {
+            Opcode::Brillig(Brillig { inputs, outputs, bytecode, predicate }) => {
+                Some(Ok(BrilligBlock { inputs, outputs, bytecode, predicate: predicate.as_ref() }))
+            }
+            Opcode::BrilligCall { id, inputs, outputs, predicate } => {
+                let block = match unconstrained_functions.get(*id as usize) {
+                    Some(function) => Ok(BrilligBlock {
+                        inputs,
+                        outputs,
+                        bytecode: &function.bytecode,
+                        predicate: predicate.as_ref(),
+                    }),
+                    None => Err(OpcodeResolutionError::UnknownUnconstrainedFunction(*id)),
+                };
+                Some(block)
+            }
+            _ => None,
+        }
+    }
+}
+
 pub struct BrilligSolver<'b, B: BlackBoxFunctionSolver> {
     vm: VM<'b, B>,
     acir_index: usize,
@@ -30,22 +67,22 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     /// Evaluates if the Brillig block should be skipped entirely
     pub(super) fn should_skip(
         witness: &WitnessMap,
-        brillig: &Brillig,
+        brillig: BrilligBlock<'_>,
     ) -> Result<bool, OpcodeResolutionError> {
         // If the predicate is `None`, the block should never be skipped
         // If the predicate is `Some` but we cannot find a value, then we return stalled
-        match &brillig.predicate {
+        match brillig.predicate {
             Some(pred) => Ok(get_value(pred, witness)?.is_zero()),
             None => Ok(false),
         }
     }
 
-    /// Assigns the zero value to all outputs of the given [`Brillig`] bytecode.
+    /// Assigns the zero value to all outputs of the given Brillig block.
     pub(super) fn zero_out_brillig_outputs(
         initial_witness: &mut WitnessMap,
-        brillig: &Brillig,
+        brillig: BrilligBlock<'_>,
     ) -> Result<(), OpcodeResolutionError> {
-        for output in &brillig.outputs {
+        for output in brillig.outputs {
             match output {
                 BrilligOutputs::Simple(witness) => {
                     insert_value(witness, FieldElement::zero(), initial_witness)?;
@@ -64,7 +101,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     /// witness.
     pub(super) fn new(
         initial_witness: &WitnessMap,
-        brillig: &'b Brillig,
+        brillig: BrilligBlock<'b>,
         bb_solver: &'b B,
         acir_index: usize,
     ) -> Result<Self, OpcodeResolutionError> {
@@ -75,7 +112,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
         // Iterate over each input and evaluate the expression(s) associated with it.
         // Push the results into registers and/or memory.
         // If a certain expression is not solvable, we stall the ACVM and do not proceed with Brillig VM execution.
-        for input in &brillig.inputs {
+        for input in brillig.inputs {
             match input {
                 BrilligInputs::Single(expr) => match get_value(expr, initial_witness) {
                     Ok(value) => input_register_values.push(value.into()),
@@ -108,7 +145,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
         // Instantiate a Brillig VM given the solved input registers and memory
         // along with the Brillig bytecode.
         let input_registers = Registers::load(input_register_values);
-        let vm = VM::new(input_registers, input_memory, &brillig.bytecode, vec![], bb_solver);
+        let vm = VM::new(input_registers, input_memory, brillig.bytecode, vec![], bb_solver);
         Ok(Self { vm, acir_index })
     }
 
@@ -126,6 +163,33 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
         self.vm.program_counter()
     }
 
+    /// Sets the bounds on the resources the Brillig process may consume.
+    pub(super) fn set_limits(&mut self, limits: ExecutionLimits) {
+        self.vm.set_limits(limits);
+    }
+
+    /// Returns the number of Brillig opcodes which have been executed so far.
+    pub(super) fn steps(&self) -> usize {
+        self.vm.steps()
+    }
+
+    /// Starts recording the number of times each Brillig opcode is executed.
+    pub(super) fn enable_profiling(&mut self) {
+        self.vm.enable_profiling();
+    }
+
+    /// Returns the number of times each Brillig opcode has been executed, skipping opcodes
+    /// which were never reached.
+    pub(super) fn profiling_samples(&self) -> impl Iterator<Item = (OpcodeLocation, usize)> + '_ {
+        let acir_index = self.acir_index;
+        self.vm.profiling_samples().unwrap_or_default().iter().enumerate().filter_map(
+            move |(brillig_index, count)| {
+                (*count > 0)
+                    .then_some((OpcodeLocation::Brillig { acir_index, brillig_index }, *count))
+            },
+        )
+    }
+
     fn handle_vm_status(
         &self,
         vm_status: VMStatus,
@@ -149,6 +213,18 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
                         .collect(),
                 })
             }
+            VMStatus::LimitExceeded { limit, call_stack } => {
+                Err(OpcodeResolutionError::ExecutionLimitExceeded {
+                    limit,
+                    call_stack: call_stack
+                        .iter()
+                        .map(|brillig_index| OpcodeLocation::Brillig {
+                            acir_index: self.acir_index,
+                            brillig_index: *brillig_index,
+                        })
+                        .collect(),
+                })
+            }
             VMStatus::ForeignCallWait { function, inputs } => {
                 Ok(BrilligSolverStatus::ForeignCallWait(ForeignCallWaitInfo { function, inputs }))
             }
@@ -158,7 +234,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     pub(super) fn finalize(
         self,
         witness: &mut WitnessMap,
-        brillig: &Brillig,
+        brillig: BrilligBlock<'_>,
     ) -> Result<(), OpcodeResolutionError> {
         // Finish the Brillig execution by writing the outputs to the witness map
         let vm_status = self.vm.get_status();
@@ -174,7 +250,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     fn write_brillig_outputs(
         &self,
         witness_map: &mut WitnessMap,
-        brillig: &Brillig,
+        brillig: BrilligBlock<'_>,
     ) -> Result<(), OpcodeResolutionError> {
         // Write VM execution results into the witness map
         for (i, output) in brillig.outputs.iter().enumerate() {
@@ -1,16 +1,19 @@
 // Re-usable methods that backends can use to implement their PWG
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use acir::{
     brillig::ForeignCallResult,
-    circuit::{opcodes::BlockId, Opcode, OpcodeLocation},
+    circuit::{brillig::BrilligBytecode, opcodes::BlockId, Opcode, OpcodeLocation},
     native_types::{Expression, Witness, WitnessMap},
     BlackBoxFunc, FieldElement,
 };
-use acvm_blackbox_solver::BlackBoxResolutionError;
+use acvm_blackbox_solver::{BigIntSolver, BlackBoxResolutionError};
 
-use self::{arithmetic::ArithmeticSolver, directives::solve_directives, memory_op::MemoryOpSolver};
+use self::{
+    arithmetic::ArithmeticSolver, brillig::BrilligBlock, directives::solve_directives,
+    memory_op::MemoryOpSolver,
+};
 use crate::{BlackBoxFunctionSolver, Language};
 
 use thiserror::Error;
@@ -24,9 +27,17 @@ mod directives;
 // black box functions
 mod blackbox;
 mod memory_op;
+// concurrent solving of independent opcodes
+#[cfg(feature = "parallel")]
+mod parallel;
 
 pub use self::brillig::{BrilligSolver, BrilligSolverStatus};
 pub use brillig::ForeignCallWaitInfo;
+pub use brillig_vm::{ExceededLimit, ExecutionLimits};
+
+/// The number of times each Brillig opcode was executed while solving a circuit,
+/// keyed by the [location][OpcodeLocation::Brillig] of the opcode.
+pub type ProfilingSamples = BTreeMap<OpcodeLocation, usize>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ACVMStatus {
@@ -46,6 +57,22 @@ pub enum ACVMStatus {
     ///
     /// Once this is done, the ACVM can be restarted to solve the remaining opcodes.
     RequiresForeignCall(ForeignCallWaitInfo),
+
+    /// The ACVM has encountered a [call][Opcode::Call] to another ACIR function of the program, which
+    /// must be solved as a separate circuit. The return values of the call must be passed back to the
+    /// ACVM using [`ACVM::resolve_pending_acir_call`].
+    ///
+    /// Once this is done, the ACVM can be restarted to solve the remaining opcodes.
+    RequiresAcirCall(AcirCallWaitInfo),
+}
+
+/// A call to another ACIR function which must be solved before the ACVM can continue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcirCallWaitInfo {
+    /// Index of the called function within the program's list of functions.
+    pub id: u32,
+    /// The values passed to the function's parameters, in order.
+    pub inputs: Vec<FieldElement>,
 }
 
 impl std::fmt::Display for ACVMStatus {
@@ -55,6 +82,7 @@ impl std::fmt::Display for ACVMStatus {
             ACVMStatus::InProgress => write!(f, "In progress"),
             ACVMStatus::Failure(_) => write!(f, "Execution failure"),
             ACVMStatus::RequiresForeignCall(_) => write!(f, "Waiting on foreign call"),
+            ACVMStatus::RequiresAcirCall(_) => write!(f, "Waiting on ACIR call"),
         }
     }
 }
@@ -114,6 +142,10 @@ pub enum OpcodeResolutionError {
     BlackBoxFunctionFailed(BlackBoxFunc, String),
     #[error("Failed to solve brillig function, reason: {message}")]
     BrilligFunctionFailed { message: String, call_stack: Vec<OpcodeLocation> },
+    #[error("Execution halted, reason: {limit}")]
+    ExecutionLimitExceeded { limit: ExceededLimit, call_stack: Vec<OpcodeLocation> },
+    #[error("Cannot find unconstrained function {0} called by a Brillig call opcode")]
+    UnknownUnconstrainedFunction(u32),
 }
 
 impl From<BlackBoxResolutionError> for OpcodeResolutionError {
@@ -137,14 +169,34 @@ pub struct ACVM<'a, B: BlackBoxFunctionSolver> {
     /// Stores the solver for memory operations acting on blocks of memory disambiguated by [block][`BlockId`].
     block_solvers: HashMap<BlockId, MemoryOpSolver>,
 
+    /// Stores the big integers created by the big integer black box functions of the circuit.
+    bigint_solver: BigIntSolver,
+
     /// A list of opcodes which are to be executed by the ACVM.
     opcodes: &'a [Opcode],
+    /// The bytecode of the unconstrained functions called by [BrilligCall][Opcode::BrilligCall] opcodes.
+    unconstrained_functions: &'a [BrilligBytecode],
     /// Index of the next opcode to be executed.
     instruction_pointer: usize,
 
     witness_map: WitnessMap,
 
     brillig_solver: Option<BrilligSolver<'a, B>>,
+
+    /// Whether to record the number of times each Brillig opcode is executed.
+    profiling_active: bool,
+
+    profiling_samples: ProfilingSamples,
+
+    /// Bounds on the resources which may be consumed while solving the circuit.
+    execution_limits: ExecutionLimits,
+
+    /// The number of steps taken so far, counting both ACIR opcodes and the Brillig opcodes they execute.
+    steps: usize,
+
+    /// Opcodes which have been solved ahead of the instruction pointer by [`ACVM::solve_parallel`].
+    #[cfg(feature = "parallel")]
+    presolver: parallel::Presolver,
 }
 
 impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
@@ -154,13 +206,50 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
             status,
             backend,
             block_solvers: HashMap::default(),
+            bigint_solver: BigIntSolver::default(),
             opcodes,
+            unconstrained_functions: &[],
             instruction_pointer: 0,
             witness_map: initial_witness,
             brillig_solver: None,
+            profiling_active: false,
+            profiling_samples: ProfilingSamples::new(),
+            execution_limits: ExecutionLimits::default(),
+            steps: 0,
+            #[cfg(feature = "parallel")]
+            presolver: parallel::Presolver::default(),
         }
     }
 
+    /// Sets the bounds on the resources which may be consumed while solving the circuit.
+    ///
+    /// The step limit applies to the total number of ACIR opcodes solved and Brillig opcodes executed,
+    /// while the memory limit applies to each Brillig process individually.
+    /// Exceeding either limit fails execution with [`OpcodeResolutionError::ExecutionLimitExceeded`].
+    pub fn set_execution_limits(&mut self, execution_limits: ExecutionLimits) {
+        self.execution_limits = execution_limits;
+    }
+
+    /// Sets the unconstrained functions which may be called by [BrilligCall][Opcode::BrilligCall] opcodes,
+    /// indexed by the `id` of the call.
+    pub fn set_unconstrained_functions(&mut self, unconstrained_functions: &'a [BrilligBytecode]) {
+        self.unconstrained_functions = unconstrained_functions;
+    }
+
+    /// Enables recording of the number of times each Brillig opcode is executed.
+    ///
+    /// The samples collected so far can be retrieved using [`ACVM::profiling_samples`].
+    pub fn enable_profiling(&mut self) {
+        self.profiling_active = true;
+    }
+
+    /// Returns the number of times each Brillig opcode has been executed.
+    ///
+    /// This is empty unless profiling has been enabled through [`ACVM::enable_profiling`].
+    pub fn profiling_samples(&self) -> &ProfilingSamples {
+        &self.profiling_samples
+    }
+
     /// Returns a reference to the current state of the ACVM's [`WitnessMap`].
     ///
     /// Once execution has completed, the witness map can be extracted using [`ACVM::finalize`]
@@ -233,12 +322,57 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         self.status(ACVMStatus::InProgress);
     }
 
+    /// Sets the status of the VM to `RequiresAcirCall`.
+    /// Indicating that the VM is now waiting for a call to another ACIR function to be resolved.
+    fn wait_for_acir_call(&mut self, acir_call: AcirCallWaitInfo) -> ACVMStatus {
+        self.status(ACVMStatus::RequiresAcirCall(acir_call))
+    }
+
+    /// Return a reference to the arguments for the next pending ACIR call, if one exists.
+    pub fn get_pending_acir_call(&self) -> Option<&AcirCallWaitInfo> {
+        if let ACVMStatus::RequiresAcirCall(acir_call) = &self.status {
+            Some(acir_call)
+        } else {
+            None
+        }
+    }
+
+    /// Resolves a pending ACIR call using the return values of the called function, which have been
+    /// calculated by solving the function's circuit outside of this ACVM.
+    ///
+    /// The ACVM can then be restarted to solve the remaining ACIR opcodes.
+    pub fn resolve_pending_acir_call(&mut self, call_result: Vec<FieldElement>) {
+        if !matches!(self.status, ACVMStatus::RequiresAcirCall(_)) {
+            panic!("ACVM is not expecting an ACIR call response as no call was made");
+        }
+
+        let Opcode::Call { outputs, .. } = &self.opcodes[self.instruction_pointer] else {
+            unreachable!("Not executing a call opcode");
+        };
+        if outputs.len() != call_result.len() {
+            panic!(
+                "ACIR call returned {} values but {} were expected",
+                call_result.len(),
+                outputs.len()
+            );
+        }
+
+        let resolution = outputs
+            .iter()
+            .zip(call_result)
+            .try_for_each(|(output, value)| insert_value(output, value, &mut self.witness_map));
+
+        // Now that the call has been resolved then we can resume execution.
+        self.handle_opcode_resolution(resolution);
+    }
+
     /// Executes the ACVM's circuit until execution halts.
     ///
     /// Execution can halt due to three reasons:
     /// 1. All opcodes have been executed successfully.
     /// 2. The circuit has been found to be unsatisfiable.
     /// 2. A Brillig [foreign call][`ForeignCallWaitInfo`] has been encountered and must be resolved.
+    /// 3. A [call][`AcirCallWaitInfo`] to another ACIR function has been encountered and must be resolved.
     pub fn solve(&mut self) -> ACVMStatus {
         while self.status == ACVMStatus::InProgress {
             self.solve_opcode();
@@ -247,13 +381,30 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     }
 
     pub fn solve_opcode(&mut self) -> ACVMStatus {
+        if let Some(max_steps) = self.execution_limits.max_steps {
+            if self.steps >= max_steps {
+                return self.fail(OpcodeResolutionError::ExecutionLimitExceeded {
+                    limit: ExceededLimit::Steps(max_steps),
+                    call_stack: vec![OpcodeLocation::Acir(self.instruction_pointer)],
+                });
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        if let Some(resolution) = self.take_presolved_opcode() {
+            return self.handle_opcode_resolution(resolution);
+        }
+
         let opcode = &self.opcodes[self.instruction_pointer];
 
         let resolution = match opcode {
             Opcode::Arithmetic(expr) => ArithmeticSolver::solve(&mut self.witness_map, expr),
-            Opcode::BlackBoxFuncCall(bb_func) => {
-                blackbox::solve(self.backend, &mut self.witness_map, bb_func)
-            }
+            Opcode::BlackBoxFuncCall(bb_func) => blackbox::solve(
+                self.backend,
+                &mut self.witness_map,
+                bb_func,
+                &mut self.bigint_solver,
+            ),
             Opcode::Directive(directive) => solve_directives(&mut self.witness_map, directive),
             Opcode::MemoryInit { block_id, init } => {
                 let solver = self.block_solvers.entry(*block_id).or_default();
@@ -263,10 +414,14 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
                 let solver = self.block_solvers.entry(*block_id).or_default();
                 solver.solve_memory_op(op, &mut self.witness_map, predicate)
             }
-            Opcode::Brillig(_) => match self.solve_brillig_opcode() {
+            Opcode::Brillig(_) | Opcode::BrilligCall { .. } => match self.solve_brillig_opcode() {
                 Ok(Some(foreign_call)) => return self.wait_for_foreign_call(foreign_call),
                 res => res.map(|_| ()),
             },
+            Opcode::Call { .. } => match self.solve_call_opcode() {
+                Ok(Some(acir_call)) => return self.wait_for_acir_call(acir_call),
+                res => res.map(|_| ()),
+            },
         };
         self.handle_opcode_resolution(resolution)
     }
@@ -277,6 +432,7 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     ) -> ACVMStatus {
         match resolution {
             Ok(()) => {
+                self.steps += 1;
                 self.instruction_pointer += 1;
                 if self.instruction_pointer == self.opcodes.len() {
                     self.status(ACVMStatus::Solved)
@@ -311,9 +467,13 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     fn solve_brillig_opcode(
         &mut self,
     ) -> Result<Option<ForeignCallWaitInfo>, OpcodeResolutionError> {
-        let Opcode::Brillig(brillig) = &self.opcodes[self.instruction_pointer] else {
+        let opcodes = self.opcodes;
+        let Some(brillig) =
+            BrilligBlock::from_opcode(&opcodes[self.instruction_pointer], self.unconstrained_functions)
+        else {
             unreachable!("Not executing a Brillig opcode");
         };
+        let brillig = brillig?;
 
         let witness = &mut self.witness_map;
         if BrilligSolver::<B>::should_skip(witness, brillig)? {
@@ -324,9 +484,25 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         // there will be a cached `BrilligSolver` to avoid recomputation.
         let mut solver: BrilligSolver<'_, B> = match self.brillig_solver.take() {
             Some(solver) => solver,
-            None => BrilligSolver::new(witness, brillig, self.backend, self.instruction_pointer)?,
+            None => {
+                let mut solver =
+                    BrilligSolver::new(witness, brillig, self.backend, self.instruction_pointer)?;
+                solver.set_limits(remaining_limits(&self.execution_limits, self.steps));
+                if self.profiling_active {
+                    solver.enable_profiling();
+                }
+                solver
+            }
         };
-        match solver.solve()? {
+        let status = solver.solve();
+        if !matches!(status, Ok(BrilligSolverStatus::ForeignCallWait(_))) {
+            // The Brillig process has halted so we can record how many times each opcode was executed.
+            self.steps += solver.steps();
+            for (location, count) in solver.profiling_samples() {
+                *self.profiling_samples.entry(location).or_default() += count;
+            }
+        }
+        match status? {
             BrilligSolverStatus::ForeignCallWait(foreign_call) => {
                 // Cache the current state of the solver
                 self.brillig_solver = Some(solver);
@@ -343,10 +519,40 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         }
     }
 
+    /// Reads the inputs of the call at the instruction pointer, or zeroes its outputs if its predicate is false.
+    fn solve_call_opcode(&mut self) -> Result<Option<AcirCallWaitInfo>, OpcodeResolutionError> {
+        let Opcode::Call { id, inputs, outputs, predicate } = &self.opcodes[self.instruction_pointer]
+        else {
+            unreachable!("Not executing a call opcode");
+        };
+
+        if let Some(predicate) = predicate {
+            if get_value(predicate, &self.witness_map)?.is_zero() {
+                for output in outputs {
+                    insert_value(output, FieldElement::zero(), &mut self.witness_map)?;
+                }
+                return Ok(None);
+            }
+        }
+
+        let inputs = inputs
+            .iter()
+            .map(|input| witness_to_value(&self.witness_map, *input).copied())
+            .collect::<Result<_, _>>()?;
+        Ok(Some(AcirCallWaitInfo { id: *id, inputs }))
+    }
+
     pub fn step_into_brillig_opcode(&mut self) -> StepResult<'a, B> {
-        let Opcode::Brillig(brillig) = &self.opcodes[self.instruction_pointer] else {
+        let opcodes = self.opcodes;
+        let Some(brillig) =
+            BrilligBlock::from_opcode(&opcodes[self.instruction_pointer], self.unconstrained_functions)
+        else {
             return StepResult::Status(self.solve_opcode());
         };
+        let brillig = match brillig {
+            Ok(brillig) => brillig,
+            Err(err) => return StepResult::Status(self.handle_opcode_resolution(Err(err))),
+        };
 
         let witness = &mut self.witness_map;
         let should_skip = match BrilligSolver::<B>::should_skip(witness, brillig) {
@@ -361,13 +567,22 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
 
         let solver = BrilligSolver::new(witness, brillig, self.backend, self.instruction_pointer);
         match solver {
-            Ok(solver) => StepResult::IntoBrillig(solver),
+            Ok(mut solver) => {
+                solver.set_limits(remaining_limits(&self.execution_limits, self.steps));
+                if self.profiling_active {
+                    solver.enable_profiling();
+                }
+                StepResult::IntoBrillig(solver)
+            }
             Err(..) => StepResult::Status(self.handle_opcode_resolution(solver.map(|_| ()))),
         }
     }
 
     pub fn finish_brillig_with_solver(&mut self, solver: BrilligSolver<'a, B>) -> ACVMStatus {
-        if !matches!(&self.opcodes[self.instruction_pointer], Opcode::Brillig(..)) {
+        if !matches!(
+            &self.opcodes[self.instruction_pointer],
+            Opcode::Brillig(..) | Opcode::BrilligCall { .. }
+        ) {
             unreachable!("Not executing a Brillig opcode");
         }
         self.brillig_solver = Some(solver);
@@ -375,6 +590,14 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     }
 }
 
+/// Returns the limits to apply to a Brillig process given that `steps` have already been taken.
+fn remaining_limits(execution_limits: &ExecutionLimits, steps: usize) -> ExecutionLimits {
+    ExecutionLimits {
+        max_steps: execution_limits.max_steps.map(|max_steps| max_steps.saturating_sub(steps)),
+        ..*execution_limits
+    }
+}
+
 // Returns the concrete value for a particular witness
 // If the witness has no assignment, then
 // an error is returned
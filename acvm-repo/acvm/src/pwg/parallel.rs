@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use acir::{
+    brillig::Opcode as BrilligOpcode,
+    circuit::{brillig::BrilligInputs, opcodes::BlackBoxFuncCall, Opcode},
+    native_types::{Expression, Witness, WitnessMap},
+};
+use acvm_blackbox_solver::BigIntSolver;
+use rayon::prelude::*;
+
+use super::{
+    blackbox, brillig::BrilligBlock, insert_value, remaining_limits, ACVMStatus, BrilligSolver,
+    BrilligSolverStatus, ExceededLimit, ExecutionLimits, OpcodeResolutionError, ProfilingSamples,
+    ACVM,
+};
+use crate::BlackBoxFunctionSolver;
+
+/// The number of opcodes past the instruction pointer which are searched for opcodes to solve concurrently.
+const LOOKAHEAD: usize = 4096;
+
+/// Tracks the opcodes which may be solved ahead of the instruction pointer along with
+/// the results of those which have been.
+#[derive(Default)]
+pub(super) struct Presolver {
+    /// For each opcode of the circuit, the witnesses which must be assigned before it can be solved
+    /// or `None` if the opcode must be solved in order.
+    dependencies: Option<Vec<Option<Vec<Witness>>>>,
+
+    /// The opcodes solved ahead of the instruction pointer, keyed by their index.
+    results: HashMap<usize, PresolvedOpcode>,
+}
+
+/// The outcome of solving an opcode ahead of the instruction pointer.
+struct PresolvedOpcode {
+    /// The witness assignments produced by the opcode.
+    result: Result<WitnessMap, OpcodeResolutionError>,
+    /// The limits the opcode was solved under.
+    ///
+    /// Only the steps taken before the opcode was presolved are known at that point, so its step budget
+    /// may be larger than the one left once the instruction pointer reaches it.
+    limits: ExecutionLimits,
+    /// The number of Brillig opcodes executed.
+    steps: usize,
+    /// The number of times each Brillig opcode was executed, if profiling is active.
+    profiling_samples: ProfilingSamples,
+}
+
+impl PresolvedOpcode {
+    /// Returns whether solving the opcode under `limits` would have produced the same outcome.
+    fn holds_under(&self, limits: &ExecutionLimits) -> bool {
+        if self.limits == *limits {
+            return true;
+        }
+        let within_steps = limits.max_steps.map_or(true, |max_steps| self.steps <= max_steps);
+        let exceeded_steps = matches!(
+            self.result,
+            Err(OpcodeResolutionError::ExecutionLimitExceeded {
+                limit: ExceededLimit::Steps(_),
+                ..
+            })
+        );
+        within_steps && !exceeded_steps
+    }
+}
+
+impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
+    /// Applies the result of the opcode at the instruction pointer if it has already been solved
+    /// by [`ACVM::solve_parallel`].
+    ///
+    /// Returns `None` if the opcode must be solved again, as it was presolved with a larger step budget
+    /// than the one which remains.
+    pub(super) fn take_presolved_opcode(&mut self) -> Option<Result<(), OpcodeResolutionError>> {
+        let presolved = self.presolver.results.remove(&self.instruction_pointer)?;
+        if !presolved.holds_under(&remaining_limits(&self.execution_limits, self.steps)) {
+            return None;
+        }
+
+        self.steps += presolved.steps;
+        for (location, count) in presolved.profiling_samples {
+            *self.profiling_samples.entry(location).or_default() += count;
+        }
+        Some(presolved.result.and_then(|assignments| {
+            assignments.into_iter().try_for_each(|(witness, value)| {
+                insert_value(&witness, value, &mut self.witness_map)
+            })
+        }))
+    }
+}
+
+impl<'a, B: BlackBoxFunctionSolver + Sync> ACVM<'a, B> {
+    /// Executes the ACVM's circuit until execution halts, solving independent opcodes concurrently.
+    ///
+    /// Black box function calls (other than the cheap logic and range opcodes) and Brillig blocks which
+    /// make no foreign calls only depend on the witnesses they read. Whenever the next opcode is one of these,
+    /// every such opcode within a window ahead of it whose inputs are already assigned is solved on the
+    /// rayon thread pool. The remaining opcodes are then solved in order as in [`ACVM::solve`], picking up
+    /// the precomputed assignments as they are reached, so the resulting [`WitnessMap`] and any error
+    /// are identical to those of [`ACVM::solve`].
+    ///
+    /// Brillig blocks are presolved with the step budget which remains when they are scheduled. If fewer steps
+    /// remain once the instruction pointer reaches a block and the block needed more of them, it is solved again
+    /// in order so that it fails exactly as it would have in [`ACVM::solve`].
+    pub fn solve_parallel(&mut self) -> ACVMStatus {
+        if self.presolver.dependencies.is_none() {
+            let dependencies =
+                self.opcodes.iter().map(|opcode| self.dependencies(opcode)).collect();
+            self.presolver.dependencies = Some(dependencies);
+        }
+
+        while self.status == ACVMStatus::InProgress {
+            if self.is_presolvable(self.instruction_pointer)
+                && !self.presolver.results.contains_key(&self.instruction_pointer)
+            {
+                self.presolve_ready_opcodes();
+            }
+            self.solve_opcode();
+        }
+        self.status.clone()
+    }
+
+    /// Returns the witnesses read by `opcode` if it may be solved out of order.
+    fn dependencies(&self, opcode: &Opcode) -> Option<Vec<Witness>> {
+        match opcode {
+            Opcode::BlackBoxFuncCall(
+                BlackBoxFuncCall::AND { .. }
+                | BlackBoxFuncCall::XOR { .. }
+                | BlackBoxFuncCall::RANGE { .. }
+                | BlackBoxFuncCall::RecursiveAggregation { .. }
+                // Big integer opcodes read and write the big integers of the circuit.
+                | BlackBoxFuncCall::BigIntAdd { .. }
+                | BlackBoxFuncCall::BigIntSub { .. }
+                | BlackBoxFuncCall::BigIntMul { .. }
+                | BlackBoxFuncCall::BigIntDiv { .. }
+                | BlackBoxFuncCall::BigIntFromLeBytes { .. }
+                | BlackBoxFuncCall::BigIntToLeBytes { .. },
+            ) => None,
+            Opcode::BlackBoxFuncCall(bb_func) => {
+                Some(bb_func.get_inputs_vec().iter().map(|input| input.witness).collect())
+            }
+            Opcode::Brillig(_) | Opcode::BrilligCall { .. } => {
+                // Calls to unknown functions are left to fail when they are reached.
+                let brillig =
+                    BrilligBlock::from_opcode(opcode, self.unconstrained_functions)?.ok()?;
+                let has_foreign_calls = brillig
+                    .bytecode
+                    .iter()
+                    .any(|opcode| matches!(opcode, BrilligOpcode::ForeignCall { .. }));
+                (!has_foreign_calls).then(|| brillig_inputs(brillig))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_presolvable(&self, opcode_index: usize) -> bool {
+        let dependencies = self.presolver.dependencies.as_ref().expect("dependencies are computed");
+        dependencies[opcode_index].is_some()
+    }
+
+    /// Solves every presolvable opcode within the lookahead window whose inputs are all assigned.
+    fn presolve_ready_opcodes(&mut self) {
+        let dependencies = self.presolver.dependencies.as_ref().expect("dependencies are computed");
+        let window_end = (self.instruction_pointer + LOOKAHEAD).min(self.opcodes.len());
+
+        // Each ready opcode is handed only the assignments of the witnesses it reads.
+        let ready: Vec<(usize, WitnessMap)> = (self.instruction_pointer..window_end)
+            .filter(|opcode_index| !self.presolver.results.contains_key(opcode_index))
+            .filter_map(|opcode_index| {
+                let inputs = dependencies[opcode_index].as_ref()?;
+                let mut assignments = WitnessMap::new();
+                for witness in inputs {
+                    assignments.insert(*witness, *self.witness_map.get(witness)?);
+                }
+                Some((opcode_index, assignments))
+            })
+            .collect();
+
+        let backend = self.backend;
+        let opcodes = self.opcodes;
+        let unconstrained_functions = self.unconstrained_functions;
+        let limits = remaining_limits(&self.execution_limits, self.steps);
+        let profiling_active = self.profiling_active;
+        let results: Vec<_> = ready
+            .into_par_iter()
+            .map(|(opcode_index, mut assignments)| {
+                let mut presolved = PresolvedOpcode {
+                    result: Ok(WitnessMap::new()),
+                    limits,
+                    steps: 0,
+                    profiling_samples: ProfilingSamples::new(),
+                };
+                let result = match &opcodes[opcode_index] {
+                    Opcode::BlackBoxFuncCall(bb_func) => {
+                        // Big integer opcodes are never presolved, so no big integers are needed.
+                        blackbox::solve(
+                            backend,
+                            &mut assignments,
+                            bb_func,
+                            &mut BigIntSolver::default(),
+                        )
+                    }
+                    opcode @ (Opcode::Brillig(_) | Opcode::BrilligCall { .. }) => {
+                        BrilligBlock::from_opcode(opcode, unconstrained_functions)
+                            .expect("opcode executes Brillig bytecode")
+                            .and_then(|brillig| {
+                                solve_brillig(
+                                    backend,
+                                    &mut assignments,
+                                    brillig,
+                                    opcode_index,
+                                    profiling_active,
+                                    &mut presolved,
+                                )
+                            })
+                    }
+                    _ => unreachable!("only black box calls and Brillig blocks are presolved"),
+                };
+                presolved.result = result.map(|_| assignments);
+                (opcode_index, presolved)
+            })
+            .collect();
+
+        self.presolver.results.extend(results);
+    }
+}
+
+/// Solves a Brillig block which makes no foreign calls under the limits of `presolved`, writing its
+/// outputs into `witness` and recording the opcodes it executed in `presolved`.
+fn solve_brillig<B: BlackBoxFunctionSolver>(
+    backend: &B,
+    witness: &mut WitnessMap,
+    brillig: BrilligBlock<'_>,
+    acir_index: usize,
+    profiling_active: bool,
+    presolved: &mut PresolvedOpcode,
+) -> Result<(), OpcodeResolutionError> {
+    if BrilligSolver::<B>::should_skip(witness, brillig)? {
+        return BrilligSolver::<B>::zero_out_brillig_outputs(witness, brillig);
+    }
+
+    let mut solver = BrilligSolver::new(witness, brillig, backend, acir_index)?;
+    solver.set_limits(presolved.limits);
+    if profiling_active {
+        solver.enable_profiling();
+    }
+    let status = solver.solve();
+    presolved.steps = solver.steps();
+    presolved.profiling_samples.extend(solver.profiling_samples());
+    match status? {
+        BrilligSolverStatus::Finished => solver.finalize(witness, brillig),
+        BrilligSolverStatus::InProgress | BrilligSolverStatus::ForeignCallWait(_) => {
+            unreachable!("Brillig blocks without foreign calls run to completion")
+        }
+    }
+}
+
+/// Returns the witnesses read by the inputs and predicate of a Brillig block.
+fn brillig_inputs(brillig: BrilligBlock<'_>) -> Vec<Witness> {
+    let mut expressions: Vec<&Expression> = brillig
+        .inputs
+        .iter()
+        .flat_map(|input| match input {
+            BrilligInputs::Single(expr) => std::slice::from_ref(expr).iter(),
+            BrilligInputs::Array(expr_arr) => expr_arr.iter(),
+        })
+        .collect();
+    expressions.extend(brillig.predicate);
+
+    let mut witnesses: Vec<Witness> = expressions
+        .into_iter()
+        .flat_map(|expr| {
+            let mul_witnesses = expr.mul_terms.iter().flat_map(|(_, lhs, rhs)| [*lhs, *rhs]);
+            let linear_witnesses = expr.linear_combinations.iter().map(|(_, witness)| *witness);
+            mul_witnesses.chain(linear_witnesses).collect::<Vec<_>>()
+        })
+        .collect();
+    witnesses.sort_unstable();
+    witnesses.dedup();
+    witnesses
+}
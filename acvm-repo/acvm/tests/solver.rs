@@ -3,17 +3,22 @@ use std::collections::BTreeMap;
 use acir::{
     brillig::{BinaryFieldOp, Opcode as BrilligOpcode, RegisterIndex, RegisterOrMemory, Value},
     circuit::{
-        brillig::{Brillig, BrilligInputs, BrilligOutputs},
-        opcodes::{BlockId, MemOp},
-        Opcode, OpcodeLocation,
+        brillig::{Brillig, BrilligBytecode, BrilligInputs, BrilligOutputs},
+        directives::Directive,
+        opcodes::{BlackBoxFuncCall, BlockId, FunctionInput, MemOp},
+        Circuit, Opcode, OpcodeLocation,
     },
     native_types::{Expression, Witness, WitnessMap},
     FieldElement,
 };
 
 use acvm::{
-    pwg::{ACVMStatus, ErrorLocation, ForeignCallWaitInfo, OpcodeResolutionError, ACVM},
-    BlackBoxFunctionSolver,
+    compiler,
+    pwg::{
+        ACVMStatus, AcirCallWaitInfo, ErrorLocation, ExceededLimit, ExecutionLimits,
+        ForeignCallWaitInfo, OpcodeResolutionError, ACVM,
+    },
+    BlackBoxFunctionSolver, Language,
 };
 use acvm_blackbox_solver::BlackBoxResolutionError;
 
@@ -50,6 +55,22 @@ impl BlackBoxFunctionSolver for StubbedBackend {
     ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
         panic!("Path not trodden by this test")
     }
+    fn ec_add(
+        &self,
+        _input1_x: &FieldElement,
+        _input1_y: &FieldElement,
+        _input2_x: &FieldElement,
+        _input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        panic!("Path not trodden by this test")
+    }
+    fn ec_double(
+        &self,
+        _input_x: &FieldElement,
+        _input_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        panic!("Path not trodden by this test")
+    }
 }
 
 // Reenable these test cases once we move the brillig implementation of inversion down into the acvm stdlib.
@@ -491,6 +512,70 @@ fn brillig_oracle_predicate() {
     // ACVM should be able to be finalized in `Solved` state.
     acvm.finalize();
 }
+
+#[test]
+fn brillig_profiling_samples() {
+    let fe_0 = FieldElement::zero();
+    let fe_1 = FieldElement::one();
+    let w_x = Witness(1);
+    let w_y = Witness(2);
+    let w_oracle = Witness(3);
+    let w_x_plus_y = Witness(4);
+
+    let brillig_opcode = Opcode::Brillig(Brillig {
+        inputs: vec![BrilligInputs::Single(Expression {
+            mul_terms: vec![],
+            linear_combinations: vec![(fe_1, w_x), (fe_1, w_y)],
+            q_c: fe_0,
+        })],
+        outputs: vec![BrilligOutputs::Simple(w_x_plus_y), BrilligOutputs::Simple(w_oracle)],
+        bytecode: vec![
+            BrilligOpcode::ForeignCall {
+                function: "invert".into(),
+                destinations: vec![RegisterOrMemory::RegisterIndex(RegisterIndex::from(1))],
+                inputs: vec![RegisterOrMemory::RegisterIndex(RegisterIndex::from(0))],
+            },
+            BrilligOpcode::Stop,
+            // Never executed so should not be sampled
+            BrilligOpcode::Trap,
+        ],
+        predicate: None,
+    });
+
+    let opcodes = vec![brillig_opcode];
+
+    let witness_assignments = BTreeMap::from([
+        (Witness(1), FieldElement::from(2u128)),
+        (Witness(2), FieldElement::from(3u128)),
+    ])
+    .into();
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+    acvm.enable_profiling();
+
+    let solver_status = acvm.solve();
+    assert!(
+        matches!(solver_status, ACVMStatus::RequiresForeignCall(_)),
+        "should require foreign call response"
+    );
+    assert!(acvm.profiling_samples().is_empty(), "samples should be recorded once brillig halts");
+
+    let foreign_call_wait_info =
+        acvm.get_pending_foreign_call().expect("should have a brillig foreign call request");
+    let foreign_call_result =
+        Value::from(foreign_call_wait_info.inputs[0].unwrap_value().to_field().inverse());
+    acvm.resolve_pending_foreign_call(foreign_call_result.into());
+
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
+
+    let expected_samples = BTreeMap::from([
+        (OpcodeLocation::Brillig { acir_index: 0, brillig_index: 0 }, 1),
+        (OpcodeLocation::Brillig { acir_index: 0, brillig_index: 1 }, 1),
+    ]);
+    assert_eq!(acvm.profiling_samples(), &expected_samples);
+}
+
 #[test]
 fn unsatisfied_opcode_resolved() {
     let a = Witness(0);
@@ -609,6 +694,58 @@ fn unsatisfied_opcode_resolved_brillig() {
     );
 }
 
+#[test]
+fn brillig_step_limit() {
+    let w_x = Witness(1);
+
+    // An unconstrained infinite loop
+    let brillig_opcode = Opcode::Brillig(Brillig {
+        inputs: vec![],
+        outputs: vec![BrilligOutputs::Simple(w_x)],
+        bytecode: vec![
+            BrilligOpcode::Const { destination: RegisterIndex::from(0), value: Value::from(0u128) },
+            BrilligOpcode::Jump { location: 1 },
+        ],
+        predicate: None,
+    });
+    let opcodes = vec![
+        Opcode::Arithmetic(Expression::default()),
+        brillig_opcode,
+        Opcode::Arithmetic(Expression::default()),
+    ];
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, WitnessMap::new());
+    acvm.set_execution_limits(ExecutionLimits { max_steps: Some(100), ..Default::default() });
+    let solver_status = acvm.solve();
+
+    // The first ACIR opcode takes one step, leaving 99 steps for the Brillig process.
+    assert_eq!(
+        solver_status,
+        ACVMStatus::Failure(OpcodeResolutionError::ExecutionLimitExceeded {
+            limit: ExceededLimit::Steps(99),
+            call_stack: vec![OpcodeLocation::Brillig { acir_index: 1, brillig_index: 1 }]
+        }),
+        "The Brillig opcode should exhaust the step limit"
+    );
+}
+
+#[test]
+fn acir_step_limit() {
+    let opcodes = vec![Opcode::Arithmetic(Expression::default()); 3];
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, WitnessMap::new());
+    acvm.set_execution_limits(ExecutionLimits { max_steps: Some(2), ..Default::default() });
+    let solver_status = acvm.solve();
+
+    assert_eq!(
+        solver_status,
+        ACVMStatus::Failure(OpcodeResolutionError::ExecutionLimitExceeded {
+            limit: ExceededLimit::Steps(2),
+            call_stack: vec![OpcodeLocation::Acir(2)]
+        }),
+    );
+}
+
 #[test]
 fn memory_operations() {
     let initial_witness = WitnessMap::from(BTreeMap::from_iter([
@@ -648,3 +785,478 @@ fn memory_operations() {
 
     assert_eq!(witness_map[&Witness(8)], FieldElement::from(6u128));
 }
+
+/// Builds a circuit which initializes a memory block with `[1, 2, 3]`, reads it at the index
+/// in w4 into w5, writes w6 at that same index, and then reads it again at w4 into w7 and at
+/// the constant index 2 into w8.
+fn memory_circuit() -> Circuit {
+    let block_id = BlockId(0);
+    let opcodes = vec![
+        Opcode::MemoryInit { block_id, init: (1..4).map(Witness).collect() },
+        Opcode::MemoryOp {
+            block_id,
+            op: MemOp::read_at_mem_index(Witness(4).into(), Witness(5)),
+            predicate: None,
+        },
+        Opcode::MemoryOp {
+            block_id,
+            op: MemOp::write_to_mem_index(Witness(4).into(), Witness(6).into()),
+            predicate: None,
+        },
+        Opcode::MemoryOp {
+            block_id,
+            op: MemOp::read_at_mem_index(Witness(4).into(), Witness(7)),
+            predicate: None,
+        },
+        Opcode::MemoryOp {
+            block_id,
+            op: MemOp::read_at_mem_index(
+                Expression::from_field(FieldElement::from(2u128)),
+                Witness(8),
+            ),
+            predicate: None,
+        },
+    ];
+    Circuit { current_witness_index: 8, opcodes, ..Circuit::default() }
+}
+
+fn lower_memory_opcodes(circuit: Circuit) -> Circuit {
+    let (circuit, _) = compiler::transform(circuit, Language::R1CS, |opcode| {
+        !matches!(opcode, Opcode::MemoryInit { .. } | Opcode::MemoryOp { .. })
+    })
+    .expect("memory opcodes should be lowered");
+    assert!(!circuit
+        .opcodes
+        .iter()
+        .any(|opcode| matches!(opcode, Opcode::MemoryInit { .. } | Opcode::MemoryOp { .. })));
+    circuit
+}
+
+#[test]
+fn lowered_memory_operations() {
+    let circuit = lower_memory_opcodes(memory_circuit());
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(1u128)),
+        (Witness(2), FieldElement::from(2u128)),
+        (Witness(3), FieldElement::from(3u128)),
+        (Witness(4), FieldElement::from(1u128)),
+        (Witness(6), FieldElement::from(10u128)),
+    ]));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&Witness(5)], FieldElement::from(2u128));
+    assert_eq!(witness_map[&Witness(7)], FieldElement::from(10u128));
+    assert_eq!(witness_map[&Witness(8)], FieldElement::from(3u128));
+}
+
+#[test]
+fn lowered_memory_operations_out_of_bounds() {
+    let circuit = lower_memory_opcodes(memory_circuit());
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(1u128)),
+        (Witness(2), FieldElement::from(2u128)),
+        (Witness(3), FieldElement::from(3u128)),
+        (Witness(4), FieldElement::from(3u128)),
+        (Witness(6), FieldElement::from(10u128)),
+    ]));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert!(matches!(solver_status, ACVMStatus::Failure(_)));
+}
+
+#[test]
+fn lowered_memory_operations_non_boolean_switch() {
+    let circuit = lower_memory_opcodes(memory_circuit());
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(1u128)),
+        (Witness(2), FieldElement::from(2u128)),
+        (Witness(3), FieldElement::from(3u128)),
+        (Witness(4), FieldElement::from(1u128)),
+        (Witness(6), FieldElement::from(10u128)),
+    ]));
+
+    // Replace the sorting hint with switch bits chosen by the prover, one of which isn't boolean.
+    let mut opcodes = circuit.opcodes;
+    let sort_position = opcodes
+        .iter()
+        .position(|opcode| matches!(opcode, Opcode::Directive(Directive::PermutationSort { .. })))
+        .expect("lowered memory should be sorted");
+    let Opcode::Directive(Directive::PermutationSort { bits, .. }) = opcodes.remove(sort_position)
+    else {
+        unreachable!("opcode should be a permutation sort");
+    };
+    let mut initial_witness = initial_witness;
+    for bit in &bits {
+        initial_witness.insert(*bit, FieldElement::zero());
+    }
+    initial_witness.insert(bits[0], FieldElement::from(2u128));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    let ACVMStatus::Failure(OpcodeResolutionError::UnsatisfiedConstrain {
+        opcode_location: ErrorLocation::Resolved(OpcodeLocation::Acir(failing_opcode)),
+    }) = solver_status
+    else {
+        panic!("expected an unsatisfied constraint, got {solver_status:?}");
+    };
+
+    let bit = Expression::from(bits[0]);
+    let is_boolean = &(&bit * &bit).unwrap() - &bit;
+    assert_eq!(opcodes[failing_opcode], Opcode::Arithmetic(is_boolean));
+}
+
+#[test]
+fn lowered_memory_operations_have_bounded_width() {
+    // A block of 16 elements, which is read at the index in w17 and then overwritten there with
+    // each of the values in w18..w26, which the reads into w26..w34 return in turn.
+    let block_id = BlockId(0);
+    let len = 16;
+    let index = Witness(len + 1);
+    let value = |write| Witness(len + 2 + write);
+    let read = |write| Witness(len + 2 + 8 + write);
+    let mut opcodes = vec![Opcode::MemoryInit { block_id, init: (1..=len).map(Witness).collect() }];
+    for write in 0..8 {
+        opcodes.push(Opcode::MemoryOp {
+            block_id,
+            op: MemOp::read_at_mem_index(index.into(), read(write)),
+            predicate: None,
+        });
+        opcodes.push(Opcode::MemoryOp {
+            block_id,
+            op: MemOp::write_to_mem_index(index.into(), value(write).into()),
+            predicate: None,
+        });
+    }
+    let circuit = lower_memory_opcodes(Circuit {
+        current_witness_index: read(7).0,
+        opcodes,
+        ..Circuit::default()
+    });
+
+    // The switches of the sorting network only ever multiply witnesses, rather than the
+    // outputs of all the switches before them.
+    for opcode in &circuit.opcodes {
+        if let Opcode::Arithmetic(expression) = opcode {
+            let width = expression.mul_terms.len() + expression.linear_combinations.len();
+            assert!(width < len as usize, "opcode {opcode} has {width} terms");
+        }
+    }
+
+    let mut initial_witness = WitnessMap::new();
+    for element in 1..=len {
+        initial_witness.insert(Witness(element), FieldElement::from(element as u128));
+    }
+    initial_witness.insert(index, FieldElement::from(5u128));
+    for write in 0..8 {
+        initial_witness.insert(value(write), FieldElement::from(100 + write as u128));
+    }
+
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&read(0)], FieldElement::from(6u128));
+    for write in 1..8 {
+        assert_eq!(witness_map[&read(write)], FieldElement::from(100 + write as u128 - 1));
+    }
+}
+
+#[test]
+fn bigint_operations() {
+    // 0x1234 and 2, as little-endian bytes.
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(0x34u128)),
+        (Witness(2), FieldElement::from(0x12u128)),
+        (Witness(3), FieldElement::from(2u128)),
+    ]));
+    let modulus = vec![0x01, 0x24];
+    let byte = |witness| FunctionInput { witness: Witness(witness), num_bits: 8 };
+
+    let opcodes = vec![
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntFromLeBytes {
+            inputs: vec![byte(1), byte(2)],
+            modulus: modulus.clone(),
+            output: 0,
+        }),
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntFromLeBytes {
+            inputs: vec![byte(3)],
+            modulus,
+            output: 1,
+        }),
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntMul { lhs: 0, rhs: 1, output: 2 }),
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntSub { lhs: 1, rhs: 0, output: 3 }),
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntToLeBytes {
+            input: 2,
+            outputs: vec![Witness(4), Witness(5)],
+        }),
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntToLeBytes {
+            input: 3,
+            outputs: vec![Witness(6), Witness(7)],
+        }),
+    ];
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    // 0x1234 * 2 = 0x2468 = 0x67 (mod 0x2401)
+    assert_eq!(witness_map[&Witness(4)], FieldElement::from(0x67u128));
+    assert_eq!(witness_map[&Witness(5)], FieldElement::zero());
+    // 2 - 0x1234 = 0x11cf (mod 0x2401)
+    assert_eq!(witness_map[&Witness(6)], FieldElement::from(0xcfu128));
+    assert_eq!(witness_map[&Witness(7)], FieldElement::from(0x11u128));
+}
+
+#[test]
+fn acir_calls() {
+    let fe_1 = FieldElement::one();
+
+    // The callee is passed w1 and w2 and returns w3 and w4, which must then equal w3 + 1.
+    let call = Opcode::Call {
+        id: 1,
+        inputs: vec![Witness(1), Witness(2)],
+        outputs: vec![Witness(3), Witness(4)],
+        predicate: None,
+    };
+    let expression = Opcode::Arithmetic(Expression {
+        mul_terms: Vec::new(),
+        linear_combinations: vec![(fe_1, Witness(3)), (-fe_1, Witness(4))],
+        q_c: fe_1,
+    });
+    // A call under a false predicate is skipped and has zero outputs.
+    let skipped_call = Opcode::Call {
+        id: 1,
+        inputs: vec![Witness(1), Witness(2)],
+        outputs: vec![Witness(5)],
+        predicate: Some(Expression::zero()),
+    };
+    let opcodes = vec![call, expression, skipped_call];
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(2u128)),
+        (Witness(2), FieldElement::from(3u128)),
+    ]));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert_eq!(
+        solver_status,
+        ACVMStatus::RequiresAcirCall(AcirCallWaitInfo {
+            id: 1,
+            inputs: vec![FieldElement::from(2u128), FieldElement::from(3u128)],
+        })
+    );
+
+    acvm.resolve_pending_acir_call(vec![FieldElement::from(5u128), FieldElement::from(6u128)]);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&Witness(4)], FieldElement::from(6u128));
+    assert_eq!(witness_map[&Witness(5)], FieldElement::zero());
+}
+
+#[test]
+fn brillig_calls() {
+    // A single unconstrained function which adds its two inputs, shared by both calls.
+    let unconstrained_functions = vec![BrilligBytecode {
+        bytecode: vec![BrilligOpcode::BinaryFieldOp {
+            op: BinaryFieldOp::Add,
+            lhs: RegisterIndex::from(0),
+            rhs: RegisterIndex::from(1),
+            destination: RegisterIndex::from(0),
+        }],
+    }];
+    let brillig_call = |lhs: Witness, rhs: Witness, output: Witness| Opcode::BrilligCall {
+        id: 0,
+        inputs: vec![
+            BrilligInputs::Single(Expression::from(lhs)),
+            BrilligInputs::Single(Expression::from(rhs)),
+        ],
+        outputs: vec![BrilligOutputs::Simple(output)],
+        predicate: None,
+    };
+    let opcodes = vec![
+        brillig_call(Witness(1), Witness(2), Witness(3)),
+        brillig_call(Witness(3), Witness(1), Witness(4)),
+    ];
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter([
+        (Witness(1), FieldElement::from(2u128)),
+        (Witness(2), FieldElement::from(3u128)),
+    ]));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness.clone());
+    acvm.set_unconstrained_functions(&unconstrained_functions);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&Witness(3)], FieldElement::from(5u128));
+    assert_eq!(witness_map[&Witness(4)], FieldElement::from(7u128));
+
+    // Without the unconstrained functions the calls cannot be solved.
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness.clone());
+    let solver_status = acvm.solve();
+    assert_eq!(
+        solver_status,
+        ACVMStatus::Failure(OpcodeResolutionError::UnknownUnconstrainedFunction(0))
+    );
+
+    // Once inlined, the calls no longer need the unconstrained functions.
+    let circuit = Circuit { current_witness_index: 4, opcodes, ..Circuit::default() };
+    let circuit = compiler::inline_brillig_calls(circuit, &unconstrained_functions);
+    assert!(circuit.opcodes.iter().all(|opcode| matches!(opcode, Opcode::Brillig(_))));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, initial_witness);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&Witness(4)], FieldElement::from(7u128));
+}
+
+#[cfg(feature = "parallel")]
+fn sha256_opcode(inputs: &[Witness], first_output: u32) -> Opcode {
+    use acir::circuit::opcodes::{BlackBoxFuncCall, FunctionInput};
+
+    Opcode::BlackBoxFuncCall(BlackBoxFuncCall::SHA256 {
+        inputs: inputs
+            .iter()
+            .map(|witness| FunctionInput { witness: *witness, num_bits: 8 })
+            .collect(),
+        outputs: (first_output..first_output + 32).map(Witness).collect(),
+    })
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn parallel_solving_matches_sequential() {
+    let fe_0 = FieldElement::zero();
+    let fe_1 = FieldElement::one();
+
+    // x + y computed by an unconstrained function in three steps
+    let brillig_opcode = Opcode::Brillig(Brillig {
+        inputs: vec![
+            BrilligInputs::Single(Expression {
+                mul_terms: vec![],
+                linear_combinations: vec![(fe_1, Witness(1))],
+                q_c: fe_0,
+            }),
+            BrilligInputs::Single(Expression {
+                mul_terms: vec![],
+                linear_combinations: vec![(fe_1, Witness(2))],
+                q_c: fe_0,
+            }),
+        ],
+        outputs: vec![BrilligOutputs::Simple(Witness(200))],
+        bytecode: vec![
+            BrilligOpcode::BinaryFieldOp {
+                op: BinaryFieldOp::Add,
+                lhs: RegisterIndex::from(0),
+                rhs: RegisterIndex::from(1),
+                destination: RegisterIndex::from(2),
+            },
+            BrilligOpcode::Mov {
+                destination: RegisterIndex::from(0),
+                source: RegisterIndex::from(2),
+            },
+            BrilligOpcode::Stop,
+        ],
+        predicate: None,
+    });
+
+    let opcodes = vec![
+        // Two independent hashes of the inputs
+        sha256_opcode(&[Witness(1), Witness(2)], 10),
+        sha256_opcode(&[Witness(3), Witness(4)], 50),
+        // A hash which depends upon the output of the first
+        sha256_opcode(&[Witness(10), Witness(11)], 100),
+        // A hash which depends upon an arithmetic opcode
+        Opcode::Arithmetic(Expression {
+            mul_terms: vec![],
+            linear_combinations: vec![(fe_1, Witness(50)), (-fe_1, Witness(150))],
+            q_c: fe_0,
+        }),
+        sha256_opcode(&[Witness(150)], 160),
+        brillig_opcode,
+    ];
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter(
+        (1..5).map(|index| (Witness(index), FieldElement::from(index as u128))),
+    ));
+
+    // The Brillig block is presolved along with the first hashes, when no steps have been taken yet.
+    // Step limits which leave it fewer steps than it needs once it is reached must fail it all the same.
+    let limits =
+        [ExecutionLimits { max_steps: None, max_memory_size: None }, ExecutionLimits::default()]
+            .into_iter()
+            .chain((0..10).map(|max_steps| ExecutionLimits {
+                max_steps: Some(max_steps),
+                ..ExecutionLimits::default()
+            }));
+    for limits in limits {
+        let mut sequential_acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness.clone());
+        sequential_acvm.set_execution_limits(limits);
+        sequential_acvm.enable_profiling();
+        let sequential_status = sequential_acvm.solve();
+
+        let mut parallel_acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness.clone());
+        parallel_acvm.set_execution_limits(limits);
+        parallel_acvm.enable_profiling();
+        let parallel_status = parallel_acvm.solve_parallel();
+
+        assert_eq!(sequential_status, parallel_status, "with {limits:?}");
+        assert_eq!(
+            sequential_acvm.profiling_samples(),
+            parallel_acvm.profiling_samples(),
+            "with {limits:?}"
+        );
+        assert_eq!(sequential_acvm.witness_map(), parallel_acvm.witness_map(), "with {limits:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn parallel_solving_reports_first_failure() {
+    // a = b + c + d, which is unsatisfied by the inputs
+    let unsatisfied_opcode = Opcode::Arithmetic(Expression {
+        mul_terms: vec![],
+        linear_combinations: vec![
+            (FieldElement::one(), Witness(1)),
+            (-FieldElement::one(), Witness(2)),
+            (-FieldElement::one(), Witness(3)),
+            (-FieldElement::one(), Witness(4)),
+        ],
+        q_c: FieldElement::zero(),
+    });
+
+    // The trap is solved ahead of the unsatisfied opcode but must not be reported
+    let trap_opcode = Opcode::Brillig(Brillig {
+        inputs: vec![],
+        outputs: vec![],
+        bytecode: vec![BrilligOpcode::Trap],
+        predicate: None,
+    });
+
+    let opcodes = vec![sha256_opcode(&[Witness(1)], 10), unsatisfied_opcode, trap_opcode];
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter(
+        (1..5).map(|index| (Witness(index), FieldElement::from(index as u128))),
+    ));
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness);
+    assert_eq!(
+        acvm.solve_parallel(),
+        ACVMStatus::Failure(OpcodeResolutionError::UnsatisfiedConstrain {
+            opcode_location: ErrorLocation::Resolved(OpcodeLocation::Acir(1)),
+        }),
+    );
+}
@@ -14,7 +14,9 @@ use acvm::{
     pwg::{ACVMStatus, ACVM},
     Language,
 };
-use acvm_blackbox_solver::{blake2s, hash_to_field_128_security, keccak256, sha256};
+use acvm_blackbox_solver::{
+    blake2s, blake3, hash_to_field_128_security, keccak256, keccakf1600, sha256, sha256_compression,
+};
 use paste::paste;
 use proptest::prelude::*;
 use std::collections::{BTreeMap, BTreeSet};
@@ -220,6 +222,7 @@ macro_rules! test_uint_inner {
 
 test_hashes!(test_sha256, sha256, SHA256, does_not_support_sha256);
 test_hashes!(test_blake2s, blake2s, Blake2s, does_not_support_blake2s);
+test_hashes!(test_blake3, blake3, Blake3, does_not_support_blake3);
 test_hashes!(test_keccak, keccak256, Keccak256, does_not_support_keccak);
 
 fn does_not_support_sha256(opcode: &Opcode) -> bool {
@@ -228,6 +231,9 @@ fn does_not_support_sha256(opcode: &Opcode) -> bool {
 fn does_not_support_blake2s(opcode: &Opcode) -> bool {
     !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Blake2s { .. }))
 }
+fn does_not_support_blake3(opcode: &Opcode) -> bool {
+    !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Blake3 { .. }))
+}
 fn does_not_support_keccak(opcode: &Opcode) -> bool {
     !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccak256 { .. }))
 }
@@ -299,6 +305,151 @@ macro_rules! test_hashes {
     };
 }
 
+fn does_not_support_keccakf1600(opcode: &Opcode) -> bool {
+    !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccakf1600 { .. }))
+}
+
+/// Solves the fallback of the Keccak-f[1600] permutation of `lanes`, returning the permuted lanes.
+fn solve_keccakf1600_fallback(lanes: &[FieldElement]) -> Result<Vec<FieldElement>, ACVMStatus> {
+    let input_witnesses: Vec<Witness> = (1..=25).map(Witness).collect();
+    let output_witnesses: Vec<Witness> = (26..=50).map(Witness).collect();
+    let inputs =
+        input_witnesses.iter().map(|&witness| FunctionInput { witness, num_bits: 64 }).collect();
+    let blackbox = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccakf1600 {
+        inputs,
+        outputs: output_witnesses.clone(),
+    });
+    let circuit = Circuit {
+        current_witness_index: 50,
+        opcodes: vec![blackbox],
+        private_parameters: BTreeSet::new(), // This is not correct but is unused in this test.
+        ..Circuit::default()
+    };
+    let circuit =
+        compile(circuit, Language::PLONKCSat { width: 3 }, does_not_support_keccakf1600).unwrap().0;
+
+    let witness_assignments: BTreeMap<_, _> =
+        input_witnesses.into_iter().zip(lanes.iter().copied()).collect();
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into());
+    let solver_status = acvm.solve();
+    if solver_status != ACVMStatus::Solved {
+        return Err(solver_status);
+    }
+    Ok(output_witnesses.iter().map(|witness| acvm.witness_map()[witness]).collect())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(3))]
+    #[test]
+    fn test_keccakf1600(lanes in proptest::array::uniform25(any::<u64>())) {
+        let expected = keccakf1600(lanes).unwrap().map(|lane| FieldElement::from(lane as u128));
+        let lanes = lanes.map(|lane| FieldElement::from(lane as u128));
+
+        prop_assert_eq!(solve_keccakf1600_fallback(&lanes), Ok(expected.to_vec()));
+    }
+}
+
+#[test]
+fn keccakf1600_fallback_permutes_zero_state() {
+    let output = solve_keccakf1600_fallback(&[FieldElement::zero(); 25]).unwrap();
+    assert_eq!(output[0], FieldElement::from(0xF1258F7940E1DDE7_u128));
+}
+
+#[test]
+fn keccakf1600_fallback_rejects_lanes_larger_than_64_bits() {
+    let mut lanes = [FieldElement::zero(); 25];
+    lanes[0] = FieldElement::from(1_u128 << 64);
+    assert!(solve_keccakf1600_fallback(&lanes).is_err());
+}
+
+fn does_not_support_sha256_compression(opcode: &Opcode) -> bool {
+    !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Sha256Compression { .. }))
+}
+
+/// Solves the fallback of the SHA-256 compression of `block` into `state`, returning the new state.
+fn solve_sha256_compression_fallback(
+    block: &[FieldElement],
+    state: &[FieldElement],
+) -> Result<Vec<FieldElement>, ACVMStatus> {
+    let input_witnesses: Vec<Witness> = (1..=16).map(Witness).collect();
+    let hash_value_witnesses: Vec<Witness> = (17..=24).map(Witness).collect();
+    let output_witnesses: Vec<Witness> = (25..=32).map(Witness).collect();
+    let to_inputs = |witnesses: &[Witness]| -> Vec<FunctionInput> {
+        witnesses.iter().map(|&witness| FunctionInput { witness, num_bits: 32 }).collect()
+    };
+    let blackbox = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Sha256Compression {
+        inputs: to_inputs(&input_witnesses),
+        hash_values: to_inputs(&hash_value_witnesses),
+        outputs: output_witnesses.clone(),
+    });
+    let circuit = Circuit {
+        current_witness_index: 32,
+        opcodes: vec![blackbox],
+        private_parameters: BTreeSet::new(), // This is not correct but is unused in this test.
+        ..Circuit::default()
+    };
+    let circuit =
+        compile(circuit, Language::PLONKCSat { width: 3 }, does_not_support_sha256_compression)
+            .unwrap()
+            .0;
+
+    let witness_assignments: BTreeMap<_, _> = input_witnesses
+        .into_iter()
+        .chain(hash_value_witnesses)
+        .zip(block.iter().chain(state).copied())
+        .collect();
+    let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into());
+    let solver_status = acvm.solve();
+    if solver_status != ACVMStatus::Solved {
+        return Err(solver_status);
+    }
+    Ok(output_witnesses.iter().map(|witness| acvm.witness_map()[witness]).collect())
+}
+
+const SHA256_INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(3))]
+    #[test]
+    fn test_sha256_compression(
+        block in proptest::array::uniform16(any::<u32>()),
+        state in proptest::array::uniform8(any::<u32>()),
+    ) {
+        let expected =
+            sha256_compression(state, block).unwrap().map(|word| FieldElement::from(word as u128));
+        let block = block.map(|word| FieldElement::from(word as u128));
+        let state = state.map(|word| FieldElement::from(word as u128));
+
+        prop_assert_eq!(solve_sha256_compression_fallback(&block, &state), Ok(expected.to_vec()));
+    }
+}
+
+#[test]
+fn sha256_compression_fallback_hashes_padded_block() {
+    // The single padded block of the message "abc".
+    let mut block = [FieldElement::zero(); 16];
+    block[0] = FieldElement::from(0x61626380_u128);
+    block[15] = FieldElement::from(0x18_u128);
+    let state = SHA256_INITIAL_STATE.map(|word| FieldElement::from(word as u128));
+
+    let digest: [u128; 8] = [
+        0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+        0xf20015ad,
+    ];
+    let digest = digest.map(FieldElement::from);
+    assert_eq!(solve_sha256_compression_fallback(&block, &state), Ok(digest.to_vec()));
+}
+
+#[test]
+fn sha256_compression_fallback_rejects_words_larger_than_32_bits() {
+    let mut block = [FieldElement::zero(); 16];
+    block[0] = FieldElement::from(1_u128 << 32);
+    let state = SHA256_INITIAL_STATE.map(|word| FieldElement::from(word as u128));
+    assert!(solve_sha256_compression_fallback(&block, &state).is_err());
+}
+
 fn does_not_support_hash_to_field(opcode: &Opcode) -> bool {
     !matches!(opcode, Opcode::BlackBoxFuncCall(BlackBoxFuncCall::HashToField128Security { .. }))
 }
@@ -1,11 +1,16 @@
+use std::collections::BTreeMap;
+
 use acvm::{
-    acir::circuit::Circuit,
-    pwg::{ACVMStatus, ErrorLocation, OpcodeResolutionError, ACVM},
+    acir::{
+        circuit::{brillig::BrilligBytecode, Circuit, OpcodeLocation},
+        native_types::WitnessMap,
+    },
+    pwg::{ACVMStatus, ErrorLocation, ExecutionLimits, OpcodeResolutionError, ACVM},
 };
 #[allow(deprecated)]
 use barretenberg_blackbox_solver::BarretenbergSolver;
 
-use js_sys::Error;
+use js_sys::{Array, Error, Uint8Array};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use crate::{
@@ -62,55 +67,197 @@ pub async fn execute_circuit_with_black_box_solver(
     circuit: Vec<u8>,
     initial_witness: JsWitnessMap,
     foreign_call_handler: ForeignCallHandler,
+) -> Result<JsWitnessMap, Error> {
+    execute_circuit_with_limits(solver, circuit, initial_witness, foreign_call_handler, None, None)
+        .await
+}
+
+/// Executes an ACIR circuit to generate the solved witness from the initial witness,
+/// failing if execution exceeds the provided limits.
+///
+/// @param {&WasmBlackBoxFunctionSolver} solver - A black box solver.
+/// @param {Uint8Array} circuit - A serialized representation of an ACIR circuit
+/// @param {WitnessMap} initial_witness - The initial witness map defining all of the inputs to `circuit`..
+/// @param {ForeignCallHandler} foreign_call_handler - A callback to process any foreign calls from the circuit.
+/// @param {number | undefined} max_steps - The maximum number of ACIR and Brillig opcodes which may be executed.
+/// @param {number | undefined} max_memory_size - The maximum number of memory slots which each Brillig process may use.
+/// @returns {WitnessMap} The solved witness calculated by executing the circuit on the provided inputs.
+#[wasm_bindgen(js_name = executeCircuitWithLimits, skip_jsdoc)]
+pub async fn execute_circuit_with_limits(
+    solver: &WasmBlackBoxFunctionSolver,
+    circuit: Vec<u8>,
+    initial_witness: JsWitnessMap,
+    foreign_call_handler: ForeignCallHandler,
+    max_steps: Option<usize>,
+    max_memory_size: Option<usize>,
+) -> Result<JsWitnessMap, Error> {
+    console_error_panic_hook::set_once();
+    let circuit: Circuit =
+        Circuit::deserialize_circuit(&circuit).expect("Failed to deserialize circuit");
+
+    let witness_map = execute(
+        solver,
+        &circuit,
+        &[],
+        &[],
+        initial_witness.into(),
+        &foreign_call_handler,
+        ExecutionLimits { max_steps, max_memory_size },
+    )
+    .await?;
+    Ok(witness_map.into())
+}
+
+/// Executes an ACIR program made of several circuits to generate the solved witness of its main circuit
+/// from the initial witness, failing if execution exceeds the provided limits.
+///
+/// @param {&WasmBlackBoxFunctionSolver} solver - A black box solver.
+/// @param {Uint8Array} circuit - A serialized representation of the program's main ACIR circuit
+/// @param {Uint8Array[]} functions - Serialized representations of the ACIR functions called by the program, indexed by the `id` of the calls.
+/// @param {Uint8Array} unconstrained_functions - A serialized representation of the unconstrained functions called by the program, indexed by the `id` of the Brillig calls.
+/// @param {WitnessMap} initial_witness - The initial witness map defining all of the inputs to `circuit`..
+/// @param {ForeignCallHandler} foreign_call_handler - A callback to process any foreign calls from the program.
+/// @param {number | undefined} max_steps - The maximum number of ACIR and Brillig opcodes which may be executed by each circuit.
+/// @param {number | undefined} max_memory_size - The maximum number of memory slots which each Brillig process may use.
+/// @returns {WitnessMap} The solved witness calculated by executing the main circuit on the provided inputs.
+#[wasm_bindgen(js_name = executeProgram, skip_jsdoc)]
+pub async fn execute_program(
+    solver: &WasmBlackBoxFunctionSolver,
+    circuit: Vec<u8>,
+    functions: Array,
+    unconstrained_functions: Vec<u8>,
+    initial_witness: JsWitnessMap,
+    foreign_call_handler: ForeignCallHandler,
+    max_steps: Option<usize>,
+    max_memory_size: Option<usize>,
 ) -> Result<JsWitnessMap, Error> {
     console_error_panic_hook::set_once();
     let circuit: Circuit =
         Circuit::deserialize_circuit(&circuit).expect("Failed to deserialize circuit");
+    let functions: Vec<Circuit> = functions
+        .iter()
+        .map(|function| {
+            Circuit::deserialize_circuit(&Uint8Array::new(&function).to_vec())
+                .expect("Failed to deserialize function")
+        })
+        .collect();
+    let unconstrained_functions = BrilligBytecode::deserialize_functions(&unconstrained_functions)
+        .expect("Failed to deserialize unconstrained functions");
+
+    let witness_map = execute(
+        solver,
+        &circuit,
+        &functions,
+        &unconstrained_functions,
+        initial_witness.into(),
+        &foreign_call_handler,
+        ExecutionLimits { max_steps, max_memory_size },
+    )
+    .await?;
+    Ok(witness_map.into())
+}
 
-    let mut acvm = ACVM::new(&solver.0, &circuit.opcodes, initial_witness.into());
+/// Executes `circuit`, solving the calls it makes to the ACIR functions in `functions`
+/// and to the unconstrained functions in `unconstrained_functions`.
+async fn execute<'a>(
+    solver: &'a WasmBlackBoxFunctionSolver,
+    circuit: &'a Circuit,
+    functions: &'a [Circuit],
+    unconstrained_functions: &'a [BrilligBytecode],
+    initial_witness: WitnessMap,
+    foreign_call_handler: &ForeignCallHandler,
+    execution_limits: ExecutionLimits,
+) -> Result<WitnessMap, Error> {
+    let new_acvm = |circuit: &'a Circuit, initial_witness| {
+        let mut acvm = ACVM::new(&solver.0, &circuit.opcodes, initial_witness);
+        acvm.set_unconstrained_functions(unconstrained_functions);
+        acvm.set_execution_limits(execution_limits);
+        acvm
+    };
+
+    // The circuits being executed, each of which is called by the one before it.
+    let mut call_stack = vec![(circuit, new_acvm(circuit, initial_witness))];
 
     loop {
+        let (current_circuit, acvm) =
+            call_stack.last_mut().expect("call stack should not be empty");
+        let current_circuit = *current_circuit;
         let solver_status = acvm.solve();
 
         match solver_status {
-            ACVMStatus::Solved => break,
+            ACVMStatus::Solved => {
+                let (function, acvm) = call_stack.pop().expect("call stack should not be empty");
+                let witness_map = acvm.finalize();
+                let Some((_, caller)) = call_stack.last_mut() else {
+                    return Ok(witness_map);
+                };
+                let return_values =
+                    function.return_values.0.iter().map(|witness| witness_map[witness]).collect();
+                caller.resolve_pending_acir_call(return_values);
+            }
             ACVMStatus::InProgress => {
                 unreachable!("Execution should not stop while in `InProgress` state.")
             }
             ACVMStatus::Failure(error) => {
-                let (assert_message, call_stack) = match &error {
+                let (assert_message, mut call_stack_locations) = match &error {
                     OpcodeResolutionError::UnsatisfiedConstrain {
                         opcode_location: ErrorLocation::Resolved(opcode_location),
                     }
                     | OpcodeResolutionError::IndexOutOfBounds {
                         opcode_location: ErrorLocation::Resolved(opcode_location),
                         ..
-                    } => {
-                        (circuit.get_assert_message(*opcode_location), Some(vec![*opcode_location]))
-                    }
+                    } => (
+                        current_circuit.get_assert_message(*opcode_location),
+                        Some(vec![*opcode_location]),
+                    ),
                     OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. } => {
                         let failing_opcode =
                             call_stack.last().expect("Brillig error call stacks cannot be empty");
-                        (circuit.get_assert_message(*failing_opcode), Some(call_stack.clone()))
+                        (
+                            current_circuit.get_assert_message(*failing_opcode),
+                            Some(call_stack.clone()),
+                        )
+                    }
+                    OpcodeResolutionError::ExecutionLimitExceeded { call_stack, .. } => {
+                        (None, Some(call_stack.clone()))
                     }
                     _ => (None, None),
                 };
 
+                // Failures within a called function are reported at the location of the call in the
+                // main circuit, as the locations within the function aren't meaningful to the caller.
+                if call_stack.len() > 1 {
+                    let call_location = OpcodeLocation::Acir(call_stack[0].1.instruction_pointer());
+                    call_stack_locations = Some(vec![call_location]);
+                }
+
                 let error_string = match &assert_message {
                     Some(assert_message) => format!("Assertion failed: {}", assert_message),
                     None => error.to_string(),
                 };
 
-                return Err(JsExecutionError::new(error_string.into(), call_stack).into());
+                return Err(JsExecutionError::new(error_string, call_stack_locations).into());
             }
             ACVMStatus::RequiresForeignCall(foreign_call) => {
-                let result = resolve_brillig(&foreign_call_handler, &foreign_call).await?;
+                let result = resolve_brillig(foreign_call_handler, &foreign_call).await?;
 
                 acvm.resolve_pending_foreign_call(result);
             }
+            ACVMStatus::RequiresAcirCall(acir_call) => {
+                let Some(function) = functions.get(acir_call.id as usize) else {
+                    let error_string = format!(
+                        "Cannot execute call to ACIR function {} as it was not provided",
+                        acir_call.id
+                    );
+                    return Err(JsExecutionError::new(error_string, None).into());
+                };
+
+                // The function's parameters are taken in order of their witness indices.
+                let initial_witness = WitnessMap::from(BTreeMap::from_iter(
+                    function.private_parameters.iter().copied().zip(acir_call.inputs),
+                ));
+                call_stack.push((function, new_acvm(function, initial_witness)));
+            }
         }
     }
-
-    let witness_map = acvm.finalize();
-    Ok(witness_map.into())
 }
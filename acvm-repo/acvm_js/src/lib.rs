@@ -22,7 +22,7 @@ cfg_if::cfg_if! {
         pub use black_box_solvers::{and, xor, sha256, blake2s256, keccak256, ecdsa_secp256k1_verify, ecdsa_secp256r1_verify};
         pub use build_info::build_info;
         pub use compression::{compress_witness, decompress_witness};
-        pub use execute::{execute_circuit, execute_circuit_with_black_box_solver, create_black_box_solver};
+        pub use execute::{execute_circuit, execute_circuit_with_black_box_solver, execute_circuit_with_limits, execute_program, create_black_box_solver};
         pub use js_witness_map::JsWitnessMap;
         pub use logging::{init_log_level, LogLevel};
         pub use public_witness::{get_public_parameters_witness, get_public_witness, get_return_witness};
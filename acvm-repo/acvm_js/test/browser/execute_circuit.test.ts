@@ -3,6 +3,7 @@ import initACVM, {
   createBlackBoxSolver,
   executeCircuit,
   executeCircuitWithBlackBoxSolver,
+  executeProgram,
   WasmBlackBoxFunctionSolver,
   WitnessMap,
   initLogLevel,
@@ -123,6 +124,52 @@ it('successfully executes a MemoryOp opcode', async () => {
   expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
 });
 
+it('successfully executes a program with a call to another ACIR function', async () => {
+  const solver: WasmBlackBoxFunctionSolver = await createBlackBoxSolver();
+
+  const { bytecode, functions, unconstrainedFunctions, initialWitnessMap, expectedWitnessMap } = await import(
+    '../shared/acir_call'
+  );
+
+  const solvedWitness: WitnessMap = await executeProgram(
+    solver,
+    bytecode,
+    functions,
+    unconstrainedFunctions,
+    initialWitnessMap,
+    () => {
+      throw Error('unexpected oracle');
+    },
+    undefined,
+    undefined,
+  );
+
+  expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
+});
+
+it('successfully executes a program with a call to an unconstrained function', async () => {
+  const solver: WasmBlackBoxFunctionSolver = await createBlackBoxSolver();
+
+  const { bytecode, unconstrainedFunctions, initialWitnessMap, expectedWitnessMap } = await import(
+    '../shared/brillig_call'
+  );
+
+  const solvedWitness: WitnessMap = await executeProgram(
+    solver,
+    bytecode,
+    [],
+    unconstrainedFunctions,
+    initialWitnessMap,
+    () => {
+      throw Error('unexpected oracle');
+    },
+    undefined,
+    undefined,
+  );
+
+  expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
+});
+
 it('successfully executes two circuits with same backend', async function () {
   // chose pedersen op here because it is the one with slow initialization
   // that led to the decision to pull backend initialization into a separate
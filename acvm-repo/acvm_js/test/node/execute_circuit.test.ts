@@ -3,6 +3,8 @@ import {
   createBlackBoxSolver,
   executeCircuit,
   executeCircuitWithBlackBoxSolver,
+  executeProgram,
+  executeCircuitWithLimits,
   WasmBlackBoxFunctionSolver,
   WitnessMap,
   ForeignCallHandler,
@@ -117,6 +119,52 @@ it('successfully executes a MemoryOp opcode', async () => {
   expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
 });
 
+it('successfully executes a program with a call to another ACIR function', async () => {
+  const solver: WasmBlackBoxFunctionSolver = await createBlackBoxSolver();
+
+  const { bytecode, functions, unconstrainedFunctions, initialWitnessMap, expectedWitnessMap } = await import(
+    '../shared/acir_call'
+  );
+
+  const solvedWitness: WitnessMap = await executeProgram(
+    solver,
+    bytecode,
+    functions,
+    unconstrainedFunctions,
+    initialWitnessMap,
+    () => {
+      throw Error('unexpected oracle');
+    },
+    undefined,
+    undefined,
+  );
+
+  expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
+});
+
+it('successfully executes a program with a call to an unconstrained function', async () => {
+  const solver: WasmBlackBoxFunctionSolver = await createBlackBoxSolver();
+
+  const { bytecode, unconstrainedFunctions, initialWitnessMap, expectedWitnessMap } = await import(
+    '../shared/brillig_call'
+  );
+
+  const solvedWitness: WitnessMap = await executeProgram(
+    solver,
+    bytecode,
+    [],
+    unconstrainedFunctions,
+    initialWitnessMap,
+    () => {
+      throw Error('unexpected oracle');
+    },
+    undefined,
+    undefined,
+  );
+
+  expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
+});
+
 it('successfully executes two circuits with same backend', async function () {
   this.timeout(10000);
 
@@ -157,3 +205,27 @@ it('successfully executes 500 circuits with same backend', async function () {
     expect(solvedWitness).to.be.deep.eq(expectedWitnessMap);
   }
 });
+
+it('halts execution once the step limit is exceeded', async () => {
+  const solver: WasmBlackBoxFunctionSolver = await createBlackBoxSolver();
+
+  const { bytecode, initialWitnessMap } = await import('../shared/addition');
+
+  let error: Error | undefined;
+  try {
+    await executeCircuitWithLimits(
+      solver,
+      bytecode,
+      initialWitnessMap,
+      () => {
+        throw Error('unexpected oracle');
+      },
+      0,
+      undefined,
+    );
+  } catch (err) {
+    error = err as Error;
+  }
+
+  expect(error?.message).to.contain('step limit of 0 exceeded');
+});
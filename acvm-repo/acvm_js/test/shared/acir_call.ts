@@ -0,0 +1,27 @@
+// A program whose main circuit calls an ACIR function on w1 and returns the result in w2.
+// The called function returns twice its parameter.
+export const bytecode = Uint8Array.from([
+  31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 99, 98, 96, 96, 96, 100, 128, 0, 54, 40, 205, 136, 68, 195, 216, 76, 232, 18, 12,
+  104, 108, 38, 36, 49, 0, 214, 27, 239, 225, 85, 0, 0, 0,
+]);
+
+export const functions = [
+  Uint8Array.from([
+    31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 173, 144, 75, 14, 128, 32, 12, 68, 129, 19, 245, 11, 237, 206, 171, 72, 44, 247,
+    63, 130, 49, 193, 132, 232, 82, 223, 102, 118, 147, 153, 87, 82, 74, 57, 189, 41, 51, 183, 153, 240, 13, 202, 75, 23,
+    67, 21, 137, 70, 129, 140, 59, 144, 119, 83, 16, 237, 213, 208, 80, 77, 15, 50, 230, 48, 177, 230, 221, 27, 56, 10,
+    7, 14, 117, 30, 179, 172, 252, 183, 11, 238, 239, 171, 131, 252, 112, 112, 113, 2, 12, 39, 96, 92, 40, 1, 0, 0,
+  ]),
+];
+
+// The program doesn't call any unconstrained functions.
+export const unconstrainedFunctions = Uint8Array.from([
+  31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 99, 96, 128, 0, 0, 105, 223, 34, 101, 8, 0, 0, 0,
+]);
+
+export const initialWitnessMap = new Map([[1, '0x0000000000000000000000000000000000000000000000000000000000000003']]);
+
+export const expectedWitnessMap = new Map([
+  [1, '0x0000000000000000000000000000000000000000000000000000000000000003'],
+  [2, '0x0000000000000000000000000000000000000000000000000000000000000006'],
+]);
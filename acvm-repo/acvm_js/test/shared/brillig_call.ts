@@ -0,0 +1,21 @@
+// A circuit which calls an unconstrained function adding w1 and w2 into w3.
+export const bytecode = Uint8Array.from([
+  31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 99, 102, 96, 96, 96, 100, 128, 0, 118, 40, 205, 196, 128, 9, 96, 106, 28, 160,
+  180, 1, 101, 192, 144, 145, 122, 102, 25, 208, 210, 157, 76, 84, 116, 39, 35, 146, 59, 153, 145, 3, 154, 17, 45, 208,
+  25, 145, 21, 65, 1, 0, 195, 85, 249, 200, 169, 1, 0, 0,
+]);
+
+export const unconstrainedFunctions = Uint8Array.from([
+  31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 99, 100, 128, 0, 70, 6, 252, 0, 38, 15, 0, 144, 205, 215, 172, 48, 0, 0, 0,
+]);
+
+export const initialWitnessMap = new Map([
+  [1, '0x0000000000000000000000000000000000000000000000000000000000000002'],
+  [2, '0x0000000000000000000000000000000000000000000000000000000000000003'],
+]);
+
+export const expectedWitnessMap = new Map([
+  [1, '0x0000000000000000000000000000000000000000000000000000000000000002'],
+  [2, '0x0000000000000000000000000000000000000000000000000000000000000003'],
+  [3, '0x0000000000000000000000000000000000000000000000000000000000000005'],
+]);
@@ -0,0 +1,115 @@
+use ark_ec::AffineRepr;
+
+use acir::{BlackBoxFunc, FieldElement};
+
+use crate::BlackBoxResolutionError;
+
+/// Adds two points of the Grumpkin curve, where the coordinates `(0, 0)` represent the point at infinity.
+pub fn embedded_curve_add(
+    input1_x: &FieldElement,
+    input1_y: &FieldElement,
+    input2_x: &FieldElement,
+    input2_y: &FieldElement,
+) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+    let point1 = create_point(*input1_x, *input1_y, BlackBoxFunc::EmbeddedCurveAdd)?;
+    let point2 = create_point(*input2_x, *input2_y, BlackBoxFunc::EmbeddedCurveAdd)?;
+
+    Ok(to_coordinates(grumpkin::SWAffine::from(point1 + point2)))
+}
+
+/// Doubles a point of the Grumpkin curve, where the coordinates `(0, 0)` represent the point at infinity.
+pub fn embedded_curve_double(
+    input_x: &FieldElement,
+    input_y: &FieldElement,
+) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+    let point = create_point(*input_x, *input_y, BlackBoxFunc::EmbeddedCurveDouble)?;
+
+    Ok(to_coordinates(grumpkin::SWAffine::from(point + point)))
+}
+
+fn create_point(
+    x: FieldElement,
+    y: FieldElement,
+    func: BlackBoxFunc,
+) -> Result<grumpkin::SWAffine, BlackBoxResolutionError> {
+    if x.is_zero() && y.is_zero() {
+        return Ok(grumpkin::SWAffine::zero());
+    }
+
+    let point = grumpkin::SWAffine::new_unchecked(x.into_repr(), y.into_repr());
+    if !point.is_on_curve() {
+        return Err(BlackBoxResolutionError::Failed(
+            func,
+            format!("Point ({}, {}) is not on the curve", x.to_hex(), y.to_hex()),
+        ));
+    }
+    Ok(point)
+}
+
+fn to_coordinates(point: grumpkin::SWAffine) -> (FieldElement, FieldElement) {
+    if let Some((x, y)) = point.xy() {
+        (FieldElement::from_repr(*x), FieldElement::from_repr(*y))
+    } else {
+        (FieldElement::zero(), FieldElement::zero())
+    }
+}
+
+#[cfg(test)]
+mod grumpkin_embedded_curve_ops {
+    use super::*;
+    use crate::fixed_base_scalar_mul;
+
+    fn generator() -> (FieldElement, FieldElement) {
+        fixed_base_scalar_mul(&FieldElement::one(), &FieldElement::zero()).unwrap()
+    }
+
+    #[test]
+    fn adding_a_point_to_itself_doubles_it() -> Result<(), BlackBoxResolutionError> {
+        let (x, y) = generator();
+
+        let sum = embedded_curve_add(&x, &y, &x, &y)?;
+        let double = embedded_curve_double(&x, &y)?;
+        let expected = fixed_base_scalar_mul(&FieldElement::from(2u128), &FieldElement::zero())?;
+
+        assert_eq!(sum, expected);
+        assert_eq!(double, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn adds_distinct_points() -> Result<(), BlackBoxResolutionError> {
+        let (x, y) = generator();
+        let (double_x, double_y) = embedded_curve_double(&x, &y)?;
+
+        let sum = embedded_curve_add(&x, &y, &double_x, &double_y)?;
+        let expected = fixed_base_scalar_mul(&FieldElement::from(3u128), &FieldElement::zero())?;
+
+        assert_eq!(sum, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn handles_the_point_at_infinity() -> Result<(), BlackBoxResolutionError> {
+        let (x, y) = generator();
+        let zero = FieldElement::zero();
+
+        assert_eq!(embedded_curve_add(&x, &y, &zero, &zero)?, (x, y));
+        assert_eq!(embedded_curve_add(&x, &y, &x, &-y)?, (zero, zero));
+        assert_eq!(embedded_curve_double(&zero, &zero)?, (zero, zero));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_points_not_on_the_curve() {
+        let one = FieldElement::one();
+
+        let res = embedded_curve_double(&one, &one);
+        assert_eq!(
+            res,
+            Err(BlackBoxResolutionError::Failed(
+                BlackBoxFunc::EmbeddedCurveDouble,
+                format!("Point ({}, {}) is not on the curve", one.to_hex(), one.to_hex()),
+            ))
+        );
+    }
+}
@@ -5,19 +5,28 @@
 use acir::{BlackBoxFunc, FieldElement};
 use acvm_blackbox_solver::{BlackBoxFunctionSolver, BlackBoxResolutionError};
 
+mod embedded_curve_ops;
 mod fixed_base_scalar_mul;
+#[cfg(feature = "native")]
+mod native;
+#[cfg(feature = "wasm")]
 mod wasm;
 
+pub use embedded_curve_ops::{embedded_curve_add, embedded_curve_double};
 pub use fixed_base_scalar_mul::fixed_base_scalar_mul;
+#[cfg(feature = "wasm")]
 use wasm::Barretenberg;
 
+#[cfg(feature = "wasm")]
 use self::wasm::{Pedersen, SchnorrSig};
 
+#[cfg(feature = "wasm")]
 #[deprecated = "The `BarretenbergSolver` is a temporary solution and will be removed in future."]
 pub struct BarretenbergSolver {
     blackbox_vendor: Barretenberg,
 }
 
+#[cfg(feature = "wasm")]
 #[allow(deprecated)]
 impl BarretenbergSolver {
     #[cfg(target_arch = "wasm32")]
@@ -33,7 +42,7 @@ impl BarretenbergSolver {
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "wasm", not(target_arch = "wasm32")))]
 #[allow(deprecated)]
 impl Default for BarretenbergSolver {
     fn default() -> Self {
@@ -41,6 +50,7 @@ impl Default for BarretenbergSolver {
     }
 }
 
+#[cfg(feature = "wasm")]
 #[allow(deprecated)]
 impl BlackBoxFunctionSolver for BarretenbergSolver {
     fn schnorr_verify(
@@ -92,4 +102,123 @@ impl BlackBoxFunctionSolver for BarretenbergSolver {
     ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
         fixed_base_scalar_mul(low, high)
     }
+
+    fn ec_add(
+        &self,
+        input1_x: &FieldElement,
+        input1_y: &FieldElement,
+        input2_x: &FieldElement,
+        input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        embedded_curve_add(input1_x, input1_y, input2_x, input2_y)
+    }
+
+    fn ec_double(
+        &self,
+        input_x: &FieldElement,
+        input_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        embedded_curve_double(input_x, input_y)
+    }
+}
+
+/// Solves the black box functions over the Grumpkin curve natively,
+/// producing the same results as [`BarretenbergSolver`] without needing a WASM runtime.
+#[cfg(feature = "native")]
+#[derive(Debug, Default)]
+pub struct Bn254BlackBoxSolver;
+
+#[cfg(feature = "native")]
+impl BlackBoxFunctionSolver for Bn254BlackBoxSolver {
+    fn schnorr_verify(
+        &self,
+        public_key_x: &FieldElement,
+        public_key_y: &FieldElement,
+        signature: &[u8],
+        message: &[u8],
+    ) -> Result<bool, BlackBoxResolutionError> {
+        let signature: [u8; 64] = signature.try_into().map_err(|_| {
+            BlackBoxResolutionError::Failed(
+                BlackBoxFunc::SchnorrVerify,
+                format!("expected a signature of 64 bytes, found {}", signature.len()),
+            )
+        })?;
+        let sig_s: [u8; 32] = signature[0..32].try_into().unwrap();
+        let sig_e: [u8; 32] = signature[32..64].try_into().unwrap();
+
+        Ok(native::verify_signature(*public_key_x, *public_key_y, sig_s, sig_e, message))
+    }
+
+    fn pedersen_commitment(
+        &self,
+        inputs: &[FieldElement],
+        domain_separator: u32,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Ok(native::pedersen_commitment(inputs, domain_separator))
+    }
+
+    fn pedersen_hash(
+        &self,
+        inputs: &[FieldElement],
+        domain_separator: u32,
+    ) -> Result<FieldElement, BlackBoxResolutionError> {
+        Ok(native::pedersen_hash(inputs, domain_separator))
+    }
+
+    fn fixed_base_scalar_mul(
+        &self,
+        low: &FieldElement,
+        high: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        fixed_base_scalar_mul(low, high)
+    }
+
+    fn ec_add(
+        &self,
+        input1_x: &FieldElement,
+        input1_y: &FieldElement,
+        input2_x: &FieldElement,
+        input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        embedded_curve_add(input1_x, input1_y, input2_x, input2_y)
+    }
+
+    fn ec_double(
+        &self,
+        input_x: &FieldElement,
+        input_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        embedded_curve_double(input_x, input_y)
+    }
+}
+
+#[cfg(all(test, feature = "native", feature = "wasm", not(target_arch = "wasm32")))]
+mod tests {
+    use acir::FieldElement;
+    use acvm_blackbox_solver::BlackBoxFunctionSolver;
+
+    #[allow(deprecated)]
+    use super::{BarretenbergSolver, Bn254BlackBoxSolver};
+
+    #[test]
+    #[allow(deprecated)]
+    fn native_pedersen_matches_wasm() {
+        let wasm_solver = BarretenbergSolver::new();
+        let native_solver = Bn254BlackBoxSolver;
+
+        for num_inputs in 0..5_u128 {
+            let inputs: Vec<_> =
+                (0..num_inputs).map(|i| FieldElement::from(i * 1_000_003 + 7)).collect();
+            for domain_separator in 0..3 {
+                assert_eq!(
+                    native_solver.pedersen_commitment(&inputs, domain_separator).unwrap(),
+                    wasm_solver.pedersen_commitment(&inputs, domain_separator).unwrap()
+                );
+                assert_eq!(
+                    native_solver.pedersen_hash(&inputs, domain_separator).unwrap(),
+                    wasm_solver.pedersen_hash(&inputs, domain_separator).unwrap()
+                );
+            }
+        }
+    }
 }
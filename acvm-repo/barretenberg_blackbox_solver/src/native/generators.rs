@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use once_cell::sync::Lazy;
+
+/// The generators derived so far for each domain separator, indexed from zero.
+///
+/// Hashing to the curve dominates the cost of a pedersen hash, so generators are only derived once.
+static GENERATORS: Lazy<Mutex<HashMap<Vec<u8>, Vec<grumpkin::SWAffine>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Derives `num_generators` points of the Grumpkin curve from `domain_separator`, starting at `starting_index`.
+///
+/// Each generator is hashed to the curve from the hash of the domain separator followed by its index,
+/// in the same way as barretenberg.
+pub(crate) fn derive_generators(
+    domain_separator: &[u8],
+    num_generators: u32,
+    starting_index: u32,
+) -> Vec<grumpkin::SWAffine> {
+    let end_index = (starting_index + num_generators) as usize;
+
+    let mut cache = GENERATORS.lock().expect("generator cache should not be poisoned");
+    let generators = cache.entry(domain_separator.to_vec()).or_default();
+    if generators.len() < end_index {
+        let mut generator_preimage = [0u8; 64];
+        generator_preimage[..32].copy_from_slice(blake3::hash(domain_separator).as_bytes());
+
+        let new_generators = (generators.len() as u32..end_index as u32).map(|generator_index| {
+            generator_preimage[32..36].copy_from_slice(&generator_index.to_be_bytes());
+            hash_to_curve(&generator_preimage, 0)
+        });
+        generators.extend(new_generators);
+    }
+    generators[starting_index as usize..end_index].to_vec()
+}
+
+/// Hashes `seed` to a point of the Grumpkin curve.
+///
+/// The x coordinate is taken from 64 bytes of hash output, and the sign of the y coordinate from its first bit.
+/// If there is no point with that x coordinate, the hash is retried with an incremented `attempt_count`.
+fn hash_to_curve(seed: &[u8], attempt_count: u8) -> grumpkin::SWAffine {
+    let mut target_seed = seed.to_vec();
+    target_seed.extend_from_slice(&[attempt_count, 0]);
+    let hash_hi = blake3::hash(&target_seed);
+    target_seed[seed.len() + 1] = 1;
+    let hash_lo = blake3::hash(&target_seed);
+
+    let mut hash = hash_hi.as_bytes().to_vec();
+    hash.extend_from_slice(hash_lo.as_bytes());
+    let x = grumpkin::Fq::from_be_bytes_mod_order(&hash);
+    let sign_bit = hash_hi.as_bytes()[0] > 127;
+
+    match grumpkin::SWAffine::get_point_from_x_unchecked(x, false) {
+        Some(point) => {
+            let (_, y) = point.xy().expect("point with an x coordinate is not at infinity");
+            if y.into_bigint().is_odd() == sign_bit {
+                point
+            } else {
+                -point
+            }
+        }
+        None => hash_to_curve(seed, attempt_count + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::FieldElement;
+    use ark_ec::AffineRepr;
+
+    use super::derive_generators;
+
+    #[test]
+    fn derives_barretenberg_default_generators() {
+        let generators = derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 2, 0);
+        let coordinates: Vec<_> = generators
+            .iter()
+            .map(|generator| {
+                let (x, y) = generator.xy().unwrap();
+                (FieldElement::from_repr(*x).to_hex(), FieldElement::from_repr(*y).to_hex())
+            })
+            .collect();
+
+        assert_eq!(
+            coordinates,
+            vec![
+                (
+                    "083e7911d835097629f0067531fc15cafd79a89beecb39903f69572c636f4a5a".to_string(),
+                    "1a7f5efaad7f315c25a918f30cc8d7333fccab7ad7c90f14de81bcc528f9935d".to_string()
+                ),
+                (
+                    "054aa86a73cb8a34525e5bbed6e43ba1198e860f5f3950268f71df4591bde402".to_string(),
+                    "209dcfbf2cfb57f9f6046f44d71ac6faf87254afc7407c04eb621a6287cac126".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn starting_index_offsets_generators() {
+        let generators = derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 3, 0);
+        assert_eq!(derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 2, 1), generators[1..]);
+    }
+}
@@ -0,0 +1,9 @@
+//! Native implementations of the black box functions which barretenberg solves over the Grumpkin curve,
+//! producing the same results as its WASM build.
+
+mod generators;
+mod pedersen;
+mod schnorr;
+
+pub(crate) use pedersen::{pedersen_commitment, pedersen_hash};
+pub(crate) use schnorr::verify_signature;
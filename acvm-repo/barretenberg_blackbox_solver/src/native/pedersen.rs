@@ -0,0 +1,89 @@
+use acir::FieldElement;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+
+use super::generators::derive_generators;
+
+const DEFAULT_DOMAIN_SEPARATOR: &[u8] = b"DEFAULT_DOMAIN_SEPARATOR";
+const HASH_LENGTH_DOMAIN_SEPARATOR: &[u8] = b"pedersen_hash_length";
+
+type GrumpkinPoint = <grumpkin::SWAffine as AffineRepr>::Group;
+
+pub(crate) fn pedersen_commitment(
+    inputs: &[FieldElement],
+    hash_index: u32,
+) -> (FieldElement, FieldElement) {
+    to_coordinates(commit(inputs, hash_index).into_affine())
+}
+
+pub(crate) fn pedersen_hash(inputs: &[FieldElement], hash_index: u32) -> FieldElement {
+    let length_generator = derive_generators(HASH_LENGTH_DOMAIN_SEPARATOR, 1, 0)[0];
+    let length = length_generator * grumpkin::Fr::from(inputs.len() as u64);
+
+    let (hash, _) = to_coordinates((length + commit(inputs, hash_index)).into_affine());
+    hash
+}
+
+/// Commits to `inputs` using the default generators, starting from the generator at `hash_index`.
+fn commit(inputs: &[FieldElement], hash_index: u32) -> GrumpkinPoint {
+    let generators = derive_generators(DEFAULT_DOMAIN_SEPARATOR, inputs.len() as u32, hash_index);
+
+    inputs.iter().zip(generators).fold(GrumpkinPoint::zero(), |commitment, (input, generator)| {
+        commitment + generator * grumpkin::Fr::from_be_bytes_mod_order(&input.to_be_bytes())
+    })
+}
+
+/// Converts `point` into its coordinates as barretenberg returns them.
+///
+/// Barretenberg represents the point at infinity by setting the top bit of the Montgomery form of its
+/// x coordinate, which reads back as `1/2`, with a zero y coordinate.
+fn to_coordinates(point: grumpkin::SWAffine) -> (FieldElement, FieldElement) {
+    match point.xy() {
+        Some((x, y)) => (FieldElement::from_repr(*x), FieldElement::from_repr(*y)),
+        None => (FieldElement::from(2_u128).inverse(), FieldElement::zero()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::FieldElement;
+
+    use super::{pedersen_commitment, pedersen_hash};
+
+    #[test]
+    fn commitment_matches_barretenberg() {
+        let (x, y) = pedersen_commitment(&[FieldElement::zero(), FieldElement::one()], 0);
+        assert_eq!(x.to_hex(), "054aa86a73cb8a34525e5bbed6e43ba1198e860f5f3950268f71df4591bde402");
+        assert_eq!(y.to_hex(), "209dcfbf2cfb57f9f6046f44d71ac6faf87254afc7407c04eb621a6287cac126");
+    }
+
+    #[test]
+    fn hash_matches_barretenberg() {
+        let inputs: Vec<_> = (1..=3_u128).map(FieldElement::from).collect();
+
+        assert_eq!(
+            pedersen_hash(&[FieldElement::zero(), FieldElement::one()], 0).to_hex(),
+            "0d98561fb02ca04d00801dfdc118b2a24cea0351963587712a28d368041370e1"
+        );
+        assert_eq!(
+            pedersen_hash(&inputs, 0).to_hex(),
+            "0c21b8e26f60b476d9568df4807131ff70d8b7fffb03fa07960aa1cac9be7c46"
+        );
+        assert_eq!(
+            pedersen_hash(&inputs, 1).to_hex(),
+            "1aa7bc1a0b1c31b4712b17f8a20f81c4715ac3f4c54026779e1047689d83d284"
+        );
+    }
+
+    #[test]
+    fn point_at_infinity_matches_barretenberg() {
+        let half = FieldElement::from_hex(
+            "0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000001",
+        )
+        .unwrap();
+
+        assert_eq!(pedersen_commitment(&[], 0), (half, FieldElement::zero()));
+        assert_eq!(pedersen_commitment(&[FieldElement::zero()], 0), (half, FieldElement::zero()));
+        assert_eq!(pedersen_hash(&[], 0), half);
+    }
+}
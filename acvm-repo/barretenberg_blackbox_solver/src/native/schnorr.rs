@@ -0,0 +1,125 @@
+use acir::FieldElement;
+use acvm_blackbox_solver::blake2s;
+use ark_ec::AffineRepr;
+use ark_ff::{PrimeField, Zero};
+
+use super::pedersen::pedersen_hash;
+
+/// Verifies a Schnorr signature `(s, e)` over `message` against the Grumpkin public key `(pub_key_x, pub_key_y)`.
+///
+/// The signature is valid if `e` is the Blake2s hash of the pedersen hash of `R = s * G + e * pub_key`
+/// and the public key, followed by the message.
+pub(crate) fn verify_signature(
+    pub_key_x: FieldElement,
+    pub_key_y: FieldElement,
+    sig_s: [u8; 32],
+    sig_e: [u8; 32],
+    message: &[u8],
+) -> bool {
+    let pub_key = grumpkin::SWAffine::new_unchecked(pub_key_x.into_repr(), pub_key_y.into_repr());
+    if !pub_key.is_on_curve() {
+        return false;
+    }
+
+    let s = grumpkin::Fr::from_be_bytes_mod_order(&sig_s);
+    let e = grumpkin::Fr::from_be_bytes_mod_order(&sig_e);
+    if s.is_zero() || e.is_zero() {
+        return false;
+    }
+
+    let r: grumpkin::SWAffine = (grumpkin::SWAffine::generator() * s + pub_key * e).into();
+    let Some((r_x, _)) = r.xy() else {
+        return false;
+    };
+
+    let hash = pedersen_hash(&[FieldElement::from_repr(*r_x), pub_key_x, pub_key_y], 0);
+    let mut challenge_preimage = hash.to_be_bytes();
+    challenge_preimage.extend_from_slice(message);
+
+    // The challenge is compared against the bytes of `e` before reduction.
+    blake2s(&challenge_preimage).map_or(false, |challenge| challenge == sig_e)
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::FieldElement;
+
+    use super::verify_signature;
+
+    const MESSAGE: [u8; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    fn hex_bytes(hex: &str) -> [u8; 32] {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        bytes.try_into().unwrap()
+    }
+
+    fn public_key() -> (FieldElement, FieldElement) {
+        (
+            FieldElement::from_hex(
+                "0x0e602b9dd6a3e8d039a17f069add3f9c2a187a8f629a1de60a33a8067b9b2842",
+            )
+            .unwrap(),
+            FieldElement::from_hex(
+                "0x14cc8e83df1b5cbb163bd2c94005cb0707fe570def5a165242b1c1419cb014cb",
+            )
+            .unwrap(),
+        )
+    }
+
+    const SIG_S: &str = "2772777c9899951a7ea9fd5b2bad59e6fd671374104280c65cd75b854f22433b";
+    const SIG_E: &str = "dfc63c1cab2e76f52fb35ccdf2945a5ec392141f81d7d9a5fe6fa92c33e71e07";
+
+    #[test]
+    fn verifies_barretenberg_signatures() {
+        let (pub_key_x, pub_key_y) = public_key();
+        assert!(verify_signature(
+            pub_key_x,
+            pub_key_y,
+            hex_bytes(SIG_S),
+            hex_bytes(SIG_E),
+            &MESSAGE
+        ));
+
+        let pub_key_x = FieldElement::from_hex(
+            "0x04b260954662e97f00cab9adb773a259097f7a274b83b113532bce27fa3fb96a",
+        )
+        .unwrap();
+        let pub_key_y = FieldElement::from_hex(
+            "0x2fd51571db6c08666b0edfbfbc57d432068bccd0110a39b166ab243da0037197",
+        )
+        .unwrap();
+        let sig_s = hex_bytes("010d7770d427e92954ebff5df5acba539dfd4c4d2180b20fd643696bb1ea4d30");
+        let sig_e = hex_bytes("1bed9b542754f71b1608b0e6187391dcfe7a87b3ab04d6ca40c71354ef8a7c0c");
+        assert!(verify_signature(pub_key_x, pub_key_y, sig_s, sig_e, &MESSAGE));
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        let (pub_key_x, pub_key_y) = public_key();
+        let (sig_s, sig_e) = (hex_bytes(SIG_S), hex_bytes(SIG_E));
+
+        let mut tampered_message = MESSAGE;
+        tampered_message[0] = 1;
+        assert!(!verify_signature(pub_key_x, pub_key_y, sig_s, sig_e, &tampered_message));
+
+        assert!(!verify_signature(pub_key_y, pub_key_x, sig_s, sig_e, &MESSAGE));
+        assert!(!verify_signature(pub_key_x, pub_key_y, [0; 32], sig_e, &MESSAGE));
+
+        // `e` must match the challenge exactly, even if it is equal modulo the curve order.
+        let reduced_e =
+            hex_bytes("1e3502512667f64e4e7245f3ec8ef8e8658c69d9e010af710ded78d0d1f328eb");
+        assert!(!verify_signature(pub_key_x, pub_key_y, sig_s, reduced_e, &MESSAGE));
+    }
+
+    #[test]
+    fn accepts_unreduced_s() {
+        // Barretenberg reduces `s` modulo the curve order before using it.
+        let (pub_key_x, pub_key_y) = public_key();
+        let unreduced_s =
+            hex_bytes("57d6c5ef79cb354436fa4311ad2eb24494e87e0578b44b5398f7e79c279f4082");
+        assert!(verify_signature(pub_key_x, pub_key_y, unreduced_s, hex_bytes(SIG_E), &MESSAGE));
+    }
+}
@@ -0,0 +1,249 @@
+//! Big integers modulo an arbitrary modulus, as used by the big integer black box functions.
+//!
+//! Big integers don't fit in a field element, so programs refer to them by an identifier
+//! and the solver keeps track of the value and the modulus of each identifier.
+use std::collections::HashMap;
+
+use acir::BlackBoxFunc;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+use crate::BlackBoxResolutionError;
+
+/// Keeps track of the big integers created while solving a circuit, by their identifier.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BigIntSolver {
+    values: HashMap<u32, BigUint>,
+    moduli: HashMap<u32, BigUint>,
+}
+
+impl BigIntSolver {
+    /// Returns the value of the big integer with the given identifier.
+    pub fn get_bigint(
+        &self,
+        id: u32,
+        func: BlackBoxFunc,
+    ) -> Result<BigUint, BlackBoxResolutionError> {
+        self.values.get(&id).cloned().ok_or_else(|| uninitialized_bigint(id, func))
+    }
+
+    /// Returns the modulus of the big integer with the given identifier.
+    pub fn get_modulus(
+        &self,
+        id: u32,
+        func: BlackBoxFunc,
+    ) -> Result<BigUint, BlackBoxResolutionError> {
+        self.moduli.get(&id).cloned().ok_or_else(|| uninitialized_bigint(id, func))
+    }
+
+    /// Creates the big integer `output` from its little-endian bytes, reduced modulo `modulus`
+    /// which is also given as little-endian bytes.
+    pub fn bigint_from_bytes(
+        &mut self,
+        inputs: &[u8],
+        modulus: &[u8],
+        output: u32,
+    ) -> Result<(), BlackBoxResolutionError> {
+        let modulus = BigUint::from_bytes_le(modulus);
+        if modulus.is_zero() {
+            return Err(BlackBoxResolutionError::Failed(
+                BlackBoxFunc::BigIntFromLeBytes,
+                "The modulus of a big integer cannot be zero".to_string(),
+            ));
+        }
+        let value = BigUint::from_bytes_le(inputs) % &modulus;
+        self.values.insert(output, value);
+        self.moduli.insert(output, modulus);
+        Ok(())
+    }
+
+    /// Returns the little-endian bytes of the big integer with the given identifier,
+    /// without any trailing zero bytes.
+    pub fn bigint_to_bytes(&self, input: u32) -> Result<Vec<u8>, BlackBoxResolutionError> {
+        Ok(self.get_bigint(input, BlackBoxFunc::BigIntToLeBytes)?.to_bytes_le())
+    }
+
+    /// Applies the big integer operation `func` to the big integers `lhs` and `rhs`,
+    /// which must share the same modulus, storing the result in the big integer `output`.
+    pub fn bigint_op(
+        &mut self,
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+        func: BlackBoxFunc,
+    ) -> Result<(), BlackBoxResolutionError> {
+        let modulus = self.get_modulus(lhs, func)?;
+        if self.get_modulus(rhs, func)? != modulus {
+            return Err(BlackBoxResolutionError::Failed(
+                func,
+                format!("Big integers {lhs} and {rhs} have different moduli"),
+            ));
+        }
+        let lhs_value = self.get_bigint(lhs, func)?;
+        let rhs_value = self.get_bigint(rhs, func)?;
+
+        let result = match func {
+            BlackBoxFunc::BigIntAdd => (lhs_value + rhs_value) % &modulus,
+            BlackBoxFunc::BigIntSub => (lhs_value + &modulus - rhs_value) % &modulus,
+            BlackBoxFunc::BigIntMul => (lhs_value * rhs_value) % &modulus,
+            BlackBoxFunc::BigIntDiv => {
+                let Some(rhs_inverse) = mod_inverse(&rhs_value, &modulus) else {
+                    return Err(BlackBoxResolutionError::Failed(
+                        func,
+                        format!("Big integer {rhs} is not invertible modulo its modulus"),
+                    ));
+                };
+                (lhs_value * rhs_inverse) % &modulus
+            }
+            _ => unreachable!("ICE: {func} is not a big integer operation"),
+        };
+        self.values.insert(output, result);
+        self.moduli.insert(output, modulus);
+        Ok(())
+    }
+}
+
+/// Keeps track of the big integers of a Brillig process, which unlike those of a circuit
+/// are only given their identifier when they are created.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BrilligBigIntSolver {
+    solver: BigIntSolver,
+    next_id: u32,
+}
+
+impl BrilligBigIntSolver {
+    fn create_bigint_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Creates a big integer from its little-endian bytes, returning its identifier.
+    pub fn bigint_from_bytes(
+        &mut self,
+        inputs: &[u8],
+        modulus: &[u8],
+    ) -> Result<u32, BlackBoxResolutionError> {
+        let id = self.create_bigint_id();
+        self.solver.bigint_from_bytes(inputs, modulus, id)?;
+        Ok(id)
+    }
+
+    /// Returns the little-endian bytes of the big integer with the given identifier.
+    pub fn bigint_to_bytes(&self, input: u32) -> Result<Vec<u8>, BlackBoxResolutionError> {
+        self.solver.bigint_to_bytes(input)
+    }
+
+    /// Applies the big integer operation `func` to `lhs` and `rhs`, returning the identifier of the result.
+    pub fn bigint_op(
+        &mut self,
+        lhs: u32,
+        rhs: u32,
+        func: BlackBoxFunc,
+    ) -> Result<u32, BlackBoxResolutionError> {
+        let id = self.create_bigint_id();
+        self.solver.bigint_op(lhs, rhs, id, func)?;
+        Ok(id)
+    }
+}
+
+fn uninitialized_bigint(id: u32, func: BlackBoxFunc) -> BlackBoxResolutionError {
+    BlackBoxResolutionError::Failed(func, format!("Big integer {id} has not been initialized"))
+}
+
+/// Returns the inverse of `value` modulo `modulus` if they are coprime,
+/// computed with the extended Euclidean algorithm.
+fn mod_inverse(value: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+    let modulus = BigInt::from(modulus.clone());
+    let (mut old_remainder, mut remainder) = (BigInt::from(value.clone()), modulus.clone());
+    let (mut old_coefficient, mut coefficient) = (BigInt::one(), BigInt::zero());
+    while !remainder.is_zero() {
+        let quotient = &old_remainder / &remainder;
+        let next_remainder = &old_remainder - &quotient * &remainder;
+        old_remainder = std::mem::replace(&mut remainder, next_remainder);
+        let next_coefficient = &old_coefficient - &quotient * &coefficient;
+        old_coefficient = std::mem::replace(&mut coefficient, next_coefficient);
+    }
+
+    if !old_remainder.is_one() {
+        return None;
+    }
+    (((old_coefficient % &modulus) + &modulus) % &modulus).to_biguint()
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::BlackBoxFunc;
+
+    use super::{BigIntSolver, BrilligBigIntSolver};
+    use crate::BlackBoxResolutionError;
+
+    fn solver_with(values: &[u8], modulus: u8) -> BigIntSolver {
+        let mut solver = BigIntSolver::default();
+        for (id, value) in values.iter().enumerate() {
+            solver.bigint_from_bytes(&[*value], &[modulus], id as u32).unwrap();
+        }
+        solver
+    }
+
+    #[test]
+    fn applies_operations_modulo_the_modulus() -> Result<(), BlackBoxResolutionError> {
+        let mut solver = solver_with(&[7, 12], 15);
+
+        solver.bigint_op(0, 1, 2, BlackBoxFunc::BigIntAdd)?;
+        solver.bigint_op(0, 1, 3, BlackBoxFunc::BigIntSub)?;
+        solver.bigint_op(0, 1, 4, BlackBoxFunc::BigIntMul)?;
+
+        assert_eq!(solver.bigint_to_bytes(2)?, vec![4]);
+        assert_eq!(solver.bigint_to_bytes(3)?, vec![10]);
+        assert_eq!(solver.bigint_to_bytes(4)?, vec![9]);
+        Ok(())
+    }
+
+    #[test]
+    fn divides_by_invertible_values() -> Result<(), BlackBoxResolutionError> {
+        // 15 is not prime, but 2 is coprime with it: 11 * 2 = 22 = 7 (mod 15).
+        let mut solver = solver_with(&[7, 2, 3], 15);
+
+        solver.bigint_op(0, 1, 3, BlackBoxFunc::BigIntDiv)?;
+        assert_eq!(solver.bigint_to_bytes(3)?, vec![11]);
+
+        let result = solver.bigint_op(0, 2, 4, BlackBoxFunc::BigIntDiv);
+        assert_eq!(
+            result,
+            Err(BlackBoxResolutionError::Failed(
+                BlackBoxFunc::BigIntDiv,
+                "Big integer 2 is not invertible modulo its modulus".to_string()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_operands_with_different_moduli() {
+        let mut solver = solver_with(&[1], 15);
+        solver.bigint_from_bytes(&[1], &[17], 1).unwrap();
+
+        let result = solver.bigint_op(0, 1, 2, BlackBoxFunc::BigIntAdd);
+        assert_eq!(
+            result,
+            Err(BlackBoxResolutionError::Failed(
+                BlackBoxFunc::BigIntAdd,
+                "Big integers 0 and 1 have different moduli".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn brillig_solver_creates_new_identifiers() -> Result<(), BlackBoxResolutionError> {
+        let mut solver = BrilligBigIntSolver::default();
+
+        let lhs = solver.bigint_from_bytes(&[0x34, 0x12], &[0xff, 0xff, 0x01])?;
+        let rhs = solver.bigint_from_bytes(&[0x02], &[0xff, 0xff, 0x01])?;
+        let product = solver.bigint_op(lhs, rhs, BlackBoxFunc::BigIntMul)?;
+
+        assert_eq!((lhs, rhs, product), (0, 1, 2));
+        assert_eq!(solver.bigint_to_bytes(product)?, vec![0x68, 0x24]);
+        Ok(())
+    }
+}
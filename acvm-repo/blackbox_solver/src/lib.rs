@@ -14,6 +14,12 @@ use sha2::Sha256;
 use sha3::Keccak256;
 use thiserror::Error;
 
+mod bigint;
+mod poseidon2;
+
+pub use bigint::{BigIntSolver, BrilligBigIntSolver};
+pub use poseidon2::poseidon2_permutation;
+
 #[derive(Clone, PartialEq, Eq, Debug, Error)]
 pub enum BlackBoxResolutionError {
     #[error("unsupported blackbox function: {0}")]
@@ -49,6 +55,18 @@ pub trait BlackBoxFunctionSolver {
         low: &FieldElement,
         high: &FieldElement,
     ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError>;
+    fn ec_add(
+        &self,
+        input1_x: &FieldElement,
+        input1_y: &FieldElement,
+        input2_x: &FieldElement,
+        input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError>;
+    fn ec_double(
+        &self,
+        input_x: &FieldElement,
+        input_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError>;
 }
 
 pub fn sha256(inputs: &[u8]) -> Result<[u8; 32], BlackBoxResolutionError> {
@@ -61,11 +79,35 @@ pub fn blake2s(inputs: &[u8]) -> Result<[u8; 32], BlackBoxResolutionError> {
         .map_err(|err| BlackBoxResolutionError::Failed(BlackBoxFunc::Blake2s, err))
 }
 
+pub fn blake3(inputs: &[u8]) -> Result<[u8; 32], BlackBoxResolutionError> {
+    Ok(blake3::hash(inputs).into())
+}
+
 pub fn keccak256(inputs: &[u8]) -> Result<[u8; 32], BlackBoxResolutionError> {
     generic_hash_256::<Keccak256>(inputs)
         .map_err(|err| BlackBoxResolutionError::Failed(BlackBoxFunc::Keccak256, err))
 }
 
+/// Applies the Keccak-f[1600] permutation to a state of 25 lanes of 64 bits.
+pub fn keccakf1600(mut state: [u64; 25]) -> Result<[u64; 25], BlackBoxResolutionError> {
+    keccak::f1600(&mut state);
+    Ok(state)
+}
+
+/// Applies the SHA-256 compression function to a block of 16 words of 32 bits,
+/// starting from a state of 8 words of 32 bits.
+pub fn sha256_compression(
+    mut state: [u32; 8],
+    block: [u32; 16],
+) -> Result<[u32; 8], BlackBoxResolutionError> {
+    let mut bytes = [0; 64];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(block) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    sha2::compress256(&mut state, &[GenericArray::clone_from_slice(&bytes)]);
+    Ok(state)
+}
+
 pub fn hash_to_field_128_security(inputs: &[u8]) -> Result<FieldElement, BlackBoxResolutionError> {
     generic_hash_to_field::<Blake2s256>(inputs)
         .map_err(|err| BlackBoxResolutionError::Failed(BlackBoxFunc::HashToField128Security, err))
@@ -80,6 +122,20 @@ pub fn ecdsa_secp256k1_verify(
     Ok(verify_secp256k1_ecdsa_signature(hashed_msg, public_key_x, public_key_y, signature))
 }
 
+/// Recovers the public key which produced an ECDSA `signature` of `hashed_msg` over the secp256k1 curve,
+/// returning its x and y coordinates.
+///
+/// The lowest bit of `recovery_id` is the parity of the y coordinate of the signature's nonce point,
+/// as given by Ethereum's `v - 27` (or `v - 35 - 2 * chain_id` for EIP-155 transactions).
+pub fn ecdsa_secp256k1_recover(
+    hashed_msg: &[u8; 32],
+    signature: &[u8; 64],
+    recovery_id: u8,
+) -> Result<([u8; 32], [u8; 32]), BlackBoxResolutionError> {
+    recover_secp256k1_ecdsa_public_key(hashed_msg, signature, recovery_id)
+        .map_err(|err| BlackBoxResolutionError::Failed(BlackBoxFunc::EcdsaSecp256k1Recover, err))
+}
+
 pub fn ecdsa_secp256r1_verify(
     hashed_msg: &[u8],
     public_key_x: &[u8; 32],
@@ -172,6 +228,58 @@ fn verify_secp256k1_ecdsa_signature(
     }
 }
 
+fn recover_secp256k1_ecdsa_public_key(
+    hashed_msg: &[u8; 32],
+    signature_bytes: &[u8; 64],
+    recovery_id: u8,
+) -> Result<([u8; 32], [u8; 32]), String> {
+    use k256::elliptic_curve::sec1::{Coordinates, ToEncodedPoint};
+    use k256::elliptic_curve::subtle::Choice;
+    use k256::elliptic_curve::{DecompressPoint, PrimeField};
+    use k256::{ecdsa::Signature, AffinePoint, FieldBytes, ProjectivePoint, Scalar};
+
+    // Recovery ids 2 and 3 are only needed when the x coordinate of the nonce point exceeds the group order,
+    // which happens with negligible probability and which Ethereum does not support either.
+    if recovery_id > 1 {
+        return Err(format!("unsupported recovery id {recovery_id}, expected 0 or 1"));
+    }
+
+    let Ok(signature) = Signature::try_from(signature_bytes.as_slice()) else {
+        return Err("signature r and s must be non-zero and smaller than the group order".into());
+    };
+    let r = signature.r();
+    let s = signature.s();
+
+    let z = Scalar::from_repr(*FieldBytes::from_slice(hashed_msg));
+    let Some(z) = Option::<Scalar>::from(z) else {
+        return Err("hashed message must be smaller than the group order".into());
+    };
+
+    // The nonce point R has r as its x coordinate, and the parity of its y coordinate is given by the recovery id.
+    let nonce_point = AffinePoint::decompress(
+        FieldBytes::from_slice(&signature_bytes[..32]),
+        Choice::from(recovery_id),
+    );
+    let Some(nonce_point) = Option::<AffinePoint>::from(nonce_point) else {
+        return Err("signature r is not the x coordinate of a point on the curve".into());
+    };
+
+    // The public key is Q = r^{-1} * (s * R - z * G)
+    let Some(r_inv) = Option::<Scalar>::from(r.invert()) else {
+        return Err("signature r must be invertible".into());
+    };
+    let u1 = -(z * r_inv);
+    let u2 = *s * r_inv;
+
+    let public_key: AffinePoint =
+        ((ProjectivePoint::GENERATOR * u1) + (ProjectivePoint::from(nonce_point) * u2)).to_affine();
+
+    match public_key.to_encoded_point(false).coordinates() {
+        Coordinates::Uncompressed { x, y } => Ok(((*x).into(), (*y).into())),
+        _ => Err("recovered public key is the point at infinity".into()),
+    }
+}
+
 fn verify_secp256r1_ecdsa_signature(
     hashed_msg: &[u8],
     public_key_x_bytes: &[u8; 32],
@@ -242,7 +350,7 @@ fn verify_secp256r1_ecdsa_signature(
 
 #[cfg(test)]
 mod secp256k1_tests {
-    use super::verify_secp256k1_ecdsa_signature;
+    use super::{ecdsa_secp256k1_recover, verify_secp256k1_ecdsa_signature};
 
     // 0x3a73f4123a5cd2121f21cd7e8d358835476949d035d9c2da6806b4633ac8c1e2,
     const HASHED_MESSAGE: [u8; 32] = [
@@ -323,6 +431,68 @@ mod secp256k1_tests {
 
         assert!(valid);
     }
+
+    #[test]
+    fn recovers_public_key_from_signature() {
+        let (public_key_x, public_key_y) =
+            ecdsa_secp256k1_recover(&HASHED_MESSAGE, &SIGNATURE, 0).unwrap();
+        assert_eq!(public_key_x, PUB_KEY_X);
+        assert_eq!(public_key_y, PUB_KEY_Y);
+
+        // The other recovery id yields a different key. As verification only checks the x coordinate
+        // of the nonce point, the signature is also valid for that key, which is why the recovery id is needed.
+        let (other_key_x, other_key_y) =
+            ecdsa_secp256k1_recover(&HASHED_MESSAGE, &SIGNATURE, 1).unwrap();
+        assert_ne!(other_key_x, PUB_KEY_X);
+        assert!(verify_secp256k1_ecdsa_signature(
+            &HASHED_MESSAGE,
+            &other_key_x,
+            &other_key_y,
+            &SIGNATURE
+        ));
+    }
+
+    #[test]
+    fn recovers_signer_of_ethereum_transaction() {
+        // The example transaction of EIP-155, signed with the private key 0x4646...46 for chain id 1 with v = 37,
+        // whose signer has the address 0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F.
+        let signing_hash: [u8; 32] = [
+            0xda, 0xf5, 0xa7, 0x79, 0xae, 0x97, 0x2f, 0x97, 0x21, 0x97, 0x30, 0x3d, 0x7b, 0x57,
+            0x47, 0x46, 0xc7, 0xef, 0x83, 0xea, 0xda, 0xc0, 0xf2, 0x79, 0x1a, 0xd2, 0x3d, 0xb9,
+            0x2e, 0x4c, 0x8e, 0x53,
+        ];
+        let signature: [u8; 64] = [
+            0x28, 0xef, 0x61, 0x34, 0x0b, 0xd9, 0x39, 0xbc, 0x21, 0x95, 0xfe, 0x53, 0x75, 0x67,
+            0x86, 0x60, 0x03, 0xe1, 0xa1, 0x5d, 0x3c, 0x71, 0xff, 0x63, 0xe1, 0x59, 0x06, 0x20,
+            0xaa, 0x63, 0x62, 0x76, 0x67, 0xcb, 0xe9, 0xd8, 0x99, 0x7f, 0x76, 0x1a, 0xec, 0xb7,
+            0x03, 0x30, 0x4b, 0x38, 0x00, 0xcc, 0xf5, 0x55, 0xc9, 0xf3, 0xdc, 0x64, 0x21, 0x4b,
+            0x29, 0x7f, 0xb1, 0x96, 0x6a, 0x3b, 0x6d, 0x83,
+        ];
+        let recovery_id = 37 - 35 - 2;
+
+        let (public_key_x, public_key_y) =
+            ecdsa_secp256k1_recover(&signing_hash, &signature, recovery_id).unwrap();
+
+        let address = &super::keccak256(&[public_key_x, public_key_y].concat()).unwrap()[12..];
+        assert_eq!(
+            address,
+            [
+                0x9d, 0x8a, 0x62, 0xf6, 0x56, 0xa8, 0xd1, 0x61, 0x5c, 0x12, 0x94, 0xfd, 0x71, 0xe9,
+                0xcf, 0xb3, 0xe4, 0x85, 0x5a, 0x4f
+            ]
+        );
+        assert!(verify_secp256k1_ecdsa_signature(
+            &signing_hash,
+            &public_key_x,
+            &public_key_y,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_recovery_id() {
+        assert!(ecdsa_secp256k1_recover(&HASHED_MESSAGE, &SIGNATURE, 2).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -405,3 +575,69 @@ mod secp256r1_tests {
         assert!(valid);
     }
 }
+
+#[cfg(test)]
+mod keccakf1600_tests {
+    use super::keccakf1600;
+
+    #[test]
+    fn permutes_zero_state() {
+        let state = keccakf1600([0; 25]).unwrap();
+
+        // Known answer from the Keccak team's intermediate values for Keccak-f[1600].
+        let expected: [u64; 25] = [
+            0xf1258f7940e1dde7,
+            0x84d5ccf933c0478a,
+            0xd598261ea65aa9ee,
+            0xbd1547306f80494d,
+            0x8b284e056253d057,
+            0xff97a42d7f8e6fd4,
+            0x90fee5a0a44647c4,
+            0x8c5bda0cd6192e76,
+            0xad30a6f71b19059c,
+            0x30935ab7d08ffc64,
+            0xeb5aa93f2317d635,
+            0xa9a6e6260d712103,
+            0x81a57c16dbcf555f,
+            0x43b831cd0347c826,
+            0x01f22f1a11a5569f,
+            0x05e5635a21d9ae61,
+            0x64befef28cc970f2,
+            0x613670957bc46611,
+            0xb87c5a554fd00ecb,
+            0x8c3ee88a1ccf32c8,
+            0x940c7922ae3a2614,
+            0x1841f924a2c509e4,
+            0x16f53526e70465c2,
+            0x75f644e97f30a13b,
+            0xeaf1ff7b5ceca249,
+        ];
+        assert_eq!(state, expected);
+    }
+}
+
+#[cfg(test)]
+mod sha256_compression_tests {
+    use super::sha256_compression;
+
+    #[test]
+    fn compresses_padded_message() {
+        let initial_state: [u32; 8] = [
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+            0x5be0cd19,
+        ];
+        // The single block of the message "abc" after SHA-256 padding.
+        let mut block = [0; 16];
+        block[0] = 0x61626380;
+        block[15] = 0x18;
+
+        let state = sha256_compression(initial_state, block).unwrap();
+
+        // SHA-256("abc")
+        let expected: [u32; 8] = [
+            0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+            0xf20015ad,
+        ];
+        assert_eq!(state, expected);
+    }
+}
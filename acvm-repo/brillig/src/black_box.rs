@@ -47,4 +47,74 @@ pub enum BlackBoxOp {
     PedersenHash { inputs: HeapVector, domain_separator: RegisterIndex, output: RegisterIndex },
     /// Performs scalar multiplication over the embedded curve.
     FixedBaseScalarMul { low: RegisterIndex, high: RegisterIndex, result: HeapArray },
+    /// Applies the Poseidon2 permutation function to the given state,
+    /// outputting the permuted state.
+    Poseidon2Permutation { message: HeapVector, output: HeapArray, len: RegisterIndex },
+    /// Adds two points of the embedded curve.
+    EmbeddedCurveAdd {
+        input1_x: RegisterIndex,
+        input1_y: RegisterIndex,
+        input2_x: RegisterIndex,
+        input2_y: RegisterIndex,
+        result: HeapArray,
+    },
+    /// Doubles a point of the embedded curve.
+    EmbeddedCurveDouble { input1_x: RegisterIndex, input1_y: RegisterIndex, result: HeapArray },
+    /// Applies the Keccak-f[1600] permutation to a state of 25 lanes of 64 bits.
+    Keccakf1600 { message: HeapVector, output: HeapArray },
+    /// Applies the SHA-256 compression function to a block of 16 words of 32 bits,
+    /// starting from a state of 8 words of 32 bits.
+    Sha256Compression { input: HeapVector, hash_values: HeapVector, output: HeapArray },
+    /// Adds two big integers, storing the identifier of the result in `output`.
+    BigIntAdd { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Subtracts a big integer from another, storing the identifier of the result in `output`.
+    BigIntSub { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Multiplies two big integers, storing the identifier of the result in `output`.
+    BigIntMul { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Divides a big integer by another, storing the identifier of the result in `output`.
+    BigIntDiv { lhs: RegisterIndex, rhs: RegisterIndex, output: RegisterIndex },
+    /// Creates a big integer from its little-endian bytes, reduced modulo the given modulus.
+    BigIntFromLeBytes { inputs: HeapVector, modulus: HeapVector, output: RegisterIndex },
+    /// Writes the little-endian bytes of a big integer, padded with zeros to the size of `output`.
+    BigIntToLeBytes { input: RegisterIndex, output: HeapArray },
+    /// Recovers the public key which produced an ECDSA signature over the secp256k1 curve,
+    /// writing its 32 byte x and y coordinates to `output`.
+    EcdsaSecp256k1Recover {
+        hashed_msg: HeapVector,
+        signature: HeapArray,
+        recovery_id: RegisterIndex,
+        output: HeapArray,
+    },
+    /// Calculates the Blake3 hash of the inputs.
+    Blake3 { message: HeapVector, output: HeapArray },
+}
+
+impl BlackBoxOp {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlackBoxOp::Sha256 { .. } => "sha256",
+            BlackBoxOp::Blake2s { .. } => "blake2s",
+            BlackBoxOp::Keccak256 { .. } => "keccak256",
+            BlackBoxOp::HashToField128Security { .. } => "hash_to_field_128_security",
+            BlackBoxOp::EcdsaSecp256k1 { .. } => "ecdsa_secp256k1",
+            BlackBoxOp::EcdsaSecp256r1 { .. } => "ecdsa_secp256r1",
+            BlackBoxOp::SchnorrVerify { .. } => "schnorr_verify",
+            BlackBoxOp::PedersenCommitment { .. } => "pedersen",
+            BlackBoxOp::PedersenHash { .. } => "pedersen_hash",
+            BlackBoxOp::FixedBaseScalarMul { .. } => "fixed_base_scalar_mul",
+            BlackBoxOp::Poseidon2Permutation { .. } => "poseidon2_permutation",
+            BlackBoxOp::EmbeddedCurveAdd { .. } => "ec_add",
+            BlackBoxOp::EmbeddedCurveDouble { .. } => "ec_double",
+            BlackBoxOp::Keccakf1600 { .. } => "keccakf1600",
+            BlackBoxOp::Sha256Compression { .. } => "sha256_compression",
+            BlackBoxOp::BigIntAdd { .. } => "bigint_add",
+            BlackBoxOp::BigIntSub { .. } => "bigint_sub",
+            BlackBoxOp::BigIntMul { .. } => "bigint_mul",
+            BlackBoxOp::BigIntDiv { .. } => "bigint_div",
+            BlackBoxOp::BigIntFromLeBytes { .. } => "bigint_from_le_bytes",
+            BlackBoxOp::BigIntToLeBytes { .. } => "bigint_to_le_bytes",
+            BlackBoxOp::EcdsaSecp256k1Recover { .. } => "ecdsa_secp256k1_recover",
+            BlackBoxOp::Blake3 { .. } => "blake3",
+        }
+    }
 }
@@ -215,6 +215,35 @@ mod tests {
         assert_eq!(evaluate_u128(&BinaryIntOp::Mul, a, b, bit_size), a - 2);
     }
 
+    #[test]
+    fn u128_test() {
+        let bit_size = 128;
+
+        evaluate_int_ops(
+            vec![TestParams { a: u128::MAX, b: 2, result: 1 }],
+            BinaryIntOp::Add,
+            bit_size,
+        );
+        evaluate_int_ops(
+            vec![TestParams { a: 1, b: 2, result: u128::MAX }],
+            BinaryIntOp::Sub,
+            bit_size,
+        );
+        evaluate_int_ops(
+            vec![
+                TestParams { a: u128::MAX, b: u128::MAX, result: 1 },
+                TestParams { a: 1 << 64, b: 1 << 64, result: 0 },
+            ],
+            BinaryIntOp::Mul,
+            bit_size,
+        );
+        evaluate_int_ops(
+            vec![TestParams { a: u128::MAX, b: 1 << 64, result: u64::MAX as u128 }],
+            BinaryIntOp::UnsignedDiv,
+            bit_size,
+        );
+    }
+
     #[test]
     fn div_test() {
         let bit_size = 4;
@@ -1,8 +1,9 @@
-use acir::brillig::{BlackBoxOp, HeapArray, HeapVector, Value};
+use acir::brillig::{BlackBoxOp, HeapArray, HeapVector, RegisterIndex, Value};
 use acir::{BlackBoxFunc, FieldElement};
 use acvm_blackbox_solver::{
-    blake2s, ecdsa_secp256k1_verify, ecdsa_secp256r1_verify, hash_to_field_128_security, keccak256,
-    sha256, BlackBoxFunctionSolver, BlackBoxResolutionError,
+    blake2s, blake3, ecdsa_secp256k1_recover, ecdsa_secp256k1_verify, ecdsa_secp256r1_verify,
+    hash_to_field_128_security, keccak256, keccakf1600, poseidon2_permutation, sha256,
+    sha256_compression, BlackBoxFunctionSolver, BlackBoxResolutionError, BrilligBigIntSolver,
 };
 
 use crate::{Memory, Registers};
@@ -35,15 +36,47 @@ fn to_u8_vec(inputs: &[Value]) -> Vec<u8> {
     result
 }
 
+/// Converts every value to a word of 32 bits
+fn to_u32_words(inputs: &[Value]) -> Result<Vec<u32>, BlackBoxResolutionError> {
+    inputs
+        .iter()
+        .map(|input| {
+            let field = input.to_field();
+            field.try_to_u64().and_then(|word| u32::try_from(word).ok()).ok_or_else(|| {
+                BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::Sha256Compression,
+                    format!("Word {} does not fit in 32 bits", field.to_hex()),
+                )
+            })
+        })
+        .collect()
+}
+
 fn to_value_vec(input: &[u8]) -> Vec<Value> {
     input.iter().map(|x| Value::from(*x as usize)).collect()
 }
 
+fn evaluate_bigint_op(
+    func: BlackBoxFunc,
+    lhs: RegisterIndex,
+    rhs: RegisterIndex,
+    output: RegisterIndex,
+    registers: &mut Registers,
+    bigint_solver: &mut BrilligBigIntSolver,
+) -> Result<(), BlackBoxResolutionError> {
+    let lhs = registers.get(lhs).to_usize() as u32;
+    let rhs = registers.get(rhs).to_usize() as u32;
+    let id = bigint_solver.bigint_op(lhs, rhs, func)?;
+    registers.set(output, Value::from(id as usize));
+    Ok(())
+}
+
 pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
     op: &BlackBoxOp,
     solver: &Solver,
     registers: &mut Registers,
     memory: &mut Memory,
+    bigint_solver: &mut BrilligBigIntSolver,
 ) -> Result<(), BlackBoxResolutionError> {
     match op {
         BlackBoxOp::Sha256 { message, output } => {
@@ -58,12 +91,115 @@ pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
             memory.write_slice(registers.get(output.pointer).to_usize(), &to_value_vec(&bytes));
             Ok(())
         }
+        BlackBoxOp::Blake3 { message, output } => {
+            let message = to_u8_vec(read_heap_vector(memory, registers, message));
+            let bytes = blake3(message.as_slice())?;
+            memory.write_slice(registers.get(output.pointer).to_usize(), &to_value_vec(&bytes));
+            Ok(())
+        }
         BlackBoxOp::Keccak256 { message, output } => {
             let message = to_u8_vec(read_heap_vector(memory, registers, message));
             let bytes = keccak256(message.as_slice())?;
             memory.write_slice(registers.get(output.pointer).to_usize(), &to_value_vec(&bytes));
             Ok(())
         }
+        BlackBoxOp::Keccakf1600 { message, output } => {
+            let lanes = read_heap_vector(memory, registers, message)
+                .iter()
+                .map(|lane| {
+                    lane.to_field().try_to_u64().ok_or_else(|| {
+                        BlackBoxResolutionError::Failed(
+                            BlackBoxFunc::Keccakf1600,
+                            format!("Lane {} does not fit in 64 bits", lane.to_field().to_hex()),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            let state: [u64; 25] = lanes.try_into().map_err(|lanes: Vec<u64>| {
+                BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::Keccakf1600,
+                    format!("Expected a state of 25 lanes but got {}", lanes.len()),
+                )
+            })?;
+            let state = keccakf1600(state)?;
+            let state: Vec<Value> =
+                state.iter().map(|lane| FieldElement::from(*lane as u128).into()).collect();
+            memory.write_slice(registers.get(output.pointer).to_usize(), &state);
+            Ok(())
+        }
+        BlackBoxOp::Sha256Compression { input, hash_values, output } => {
+            let block = to_u32_words(read_heap_vector(memory, registers, input))?;
+            let state = to_u32_words(read_heap_vector(memory, registers, hash_values))?;
+            let block: [u32; 16] = block.try_into().map_err(|block: Vec<u32>| {
+                BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::Sha256Compression,
+                    format!("Expected a block of 16 words but got {}", block.len()),
+                )
+            })?;
+            let state: [u32; 8] = state.try_into().map_err(|state: Vec<u32>| {
+                BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::Sha256Compression,
+                    format!("Expected a state of 8 words but got {}", state.len()),
+                )
+            })?;
+            let state = sha256_compression(state, block)?;
+            let state: Vec<Value> =
+                state.iter().map(|word| FieldElement::from(*word as u128).into()).collect();
+            memory.write_slice(registers.get(output.pointer).to_usize(), &state);
+            Ok(())
+        }
+        BlackBoxOp::BigIntAdd { lhs, rhs, output } => evaluate_bigint_op(
+            BlackBoxFunc::BigIntAdd,
+            *lhs,
+            *rhs,
+            *output,
+            registers,
+            bigint_solver,
+        ),
+        BlackBoxOp::BigIntSub { lhs, rhs, output } => evaluate_bigint_op(
+            BlackBoxFunc::BigIntSub,
+            *lhs,
+            *rhs,
+            *output,
+            registers,
+            bigint_solver,
+        ),
+        BlackBoxOp::BigIntMul { lhs, rhs, output } => evaluate_bigint_op(
+            BlackBoxFunc::BigIntMul,
+            *lhs,
+            *rhs,
+            *output,
+            registers,
+            bigint_solver,
+        ),
+        BlackBoxOp::BigIntDiv { lhs, rhs, output } => evaluate_bigint_op(
+            BlackBoxFunc::BigIntDiv,
+            *lhs,
+            *rhs,
+            *output,
+            registers,
+            bigint_solver,
+        ),
+        BlackBoxOp::BigIntFromLeBytes { inputs, modulus, output } => {
+            let inputs = to_u8_vec(read_heap_vector(memory, registers, inputs));
+            let modulus = to_u8_vec(read_heap_vector(memory, registers, modulus));
+            let id = bigint_solver.bigint_from_bytes(&inputs, &modulus)?;
+            registers.set(*output, Value::from(id as usize));
+            Ok(())
+        }
+        BlackBoxOp::BigIntToLeBytes { input, output } => {
+            let id = registers.get(*input).to_usize() as u32;
+            let mut bytes = bigint_solver.bigint_to_bytes(id)?;
+            if bytes.len() > output.size {
+                return Err(BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::BigIntToLeBytes,
+                    format!("Big integer {id} does not fit in {} bytes", output.size),
+                ));
+            }
+            bytes.resize(output.size, 0);
+            memory.write_slice(registers.get(output.pointer).to_usize(), &to_value_vec(&bytes));
+            Ok(())
+        }
         BlackBoxOp::HashToField128Security { message, output } => {
             let field = hash_to_field_128_security(&to_u8_vec(read_heap_vector(
                 memory, registers, message,
@@ -130,6 +266,34 @@ pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
             registers.set(*result_register, result.into());
             Ok(())
         }
+        BlackBoxOp::EcdsaSecp256k1Recover { hashed_msg, signature, recovery_id, output } => {
+            let bb_func = BlackBoxFunc::EcdsaSecp256k1Recover;
+            let hashed_msg: [u8; 32] = to_u8_vec(read_heap_vector(memory, registers, hashed_msg))
+                .try_into()
+                .map_err(|_| {
+                    BlackBoxResolutionError::Failed(
+                        bb_func,
+                        "Invalid hashed message length".to_string(),
+                    )
+                })?;
+            let signature: [u8; 64] = to_u8_vec(read_heap_array(memory, registers, signature))
+                .try_into()
+                .map_err(|_| {
+                    BlackBoxResolutionError::Failed(bb_func, "Invalid signature length".to_string())
+                })?;
+            let recovery_id =
+                u8::try_from(registers.get(*recovery_id).to_usize()).map_err(|_| {
+                    BlackBoxResolutionError::Failed(bb_func, "Invalid recovery id".to_string())
+                })?;
+
+            let (public_key_x, public_key_y) =
+                ecdsa_secp256k1_recover(&hashed_msg, &signature, recovery_id)?;
+            memory.write_slice(
+                registers.get(output.pointer).to_usize(),
+                &to_value_vec(&[public_key_x, public_key_y].concat()),
+            );
+            Ok(())
+        }
         BlackBoxOp::SchnorrVerify { public_key_x, public_key_y, message, signature, result } => {
             let public_key_x = registers.get(*public_key_x).to_field();
             let public_key_y = registers.get(*public_key_y).to_field();
@@ -175,12 +339,43 @@ pub(crate) fn evaluate_black_box<Solver: BlackBoxFunctionSolver>(
             registers.set(*output, hash.into());
             Ok(())
         }
+        BlackBoxOp::Poseidon2Permutation { message, output, len } => {
+            let input: Vec<FieldElement> =
+                read_heap_vector(memory, registers, message).iter().map(|x| x.to_field()).collect();
+            let len: u32 = registers.get(*len).to_u128().try_into().map_err(|_| {
+                BlackBoxResolutionError::Failed(
+                    BlackBoxFunc::Poseidon2Permutation,
+                    "Invalid state length".to_string(),
+                )
+            })?;
+            let state = poseidon2_permutation(&input, len)?;
+            let state: Vec<Value> = state.into_iter().map(Value::from).collect();
+            memory.write_slice(registers.get(output.pointer).to_usize(), &state);
+            Ok(())
+        }
+        BlackBoxOp::EmbeddedCurveAdd { input1_x, input1_y, input2_x, input2_y, result } => {
+            let input1_x = registers.get(*input1_x).to_field();
+            let input1_y = registers.get(*input1_y).to_field();
+            let input2_x = registers.get(*input2_x).to_field();
+            let input2_y = registers.get(*input2_y).to_field();
+            let (x, y) = solver.ec_add(&input1_x, &input1_y, &input2_x, &input2_y)?;
+            memory.write_slice(registers.get(result.pointer).to_usize(), &[x.into(), y.into()]);
+            Ok(())
+        }
+        BlackBoxOp::EmbeddedCurveDouble { input1_x, input1_y, result } => {
+            let input1_x = registers.get(*input1_x).to_field();
+            let input1_y = registers.get(*input1_y).to_field();
+            let (x, y) = solver.ec_double(&input1_x, &input1_y)?;
+            memory.write_slice(registers.get(result.pointer).to_usize(), &[x.into(), y.into()]);
+            Ok(())
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use acir::brillig::BlackBoxOp;
+    use acvm_blackbox_solver::BrilligBigIntSolver;
 
     use crate::{
         black_box::{evaluate_black_box, to_u8_vec, to_value_vec},
@@ -210,7 +405,14 @@ mod test {
             output: HeapArray { pointer: 2.into(), size: 32 },
         };
 
-        evaluate_black_box(&op, &DummyBlackBoxSolver, &mut registers, &mut memory).unwrap();
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
 
         let result = memory.read_slice(result_pointer, 32);
 
@@ -222,4 +424,299 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn blake3() {
+        let message: Vec<u8> = b"hello world".to_vec();
+        let message_length = message.len();
+
+        let mut memory = Memory::from(vec![]);
+        let message_pointer = 0;
+        let result_pointer = message_pointer + message_length;
+        memory.write_slice(message_pointer, to_value_vec(&message).as_slice());
+
+        let mut registers = Registers {
+            inner: vec![
+                Value::from(message_pointer),
+                Value::from(message_length),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let op = BlackBoxOp::Blake3 {
+            message: HeapVector { pointer: 0.into(), size: 1.into() },
+            output: HeapArray { pointer: 2.into(), size: 32 },
+        };
+
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
+
+        let result = memory.read_slice(result_pointer, 32);
+
+        assert_eq!(
+            to_u8_vec(result),
+            vec![
+                215, 73, 129, 239, 167, 10, 12, 136, 11, 141, 140, 25, 133, 208, 117, 219, 203,
+                246, 121, 185, 154, 95, 153, 20, 229, 170, 249, 107, 131, 26, 158, 36
+            ]
+        );
+    }
+
+    #[test]
+    fn ecdsa_secp256k1_recover() {
+        let hashed_msg: Vec<u8> = (1..=32).collect();
+        let signature: [u8; 64] = [
+            0xe5, 0x08, 0x1c, 0x80, 0xab, 0x42, 0x7d, 0xc3, 0x70, 0x34, 0x6f, 0x4a, 0x0e, 0x31,
+            0xaa, 0x2b, 0xad, 0x8d, 0x97, 0x98, 0xc3, 0x80, 0x61, 0xdb, 0x9a, 0xe5, 0x5a, 0x4e,
+            0x8d, 0xf4, 0x54, 0xfd, 0x28, 0x11, 0x98, 0x94, 0x34, 0x4e, 0x71, 0xb7, 0x87, 0x70,
+            0xcc, 0x93, 0x1d, 0x61, 0xf4, 0x80, 0xec, 0xbb, 0x0b, 0x89, 0xd6, 0xeb, 0x69, 0x69,
+            0x01, 0x61, 0xe4, 0x9a, 0x71, 0x5f, 0xcd, 0x55,
+        ];
+        let hashed_msg_pointer = 0;
+        let signature_pointer = hashed_msg.len();
+        let result_pointer = signature_pointer + signature.len();
+
+        let mut memory = Memory::from(vec![]);
+        memory.write_slice(hashed_msg_pointer, &to_value_vec(&hashed_msg));
+        memory.write_slice(signature_pointer, &to_value_vec(&signature));
+
+        let mut registers = Registers {
+            inner: vec![
+                Value::from(hashed_msg_pointer),
+                Value::from(hashed_msg.len()),
+                Value::from(signature_pointer),
+                Value::from(1_usize),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let op = BlackBoxOp::EcdsaSecp256k1Recover {
+            hashed_msg: HeapVector { pointer: 0.into(), size: 1.into() },
+            signature: HeapArray { pointer: 2.into(), size: 64 },
+            recovery_id: 3.into(),
+            output: HeapArray { pointer: 4.into(), size: 64 },
+        };
+
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
+
+        let result = to_u8_vec(memory.read_slice(result_pointer, 64));
+        let (public_key_x, public_key_y) = acvm_blackbox_solver::ecdsa_secp256k1_recover(
+            &hashed_msg.try_into().unwrap(),
+            &signature,
+            1,
+        )
+        .unwrap();
+        assert_eq!(result, [public_key_x, public_key_y].concat());
+    }
+
+    #[test]
+    fn keccakf1600() {
+        let state: Vec<Value> = (0..25usize).map(Value::from).collect();
+        let state_pointer = 0;
+        let result_pointer = state.len();
+
+        let mut memory = Memory::from(vec![]);
+        memory.write_slice(state_pointer, &state);
+
+        let mut registers = Registers {
+            inner: vec![
+                Value::from(state_pointer),
+                Value::from(state.len()),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let op = BlackBoxOp::Keccakf1600 {
+            message: HeapVector { pointer: 0.into(), size: 1.into() },
+            output: HeapArray { pointer: 2.into(), size: 25 },
+        };
+
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
+
+        let result = memory.read_slice(result_pointer, 25);
+        let expected =
+            acvm_blackbox_solver::keccakf1600(std::array::from_fn(|lane| lane as u64)).unwrap();
+        assert_eq!(
+            result.iter().map(Value::to_field).collect::<Vec<_>>(),
+            expected.iter().map(|lane| acir::FieldElement::from(*lane as u128)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn sha256_compression() {
+        let mut block = [0; 16];
+        block[0] = 0x61626380;
+        block[15] = 0x18;
+        let state: [u32; 8] = [
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+            0x5be0cd19,
+        ];
+        let inputs: Vec<Value> =
+            block.iter().chain(&state).map(|word| Value::from(*word as usize)).collect();
+        let block_pointer = 0;
+        let state_pointer = block.len();
+        let result_pointer = inputs.len();
+
+        let mut memory = Memory::from(vec![]);
+        memory.write_slice(block_pointer, &inputs);
+
+        let mut registers = Registers {
+            inner: vec![
+                Value::from(block_pointer),
+                Value::from(block.len()),
+                Value::from(state_pointer),
+                Value::from(state.len()),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let op = BlackBoxOp::Sha256Compression {
+            input: HeapVector { pointer: 0.into(), size: 1.into() },
+            hash_values: HeapVector { pointer: 2.into(), size: 3.into() },
+            output: HeapArray { pointer: 4.into(), size: 8 },
+        };
+
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
+
+        // SHA-256("abc"), as the block is the padded message "abc".
+        let result = memory.read_slice(result_pointer, 8);
+        assert_eq!(
+            result.iter().map(|word| word.to_usize()).collect::<Vec<_>>(),
+            vec![
+                0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+                0xf20015ad
+            ]
+        );
+    }
+
+    #[test]
+    fn bigint_operations() {
+        // 0x1234 and 2 modulo 0x2401, written to memory as little-endian bytes.
+        let bytes: Vec<Value> =
+            [0x34, 0x12, 0x02, 0x01, 0x24].iter().map(|byte| Value::from(*byte as usize)).collect();
+        let result_pointer = bytes.len();
+
+        let mut memory = Memory::from(vec![]);
+        memory.write_slice(0, &bytes);
+
+        let mut registers = Registers {
+            inner: vec![
+                // Pointers and sizes of the bytes of the operands and of the modulus.
+                Value::from(0usize),
+                Value::from(2usize),
+                Value::from(2usize),
+                Value::from(1usize),
+                Value::from(3usize),
+                Value::from(2usize),
+                // Identifiers of the operands and of their product.
+                Value::from(0usize),
+                Value::from(0usize),
+                Value::from(0usize),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let modulus = HeapVector { pointer: 4.into(), size: 5.into() };
+        let ops = [
+            BlackBoxOp::BigIntFromLeBytes {
+                inputs: HeapVector { pointer: 0.into(), size: 1.into() },
+                modulus,
+                output: 6.into(),
+            },
+            BlackBoxOp::BigIntFromLeBytes {
+                inputs: HeapVector { pointer: 2.into(), size: 3.into() },
+                modulus,
+                output: 7.into(),
+            },
+            BlackBoxOp::BigIntMul { lhs: 6.into(), rhs: 7.into(), output: 8.into() },
+            BlackBoxOp::BigIntToLeBytes {
+                input: 8.into(),
+                output: HeapArray { pointer: 9.into(), size: 3 },
+            },
+        ];
+
+        let mut bigint_solver = BrilligBigIntSolver::default();
+        for op in &ops {
+            evaluate_black_box(
+                op,
+                &DummyBlackBoxSolver,
+                &mut registers,
+                &mut memory,
+                &mut bigint_solver,
+            )
+            .unwrap();
+        }
+
+        // 0x1234 * 2 = 0x2468 = 0x67 (mod 0x2401)
+        let result = memory.read_slice(result_pointer, 3);
+        assert_eq!(to_u8_vec(result), vec![0x67, 0, 0]);
+    }
+
+    #[test]
+    fn poseidon2_permutation() {
+        let state: Vec<Value> = (0..4usize).map(Value::from).collect();
+        let state_pointer = 0;
+        let result_pointer = state.len();
+
+        let mut memory = Memory::from(vec![]);
+        memory.write_slice(state_pointer, &state);
+
+        let mut registers = Registers {
+            inner: vec![
+                Value::from(state_pointer),
+                Value::from(state.len()),
+                Value::from(result_pointer),
+            ],
+        };
+
+        let op = BlackBoxOp::Poseidon2Permutation {
+            message: HeapVector { pointer: 0.into(), size: 1.into() },
+            output: HeapArray { pointer: 2.into(), size: 4 },
+            len: 1.into(),
+        };
+
+        evaluate_black_box(
+            &op,
+            &DummyBlackBoxSolver,
+            &mut registers,
+            &mut memory,
+            &mut BrilligBigIntSolver::default(),
+        )
+        .unwrap();
+
+        let result = memory.read_slice(result_pointer, 4);
+        let expected = acvm_blackbox_solver::poseidon2_permutation(
+            &state.iter().map(Value::to_field).collect::<Vec<_>>(),
+            4,
+        )
+        .unwrap();
+        assert_eq!(result.iter().map(Value::to_field).collect::<Vec<_>>(), expected);
+    }
 }
@@ -24,7 +24,7 @@ mod black_box;
 mod memory;
 mod registers;
 
-use acvm_blackbox_solver::{BlackBoxFunctionSolver, BlackBoxResolutionError};
+use acvm_blackbox_solver::{BlackBoxFunctionSolver, BlackBoxResolutionError, BrilligBigIntSolver};
 use arithmetic::{evaluate_binary_bigint_op, evaluate_binary_field_op};
 use black_box::evaluate_black_box;
 
@@ -35,6 +35,59 @@ pub use registers::Registers;
 /// The error call stack contains the opcode indexes of the call stack at the time of failure, plus the index of the opcode that failed.
 pub type ErrorCallStack = Vec<usize>;
 
+/// Bounds on the resources which a Brillig process may consume.
+///
+/// Unconstrained code is free to loop forever or allocate unboundedly, so callers executing
+/// untrusted programs should set these to ensure that execution halts.
+///
+/// The [default][ExecutionLimits::default] limits are generous enough for any reasonable program
+/// while still guaranteeing termination: see [ExecutionLimits::DEFAULT_MAX_STEPS] and
+/// [ExecutionLimits::DEFAULT_MAX_MEMORY_SIZE]. A limit of `None` leaves that resource unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    /// The maximum number of opcodes which may be executed.
+    pub max_steps: Option<usize>,
+    /// The maximum number of memory slots which may be in use.
+    pub max_memory_size: Option<usize>,
+}
+
+impl ExecutionLimits {
+    /// The number of opcodes which may be executed by default (2^32 - 1, so that it fits in a
+    /// 32-bit `usize`).
+    pub const DEFAULT_MAX_STEPS: usize = u32::MAX as usize;
+    /// The number of memory slots which may be in use by default (2^24).
+    pub const DEFAULT_MAX_MEMORY_SIZE: usize = 1 << 24;
+}
+
+impl Default for ExecutionLimits {
+    fn default() -> Self {
+        ExecutionLimits {
+            max_steps: Some(Self::DEFAULT_MAX_STEPS),
+            max_memory_size: Some(Self::DEFAULT_MAX_MEMORY_SIZE),
+        }
+    }
+}
+
+/// An [execution limit][ExecutionLimits] which has been exceeded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExceededLimit {
+    /// More than the given number of opcodes would have been executed.
+    Steps(usize),
+    /// More than the given number of memory slots would have been used.
+    MemorySize(usize),
+}
+
+impl std::fmt::Display for ExceededLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExceededLimit::Steps(limit) => write!(f, "step limit of {limit} exceeded"),
+            ExceededLimit::MemorySize(limit) => {
+                write!(f, "memory limit of {limit} slots exceeded")
+            }
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum VMStatus {
     Finished,
@@ -43,6 +96,11 @@ pub enum VMStatus {
         message: String,
         call_stack: ErrorCallStack,
     },
+    /// The VM has been halted as continuing execution would exceed one of its [ExecutionLimits].
+    LimitExceeded {
+        limit: ExceededLimit,
+        call_stack: ErrorCallStack,
+    },
     /// The VM process is not solvable as a [foreign call][Opcode::ForeignCall] has been
     /// reached where the outputs are yet to be resolved.
     ///
@@ -81,6 +139,18 @@ pub struct VM<'a, B: BlackBoxFunctionSolver> {
     call_stack: Vec<Value>,
     /// The solver for blackbox functions
     black_box_solver: &'a B,
+    /// The big integers created by this process
+    ///
+    /// This is boxed as most processes never create any, and it would otherwise make the VM much larger.
+    bigint_solver: Box<BrilligBigIntSolver>,
+    /// The number of times each opcode has been executed, indexed by program counter.
+    ///
+    /// This is only tracked once profiling has been enabled through [VM::enable_profiling].
+    profiling_samples: Option<Vec<usize>>,
+    /// Bounds on the resources this process may consume
+    limits: ExecutionLimits,
+    /// The number of opcodes which have been executed
+    steps: usize,
 }
 
 impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
@@ -102,9 +172,36 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
             memory: memory.into(),
             call_stack: Vec::new(),
             black_box_solver,
+            bigint_solver: Box::default(),
+            profiling_samples: None,
+            limits: ExecutionLimits::default(),
+            steps: 0,
         }
     }
 
+    /// Sets the bounds on the resources this process may consume.
+    pub fn set_limits(&mut self, limits: ExecutionLimits) {
+        self.limits = limits;
+    }
+
+    /// Returns the number of opcodes which have been executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Starts recording the number of times each opcode is executed.
+    pub fn enable_profiling(&mut self) {
+        if self.profiling_samples.is_none() {
+            self.profiling_samples = Some(vec![0; self.bytecode.len()]);
+        }
+    }
+
+    /// Returns the number of times each opcode has been executed, indexed by program counter,
+    /// or `None` if profiling has not been enabled.
+    pub fn profiling_samples(&self) -> Option<&[usize]> {
+        self.profiling_samples.as_deref()
+    }
+
     /// Updates the current status of the VM.
     /// Returns the given status.
     fn status(&mut self, status: VMStatus) -> VMStatus {
@@ -150,11 +247,33 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
         self.status.clone()
     }
 
+    /// Sets the status of the VM to `LimitExceeded`.
+    /// Indicating that the VM has been halted before it could exceed one of its [ExecutionLimits].
+    fn exceed_limit(&mut self, limit: ExceededLimit) -> VMStatus {
+        let mut error_stack: Vec<_> =
+            self.call_stack.iter().map(|value| value.to_usize()).collect();
+        error_stack.push(self.program_counter);
+        self.status(VMStatus::LimitExceeded { limit, call_stack: error_stack })
+    }
+
+    /// Checks that writing `size` values to memory starting at `pointer` would not exceed the memory limit.
+    fn check_memory_limit(&self, pointer: usize, size: usize) -> Result<(), ExceededLimit> {
+        match self.limits.max_memory_size {
+            Some(limit) if pointer.saturating_add(size) > limit => {
+                Err(ExceededLimit::MemorySize(limit))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Loop over the bytecode and update the program counter
     pub fn process_opcodes(&mut self) -> VMStatus {
         while !matches!(
             self.process_opcode(),
-            VMStatus::Finished | VMStatus::Failure { .. } | VMStatus::ForeignCallWait { .. }
+            VMStatus::Finished
+                | VMStatus::Failure { .. }
+                | VMStatus::LimitExceeded { .. }
+                | VMStatus::ForeignCallWait { .. }
         ) {}
         self.status.clone()
     }
@@ -170,6 +289,39 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
 
     /// Process a single opcode and modify the program counter.
     pub fn process_opcode(&mut self) -> VMStatus {
+        if let Some(max_steps) = self.limits.max_steps {
+            if self.steps >= max_steps {
+                return self.exceed_limit(ExceededLimit::Steps(max_steps));
+            }
+        }
+
+        let program_counter = self.program_counter;
+        let status = self.process_opcode_internal();
+
+        // A foreign call is only executed once its result has been provided,
+        // at which point the VM will process the same opcode again.
+        if !matches!(status, VMStatus::ForeignCallWait { .. }) {
+            self.steps += 1;
+            if let Some(samples) = &mut self.profiling_samples {
+                samples[program_counter] += 1;
+            }
+        }
+
+        if let Some(limit) = self.limits.max_memory_size {
+            // Writes to memory by black box functions are not checked ahead of time so we catch
+            // these here, reporting the opcode which exceeded the limit.
+            if self.memory.values().len() > limit
+                && !matches!(status, VMStatus::LimitExceeded { .. })
+            {
+                self.program_counter = program_counter;
+                return self.exceed_limit(ExceededLimit::MemorySize(limit));
+            }
+        }
+
+        status
+    }
+
+    fn process_opcode_internal(&mut self) -> VMStatus {
         let opcode = &self.bytecode[self.program_counter];
         match opcode {
             Opcode::BinaryFieldOp { op, lhs, rhs, destination: result } => {
@@ -246,6 +398,9 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
                                     }
                                     // Convert the destination pointer to a usize
                                     let destination = self.registers.get(*pointer_index).to_usize();
+                                    if let Err(limit) = self.check_memory_limit(destination, values.len()) {
+                                        return self.exceed_limit(limit);
+                                    }
                                     // Write to our destination memory
                                     self.memory.write_slice(destination, values);
                                 }
@@ -261,6 +416,9 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
                                     self.registers.set(*size_index, Value::from(values.len()));
                                     // Convert the destination pointer to a usize
                                     let destination = self.registers.get(*pointer_index).to_usize();
+                                    if let Err(limit) = self.check_memory_limit(destination, values.len()) {
+                                        return self.exceed_limit(limit);
+                                    }
                                     // Write to our destination memory
                                     self.memory.write_slice(destination, values);
                                 }
@@ -301,6 +459,9 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
             Opcode::Store { destination_pointer, source: source_register } => {
                 // Convert our destination_pointer to a usize
                 let destination = self.registers.get(*destination_pointer).to_usize();
+                if let Err(limit) = self.check_memory_limit(destination, 1) {
+                    return self.exceed_limit(limit);
+                }
                 // Use our usize destination index to set the value in memory
                 self.memory.write(destination, self.registers.get(*source_register));
                 self.increment_program_counter()
@@ -320,6 +481,7 @@ impl<'a, B: BlackBoxFunctionSolver> VM<'a, B> {
                     self.black_box_solver,
                     &mut self.registers,
                     &mut self.memory,
+                    &mut self.bigint_solver,
                 ) {
                     Ok(()) => self.increment_program_counter(),
                     Err(e) => self.fail(e.to_string()),
@@ -443,6 +605,22 @@ impl BlackBoxFunctionSolver for DummyBlackBoxSolver {
     ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
         Ok((4_u128.into(), 5_u128.into()))
     }
+    fn ec_add(
+        &self,
+        _input1_x: &FieldElement,
+        _input1_y: &FieldElement,
+        _input2_x: &FieldElement,
+        _input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Ok((5_u128.into(), 6_u128.into()))
+    }
+    fn ec_double(
+        &self,
+        _input1_x: &FieldElement,
+        _input1_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Ok((7_u128.into(), 8_u128.into()))
+    }
 }
 
 #[cfg(test)]
@@ -967,6 +1145,92 @@ mod tests {
         // Ensure the foreign call counter has been incremented
         assert_eq!(vm.foreign_call_counter, 1);
     }
+
+    #[test]
+    fn profiling_counts_executed_opcodes() {
+        let r_counter = RegisterIndex::from(0);
+        let r_one = RegisterIndex::from(1);
+        let r_limit = RegisterIndex::from(2);
+        let r_condition = RegisterIndex::from(3);
+
+        let program = vec![
+            Opcode::Const { destination: r_counter, value: Value::from(0u128) },
+            Opcode::Const { destination: r_one, value: Value::from(1u128) },
+            Opcode::Const { destination: r_limit, value: Value::from(3u128) },
+            // Loop body: print the counter and increment it until it reaches the limit
+            Opcode::ForeignCall {
+                function: "print".into(),
+                destinations: vec![],
+                inputs: vec![RegisterOrMemory::RegisterIndex(r_counter)],
+            },
+            Opcode::BinaryIntOp {
+                op: BinaryIntOp::Add,
+                bit_size: 32,
+                lhs: r_counter,
+                rhs: r_one,
+                destination: r_counter,
+            },
+            Opcode::BinaryIntOp {
+                op: BinaryIntOp::LessThan,
+                bit_size: 32,
+                lhs: r_counter,
+                rhs: r_limit,
+                destination: r_condition,
+            },
+            Opcode::JumpIf { condition: r_condition, location: 3 },
+        ];
+
+        let mut vm =
+            VM::new(Registers::load(vec![]), vec![], &program, vec![], &DummyBlackBoxSolver);
+        assert_eq!(vm.profiling_samples(), None);
+        vm.enable_profiling();
+
+        while let VMStatus::ForeignCallWait { .. } = vm.process_opcodes() {
+            vm.resolve_foreign_call(ForeignCallResult { values: vec![] });
+        }
+        assert_eq!(vm.status, VMStatus::Finished);
+
+        // Foreign calls are only counted once they have been resolved.
+        assert_eq!(vm.profiling_samples(), Some([1, 1, 1, 3, 3, 3, 3].as_slice()));
+    }
+
+    #[test]
+    fn step_limit_halts_infinite_loop() {
+        let opcodes = [Opcode::Jump { location: 0 }];
+        let mut vm = VM::new(empty_registers(), vec![], &opcodes, vec![], &DummyBlackBoxSolver);
+        vm.set_limits(ExecutionLimits { max_steps: Some(10), ..ExecutionLimits::default() });
+
+        let status = vm.process_opcodes();
+        assert_eq!(
+            status,
+            VMStatus::LimitExceeded { limit: ExceededLimit::Steps(10), call_stack: vec![0] }
+        );
+        assert_eq!(vm.steps(), 10);
+    }
+
+    #[test]
+    fn memory_limit_halts_out_of_bounds_store() {
+        let r_pointer = RegisterIndex::from(0);
+        let r_value = RegisterIndex::from(1);
+
+        let opcodes = [
+            Opcode::Const { destination: r_value, value: Value::from(1u128) },
+            Opcode::Const { destination: r_pointer, value: Value::from(3u128) },
+            Opcode::Store { destination_pointer: r_pointer, source: r_value },
+            Opcode::Const { destination: r_pointer, value: Value::from(u64::MAX as u128) },
+            Opcode::Store { destination_pointer: r_pointer, source: r_value },
+        ];
+        let mut vm = VM::new(empty_registers(), vec![], &opcodes, vec![], &DummyBlackBoxSolver);
+        vm.set_limits(ExecutionLimits { max_memory_size: Some(4), ..ExecutionLimits::default() });
+
+        let status = vm.process_opcodes();
+        assert_eq!(
+            status,
+            VMStatus::LimitExceeded { limit: ExceededLimit::MemorySize(4), call_stack: vec![4] }
+        );
+        assert_eq!(vm.get_memory().len(), 4);
+    }
+
     #[test]
     fn foreign_call_opcode_memory_result() {
         let r_input = RegisterIndex::from(0);
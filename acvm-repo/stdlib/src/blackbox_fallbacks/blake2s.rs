@@ -302,7 +302,7 @@ fn blake2s_round(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn g(
+pub(super) fn g(
     state: &mut [UInt32],
     a: usize,
     b: usize,
@@ -0,0 +1,344 @@
+//! Blake3 fallback function.
+use super::{
+    blake2s::g,
+    utils::{byte_decomposition, round_to_nearest_byte},
+    UInt32,
+};
+use acir::{
+    circuit::Opcode,
+    native_types::{Expression, Witness},
+    FieldElement,
+};
+
+const BLAKE3_BLOCK_LEN: usize = 64;
+const BLAKE3_CHUNK_LEN: usize = 1024;
+const MSG_SCHEDULE_BLAKE3: [[usize; 16]; 7] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8],
+    [3, 4, 10, 12, 13, 2, 7, 14, 6, 5, 9, 0, 11, 15, 8, 1],
+    [10, 7, 12, 9, 14, 3, 13, 15, 4, 0, 11, 2, 5, 8, 1, 6],
+    [12, 13, 9, 11, 15, 10, 14, 8, 7, 2, 5, 3, 0, 1, 6, 4],
+    [9, 14, 11, 5, 8, 12, 15, 1, 13, 3, 0, 10, 2, 6, 4, 7],
+    [11, 15, 5, 0, 1, 9, 8, 6, 14, 10, 2, 12, 3, 4, 7, 13],
+];
+const IV_VALUE: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+// Domain separation flags
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+
+pub fn blake3(
+    inputs: Vec<(Expression, u32)>,
+    outputs: Vec<Witness>,
+    mut num_witness: u32,
+) -> (u32, Vec<Opcode>) {
+    let mut new_opcodes = Vec::new();
+    let mut new_inputs = Vec::new();
+
+    // Decompose the input field elements into bytes and collect the resulting witnesses.
+    for (witness, num_bits) in inputs {
+        let num_bytes = round_to_nearest_byte(num_bits);
+        let (extra_opcodes, extra_inputs, updated_witness_counter) =
+            byte_decomposition(witness, num_bytes, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        new_inputs.extend(extra_inputs);
+        num_witness = updated_witness_counter;
+    }
+
+    let (result, num_witness, extra_opcodes) = create_blake3_constraint(new_inputs, num_witness);
+    new_opcodes.extend(extra_opcodes);
+
+    // constrain the outputs to be the same as the result of the circuit
+    for i in 0..outputs.len() {
+        let mut expr = Expression::from(outputs[i]);
+        expr.push_addition_term(-FieldElement::one(), result[i]);
+        new_opcodes.push(Opcode::Arithmetic(expr));
+    }
+    (num_witness, new_opcodes)
+}
+
+fn create_blake3_constraint(
+    input: Vec<Witness>,
+    num_witness: u32,
+) -> (Vec<Witness>, u32, Vec<Opcode>) {
+    let mut new_opcodes = Vec::new();
+
+    // prepare constants
+    let (blake3_iv, extra_opcodes, num_witness) = Blake3IV::init(num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (zero, extra_opcodes, mut num_witness) = UInt32::load_constant(0_u32, num_witness);
+    new_opcodes.extend(extra_opcodes);
+
+    // The input is split into chunks of 1024 bytes, which form the leaves of a binary tree.
+    // Each chunk's chaining value is merged into a stack of subtree roots as soon as it completes a subtree,
+    // and the remaining subtrees are merged from right to left once the last chunk is reached.
+    let num_chunks = std::cmp::max(1, (input.len() + BLAKE3_CHUNK_LEN - 1) / BLAKE3_CHUNK_LEN);
+    let mut cv_stack: Vec<Vec<UInt32>> = Vec::new();
+    let mut output = Vec::new();
+
+    for chunk_index in 0..num_chunks {
+        let chunk_end = std::cmp::min(input.len(), (chunk_index + 1) * BLAKE3_CHUNK_LEN);
+        let chunk = &input[chunk_index * BLAKE3_CHUNK_LEN..chunk_end];
+        let is_last_chunk = chunk_index + 1 == num_chunks;
+
+        let (mut chaining_value, extra_opcodes, updated_witness_counter) = blake3_chunk(
+            &blake3_iv,
+            chunk,
+            chunk_index as u64,
+            num_chunks == 1,
+            zero.inner,
+            num_witness,
+        );
+        new_opcodes.extend(extra_opcodes);
+        num_witness = updated_witness_counter;
+
+        if is_last_chunk {
+            while let Some(left_child) = cv_stack.pop() {
+                let flags = if cv_stack.is_empty() { PARENT | ROOT } else { PARENT };
+                let (parent, extra_opcodes, updated_witness_counter) =
+                    blake3_parent(&blake3_iv, left_child, chaining_value, flags, num_witness);
+                new_opcodes.extend(extra_opcodes);
+                chaining_value = parent;
+                num_witness = updated_witness_counter;
+            }
+            output = chaining_value;
+        } else {
+            let mut total_chunks = chunk_index + 1;
+            while total_chunks & 1 == 0 {
+                let left_child = cv_stack.pop().unwrap();
+                let (parent, extra_opcodes, updated_witness_counter) =
+                    blake3_parent(&blake3_iv, left_child, chaining_value, PARENT, num_witness);
+                new_opcodes.extend(extra_opcodes);
+                chaining_value = parent;
+                num_witness = updated_witness_counter;
+                total_chunks >>= 1;
+            }
+            cv_stack.push(chaining_value);
+        }
+    }
+
+    // decompose the result bytes in u32 to u8
+    let mut result = Vec::new();
+    for word in output {
+        let (extra_opcodes, mut bytes, updated_witness_counter) =
+            byte_decomposition(Expression::from(word.inner), 4, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        bytes.reverse();
+        result.extend(bytes);
+        num_witness = updated_witness_counter;
+    }
+
+    (result, num_witness, new_opcodes)
+}
+
+/// Compresses the blocks of a chunk, returning its chaining value.
+fn blake3_chunk(
+    blake3_iv: &Blake3IV,
+    chunk: &[Witness],
+    chunk_counter: u64,
+    is_root: bool,
+    zero: Witness,
+    mut num_witness: u32,
+) -> (Vec<UInt32>, Vec<Opcode>, u32) {
+    let mut new_opcodes = Vec::new();
+    let mut chaining_value = blake3_iv.iv.clone();
+
+    // An empty input is hashed as a single empty block.
+    let blocks: Vec<&[Witness]> =
+        if chunk.is_empty() { vec![chunk] } else { chunk.chunks(BLAKE3_BLOCK_LEN).collect() };
+    let num_blocks = blocks.len();
+
+    for (block_index, block) in blocks.into_iter().enumerate() {
+        let mut flags = 0;
+        if block_index == 0 {
+            flags |= CHUNK_START;
+        }
+        if block_index + 1 == num_blocks {
+            flags |= CHUNK_END;
+            if is_root {
+                flags |= ROOT;
+            }
+        }
+
+        // pad the block with zeros
+        let mut padded_block = block.to_vec();
+        padded_block.resize(BLAKE3_BLOCK_LEN, zero);
+
+        let (message, extra_opcodes, updated_witness_counter) =
+            message_words(&padded_block, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        let (new_chaining_value, extra_opcodes, updated_witness_counter) = blake3_compress(
+            &chaining_value,
+            &message,
+            chunk_counter,
+            block.len() as u32,
+            flags,
+            blake3_iv,
+            updated_witness_counter,
+        );
+        new_opcodes.extend(extra_opcodes);
+        chaining_value = new_chaining_value;
+        num_witness = updated_witness_counter;
+    }
+
+    (chaining_value, new_opcodes, num_witness)
+}
+
+/// Compresses the chaining values of two children of the tree, returning the chaining value of their parent.
+fn blake3_parent(
+    blake3_iv: &Blake3IV,
+    left_child: Vec<UInt32>,
+    right_child: Vec<UInt32>,
+    flags: u32,
+    num_witness: u32,
+) -> (Vec<UInt32>, Vec<Opcode>, u32) {
+    let message = [left_child, right_child].concat();
+    blake3_compress(
+        &blake3_iv.iv,
+        &message,
+        0,
+        BLAKE3_BLOCK_LEN as u32,
+        flags,
+        blake3_iv,
+        num_witness,
+    )
+}
+
+/// Loads a block of 64 bytes as 16 little-endian words.
+fn message_words(block: &[Witness], mut num_witness: u32) -> (Vec<UInt32>, Vec<Opcode>, u32) {
+    let mut new_opcodes = Vec::new();
+    let mut m = Vec::new();
+
+    for i in 0..16 {
+        let mut mi_bytes = block.get(i * 4..i * 4 + 4).unwrap().to_vec();
+        mi_bytes.reverse();
+        let (mi, extra_opcodes, updated_witness_counter) =
+            UInt32::from_witnesses(&mi_bytes, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        m.push(mi[0]);
+        num_witness = updated_witness_counter;
+    }
+
+    (m, new_opcodes, num_witness)
+}
+
+/// Applies the Blake3 compression function, returning the first 8 words of its output.
+fn blake3_compress(
+    chaining_value: &[UInt32],
+    message: &[UInt32],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+    blake3_iv: &Blake3IV,
+    num_witness: u32,
+) -> (Vec<UInt32>, Vec<Opcode>, u32) {
+    let mut new_opcodes = Vec::new();
+    let mut v = Vec::new();
+
+    v.extend_from_slice(chaining_value);
+    v.extend_from_slice(&blake3_iv.iv[0..4]);
+    let (counter_low, extra_opcodes, num_witness) =
+        UInt32::load_constant(counter as u32, num_witness);
+    new_opcodes.extend(extra_opcodes);
+    v.push(counter_low);
+    let (counter_high, extra_opcodes, num_witness) =
+        UInt32::load_constant((counter >> 32) as u32, num_witness);
+    new_opcodes.extend(extra_opcodes);
+    v.push(counter_high);
+    let (block_len, extra_opcodes, num_witness) = UInt32::load_constant(block_len, num_witness);
+    new_opcodes.extend(extra_opcodes);
+    v.push(block_len);
+    let (flags, extra_opcodes, mut num_witness) = UInt32::load_constant(flags, num_witness);
+    new_opcodes.extend(extra_opcodes);
+    v.push(flags);
+
+    for round in 0..MSG_SCHEDULE_BLAKE3.len() {
+        let (extra_opcodes, updated_witness_counter) =
+            blake3_round(&mut v, message, round, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        num_witness = updated_witness_counter;
+    }
+
+    let mut output = Vec::new();
+    for (low, high) in v[..8].iter().zip(&v[8..]) {
+        let (output_word, extra_opcodes, updated_witness_counter) = low.xor(high, num_witness);
+        new_opcodes.extend(extra_opcodes);
+        output.push(output_word);
+        num_witness = updated_witness_counter;
+    }
+
+    (output, new_opcodes, num_witness)
+}
+
+fn blake3_round(
+    state: &mut [UInt32],
+    msg: &[UInt32],
+    round: usize,
+    num_witness: u32,
+) -> (Vec<Opcode>, u32) {
+    let mut new_opcodes = Vec::new();
+    let schedule = &MSG_SCHEDULE_BLAKE3[round];
+
+    // Mix the columns.
+    let (extra_opcodes, num_witness) =
+        g(state, 0, 4, 8, 12, msg[schedule[0]], msg[schedule[1]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 1, 5, 9, 13, msg[schedule[2]], msg[schedule[3]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 2, 6, 10, 14, msg[schedule[4]], msg[schedule[5]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 3, 7, 11, 15, msg[schedule[6]], msg[schedule[7]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+
+    // Mix the diagonals.
+    let (extra_opcodes, num_witness) =
+        g(state, 0, 5, 10, 15, msg[schedule[8]], msg[schedule[9]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 1, 6, 11, 12, msg[schedule[10]], msg[schedule[11]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 2, 7, 8, 13, msg[schedule[12]], msg[schedule[13]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (extra_opcodes, num_witness) =
+        g(state, 3, 4, 9, 14, msg[schedule[14]], msg[schedule[15]], num_witness);
+    new_opcodes.extend(extra_opcodes);
+
+    (new_opcodes, num_witness)
+}
+
+/// Blake3 IV (Initialization Vector), which is also the key of the default hash mode
+struct Blake3IV {
+    iv: Vec<UInt32>,
+}
+
+impl Blake3IV {
+    fn new(iv: Vec<UInt32>) -> Self {
+        Blake3IV { iv }
+    }
+
+    /// Initialize IV of Blake3
+    fn init(mut num_witness: u32) -> (Blake3IV, Vec<Opcode>, u32) {
+        let mut new_opcodes = Vec::new();
+        let mut iv = Vec::new();
+
+        for iv_v in IV_VALUE {
+            let (new_witness, extra_opcodes, updated_witness_counter) =
+                UInt32::load_constant(iv_v, num_witness);
+            new_opcodes.extend(extra_opcodes);
+            iv.push(new_witness);
+            num_witness = updated_witness_counter;
+        }
+
+        let blake3_iv = Blake3IV::new(iv);
+
+        (blake3_iv, new_opcodes, num_witness)
+    }
+}
@@ -6,7 +6,10 @@ use super::{
     UInt64,
 };
 use acir::{
-    circuit::Opcode,
+    circuit::{
+        opcodes::{BlackBoxFuncCall, FunctionInput},
+        Opcode,
+    },
     native_types::{Expression, Witness},
     FieldElement,
 };
@@ -76,6 +79,39 @@ pub fn keccak256(
     (num_witness, new_opcodes)
 }
 
+/// Keccak-f[1600] permutation fallback function, over the 25 lanes of 64 bits of the state.
+pub fn keccakf1600(
+    inputs: Vec<Witness>,
+    outputs: Vec<Witness>,
+    mut num_witness: u32,
+) -> (u32, Vec<Opcode>) {
+    let mut new_opcodes = Vec::new();
+
+    // The lanes are treated as 64 bit integers, which is only sound if they fit in 64 bits.
+    for &lane in &inputs {
+        new_opcodes.push(Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness: lane, num_bits: 64 },
+        }));
+    }
+
+    let mut state: Vec<UInt64> = inputs.into_iter().map(UInt64::new).collect();
+    for round_constant in ROUND_CONSTANTS {
+        let (new_state, extra_opcodes, updated_witness_counter) =
+            keccak_round(state, round_constant, num_witness);
+        state = new_state;
+        new_opcodes.extend(extra_opcodes);
+        num_witness = updated_witness_counter;
+    }
+
+    // constrain the outputs to be the same as the permuted lanes
+    for (output, lane) in outputs.into_iter().zip(state) {
+        let mut expr = Expression::from(output);
+        expr.push_addition_term(-FieldElement::one(), lane.inner);
+        new_opcodes.push(Opcode::Arithmetic(expr));
+    }
+    (num_witness, new_opcodes)
+}
+
 fn create_keccak_constraint(
     input: Vec<Witness>,
     num_witness: u32,
@@ -1,4 +1,5 @@
 mod blake2s;
+mod blake3;
 mod hash_to_field;
 mod keccak256;
 mod logic_fallbacks;
@@ -10,10 +11,11 @@ mod uint64;
 mod uint8;
 mod utils;
 pub use blake2s::blake2s;
+pub use blake3::blake3;
 pub use hash_to_field::hash_to_field;
-pub use keccak256::keccak256;
+pub use keccak256::{keccak256, keccakf1600};
 pub use logic_fallbacks::{and, range, xor};
-pub use sha256::sha256;
+pub use sha256::{sha256, sha256_compression};
 pub use uint32::UInt32;
 pub use uint64::UInt64;
 pub use uint8::UInt8;
@@ -61,6 +61,40 @@ pub fn sha256(
     (num_witness, new_opcodes)
 }
 
+/// Sha256 compression function fallback, applying the compression of a block of 16 words of
+/// 32 bits to a state of 8 words of 32 bits.
+pub fn sha256_compression(
+    inputs: Vec<Witness>,
+    hash_values: Vec<Witness>,
+    outputs: Vec<Witness>,
+    num_witness: u32,
+) -> (u32, Vec<Opcode>) {
+    let mut new_opcodes = Vec::new();
+
+    // The words are treated as 32 bit integers, which is only sound if they fit in 32 bits.
+    for &word in inputs.iter().chain(&hash_values) {
+        new_opcodes.push(Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness: word, num_bits: 32 },
+        }));
+    }
+
+    let block: Vec<UInt32> = inputs.into_iter().map(UInt32::new).collect();
+    let state: Vec<UInt32> = hash_values.into_iter().map(UInt32::new).collect();
+    let (round_constants, extra_opcodes, num_witness) = prepare_round_constants(num_witness);
+    new_opcodes.extend(extra_opcodes);
+    let (new_state, extra_opcodes, num_witness) =
+        sha256_block(&block, state, round_constants, num_witness);
+    new_opcodes.extend(extra_opcodes);
+
+    // constrain the outputs to be the same as the compressed state
+    for (output, word) in outputs.into_iter().zip(new_state) {
+        let mut expr = Expression::from(output);
+        expr.push_addition_term(-FieldElement::one(), word.inner);
+        new_opcodes.push(Opcode::Arithmetic(expr));
+    }
+    (num_witness, new_opcodes)
+}
+
 fn create_sha256_constraint(
     mut input: Vec<Witness>,
     total_num_bytes: u32,
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use acvm::acir::circuit::Circuit;
+use acvm::acir::circuit::{brillig::BrilligBytecode, Circuit};
 use fm::FileId;
 use noirc_abi::{Abi, ContractEvent};
 use noirc_errors::debug_info::DebugInfo;
@@ -67,6 +67,21 @@ pub struct ContractFunction {
     )]
     pub bytecode: Circuit,
 
+    /// The ACIR functions which are called from `bytecode` rather than being inlined into it.
+    #[serde(
+        default,
+        serialize_with = "Circuit::serialize_circuits_base64",
+        deserialize_with = "Circuit::deserialize_circuits_base64"
+    )]
+    pub functions: Vec<Circuit>,
+    /// The unconstrained functions which are called from ACIR.
+    #[serde(
+        default,
+        serialize_with = "BrilligBytecode::serialize_functions_base64",
+        deserialize_with = "BrilligBytecode::deserialize_functions_base64"
+    )]
+    pub unconstrained_functions: Vec<BrilligBytecode>,
+
     pub debug: DebugInfo,
 }
 
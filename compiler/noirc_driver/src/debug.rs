@@ -1,5 +1,6 @@
-use fm::{FileId, FileManager};
-use noirc_errors::debug_info::DebugInfo;
+use fm::FileId;
+use noirc_errors::{debug_info::DebugInfo, Span};
+use noirc_frontend::hir::Context;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, BTreeSet},
@@ -12,11 +13,36 @@ use std::{
 pub struct DebugFile {
     pub source: String,
     pub path: PathBuf,
+    /// The functions defined in the file, so that locations can be attributed to them.
+    #[serde(default)]
+    pub functions: Vec<DebugFunction>,
+}
+
+/// A function along with the span of its body within the file defining it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DebugFunction {
+    pub name: String,
+    pub span: Span,
+}
+
+impl DebugFile {
+    /// Returns the name of the function whose body contains `span`.
+    ///
+    /// Closures are not declared as functions, so they are attributed to the function defining them.
+    pub fn enclosing_function(&self, span: Span) -> Option<&str> {
+        self.functions
+            .iter()
+            .filter(|function| {
+                function.span.start() <= span.start() && span.end() <= function.span.end()
+            })
+            .min_by_key(|function| function.span.end() - function.span.start())
+            .map(|function| function.name.as_str())
+    }
 }
 
 pub(crate) fn filter_relevant_files(
     debug_symbols: &[DebugInfo],
-    file_manager: &FileManager,
+    context: &Context,
 ) -> BTreeMap<FileId, DebugFile> {
     let files_with_debug_symbols: BTreeSet<FileId> = debug_symbols
         .iter()
@@ -28,16 +54,26 @@ pub(crate) fn filter_relevant_files(
         })
         .collect();
 
+    let mut functions: BTreeMap<FileId, Vec<DebugFunction>> = BTreeMap::new();
+    for (location, func_id) in context.function_body_locations() {
+        if files_with_debug_symbols.contains(&location.file) {
+            let name = context.function_name(&func_id).to_string();
+            let function = DebugFunction { name, span: location.span };
+            functions.entry(location.file).or_default().push(function);
+        }
+    }
+
     let mut file_map = BTreeMap::new();
 
     for file_id in files_with_debug_symbols {
-        let file_source = file_manager.fetch_file(file_id).source();
+        let file_source = context.file_manager.fetch_file(file_id).source();
 
         file_map.insert(
             file_id,
             DebugFile {
                 source: file_source.to_string(),
-                path: file_manager.path(file_id).to_path_buf(),
+                path: context.file_manager.path(file_id).to_path_buf(),
+                functions: functions.remove(&file_id).unwrap_or_default(),
             },
         );
     }
@@ -24,7 +24,7 @@ mod debug;
 mod program;
 
 pub use contract::{CompiledContract, ContractFunction, ContractFunctionType};
-pub use debug::DebugFile;
+pub use debug::{DebugFile, DebugFunction};
 pub use program::CompiledProgram;
 
 const STD_CRATE_NAME: &str = "std";
@@ -47,6 +47,12 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub show_brillig: bool,
 
+    /// Interpret the SSA on several seeded inputs after each optimization pass, failing if a pass
+    /// changes the results of the program. Programs which can't be interpreted are skipped with
+    /// a warning
+    #[arg(long, hide = true)]
+    pub check_ssa_passes: bool,
+
     /// Display the ACIR for compiled circuit
     #[arg(long)]
     pub print_acir: bool,
@@ -58,6 +64,11 @@ pub struct CompileOptions {
     /// Suppress warnings
     #[arg(long, conflicts_with = "deny_warnings")]
     pub silence_warnings: bool,
+
+    /// Inline the functions marked `#[fold]` into their callers rather than calling them,
+    /// which is needed by backends which can't prove calls between circuits.
+    #[arg(skip)]
+    pub inline_folded_functions: bool,
 }
 
 /// Helper type used to signify where only warnings are expected in file diagnostics
@@ -190,6 +201,14 @@ pub fn compile_main(
     if options.print_acir {
         println!("Compiled ACIR for main (unoptimized):");
         println!("{}", compiled_program.circuit);
+        for (id, function) in compiled_program.functions.iter().enumerate() {
+            println!("Compiled ACIR for function {id} (unoptimized):");
+            println!("{function}");
+        }
+        for (id, function) in compiled_program.unconstrained_functions.iter().enumerate() {
+            println!("Unconstrained function {id}:");
+            println!("{:?}", function.bytecode);
+        }
     }
 
     Ok((compiled_program, warnings))
@@ -301,13 +320,15 @@ fn compile_contract_inner(
             is_internal: modifiers.is_internal.unwrap_or(false),
             abi: function.abi,
             bytecode: function.circuit,
+            functions: function.functions,
+            unconstrained_functions: function.unconstrained_functions,
             debug: function.debug,
         });
     }
 
     if errors.is_empty() {
         let debug_infos: Vec<_> = functions.iter().map(|function| function.debug.clone()).collect();
-        let file_map = filter_relevant_files(&debug_infos, &context.file_manager);
+        let file_map = filter_relevant_files(&debug_infos, context);
 
         Ok(CompiledContract {
             name: contract.name,
@@ -342,13 +363,23 @@ pub fn compile_no_check(
     cached_program: Option<CompiledProgram>,
     force_compile: bool,
 ) -> Result<CompiledProgram, RuntimeError> {
-    let program = monomorphize(main_function, &context.def_interner);
+    let mut program = monomorphize(main_function, &context.def_interner);
+    if options.inline_folded_functions {
+        for function in &mut program.functions {
+            function.should_fold = false;
+        }
+    }
 
     let hash = fxhash::hash64(&program);
 
     // If user has specified that they want to see intermediate steps printed then we should
     // force compilation even if the program hasn't changed.
-    if !(force_compile || options.print_acir || options.show_brillig || options.show_ssa) {
+    if !(force_compile
+        || options.print_acir
+        || options.show_brillig
+        || options.show_ssa
+        || options.check_ssa_passes)
+    {
         if let Some(cached_program) = cached_program {
             if hash == cached_program.hash {
                 return Ok(cached_program);
@@ -356,14 +387,21 @@ pub fn compile_no_check(
         }
     }
 
-    let (circuit, debug, abi, warnings) =
-        create_circuit(context, program, options.show_ssa, options.show_brillig)?;
+    let (circuit, functions, unconstrained_functions, debug, abi, warnings) = create_circuit(
+        context,
+        program,
+        options.show_ssa,
+        options.show_brillig,
+        options.check_ssa_passes,
+    )?;
 
-    let file_map = filter_relevant_files(&[debug.clone()], &context.file_manager);
+    let file_map = filter_relevant_files(&[debug.clone()], context);
 
     Ok(CompiledProgram {
         hash,
         circuit,
+        functions,
+        unconstrained_functions,
         debug,
         abi,
         file_map,
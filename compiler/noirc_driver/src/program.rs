@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use acvm::acir::circuit::Circuit;
+use acvm::acir::circuit::{brillig::BrilligBytecode, Circuit};
 use fm::FileId;
 
 use noirc_errors::debug_info::DebugInfo;
@@ -23,6 +23,24 @@ pub struct CompiledProgram {
         deserialize_with = "Circuit::deserialize_circuit_base64"
     )]
     pub circuit: Circuit,
+    /// The ACIR functions which are called from `circuit` rather than being inlined into it.
+    ///
+    /// The `id` of each [call][acvm::acir::circuit::Opcode::Call] opcode is an index into this list.
+    #[serde(
+        default,
+        serialize_with = "Circuit::serialize_circuits_base64",
+        deserialize_with = "Circuit::deserialize_circuits_base64"
+    )]
+    pub functions: Vec<Circuit>,
+    /// The unconstrained functions which are called from ACIR.
+    ///
+    /// The `id` of each [Brillig call][acvm::acir::circuit::Opcode::BrilligCall] opcode is an index into this list.
+    #[serde(
+        default,
+        serialize_with = "BrilligBytecode::serialize_functions_base64",
+        deserialize_with = "BrilligBytecode::deserialize_functions_base64"
+    )]
+    pub unconstrained_functions: Vec<BrilligBytecode>,
     pub abi: noirc_abi::Abi,
     pub debug: DebugInfo,
     pub file_map: BTreeMap<FileId, DebugFile>,
@@ -4,6 +4,7 @@ pub(crate) mod brillig_block_variables;
 pub(crate) mod brillig_directive;
 pub(crate) mod brillig_fn;
 pub(crate) mod brillig_slice_ops;
+mod register_allocation;
 mod variable_liveness;
 
 use self::{brillig_block::BrilligBlock, brillig_fn::FunctionContext};
@@ -18,9 +19,65 @@ pub(crate) fn convert_ssa_function(func: &Function, enable_debug_trace: bool) ->
 
     brillig_context.enter_context(FunctionContext::function_id_to_function_label(func.id()));
 
+    if let Some(spill_area_pointer) = function_context.spill_area_pointer {
+        // Reserve the memory where this call keeps its spilled variables
+        brillig_context.allocate_fixed_length_array(
+            spill_area_pointer,
+            function_context.register_allocation.spill_area_size(),
+        );
+    }
+
     for block in function_context.blocks.clone() {
         BrilligBlock::compile(&mut function_context, &mut brillig_context, block, &func.dfg);
     }
 
     brillig_context.artifact()
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use acvm::brillig_vm::{brillig::Value, Registers, VMStatus, VM};
+
+    use crate::brillig::brillig_ir::{tests::DummyBlackBoxSolver, BrilligContext};
+    use crate::ssa::ssa_gen::Ssa;
+
+    use super::{brillig_fn::FunctionContext, convert_ssa_function};
+
+    /// The outcome of running a Brillig function, used to measure the effect of optimizations.
+    pub(crate) struct BrilligExecution {
+        pub(crate) return_values: Vec<Value>,
+        /// The number of opcodes executed.
+        pub(crate) steps: usize,
+        /// The number of registers written to, including the reserved registers.
+        pub(crate) registers_used: usize,
+    }
+
+    /// Compiles the `main` function of the given program into Brillig and runs it on the given inputs.
+    /// `main` must not call any other function.
+    pub(crate) fn execute_main(ssa: &Ssa, inputs: Vec<Value>) -> BrilligExecution {
+        let main = ssa.main();
+        let mut entry_point = BrilligContext::new_entry_point_artifact(
+            FunctionContext::parameters(main),
+            FunctionContext::return_values(main),
+            FunctionContext::function_id_to_function_label(main.id()),
+        );
+        entry_point.link_with(&convert_ssa_function(main, false));
+        let bytecode = entry_point.finish().byte_code;
+
+        let mut vm = VM::new(
+            Registers { inner: inputs },
+            Vec::new(),
+            &bytecode,
+            Vec::new(),
+            &DummyBlackBoxSolver,
+        );
+        assert_eq!(vm.process_opcodes(), VMStatus::Finished);
+
+        let registers = &vm.get_registers().inner;
+        BrilligExecution {
+            return_values: registers[..main.returns().len()].to_vec(),
+            steps: vm.steps(),
+            registers_used: registers.len(),
+        }
+    }
+}
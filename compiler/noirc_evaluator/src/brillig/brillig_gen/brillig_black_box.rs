@@ -1,5 +1,5 @@
 use acvm::acir::{
-    brillig::{BlackBoxOp, HeapVector, RegisterOrMemory},
+    brillig::{BlackBoxOp, HeapVector, RegisterOrMemory, Value},
     BlackBoxFunc,
 };
 
@@ -41,6 +41,19 @@ pub(crate) fn convert_black_box_call(
                 unreachable!("ICE: Blake2s expects one array argument and one array result")
             }
         }
+        BlackBoxFunc::Blake3 => {
+            if let ([message], [RegisterOrMemory::HeapArray(result_array)]) =
+                (function_arguments, function_results)
+            {
+                let message_vector = convert_array_or_vector(brillig_context, message, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::Blake3 {
+                    message: message_vector,
+                    output: *result_array,
+                });
+            } else {
+                unreachable!("ICE: Blake3 expects one array argument and one array result")
+            }
+        }
         BlackBoxFunc::Keccak256 => {
             if let (
                 [message, RegisterOrMemory::RegisterIndex(array_size)],
@@ -160,6 +173,171 @@ pub(crate) fn convert_black_box_call(
                 )
             }
         }
+        BlackBoxFunc::Keccakf1600 => {
+            if let ([message], [RegisterOrMemory::HeapArray(result_array)]) =
+                (function_arguments, function_results)
+            {
+                let state_vector = convert_array_or_vector(brillig_context, message, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::Keccakf1600 {
+                    message: state_vector,
+                    output: *result_array,
+                });
+            } else {
+                unreachable!("ICE: Keccakf1600 expects one array argument and one array result")
+            }
+        }
+        BlackBoxFunc::Sha256Compression => {
+            if let ([message, hash_values], [RegisterOrMemory::HeapArray(result_array)]) =
+                (function_arguments, function_results)
+            {
+                let message_vector = convert_array_or_vector(brillig_context, message, bb_func);
+                let hash_values_vector =
+                    convert_array_or_vector(brillig_context, hash_values, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::Sha256Compression {
+                    input: message_vector,
+                    hash_values: hash_values_vector,
+                    output: *result_array,
+                });
+            } else {
+                unreachable!(
+                    "ICE: Sha256Compression expects two array arguments and one array result"
+                )
+            }
+        }
+        BlackBoxFunc::EmbeddedCurveAdd => {
+            if let (
+                [RegisterOrMemory::RegisterIndex(input1_x), RegisterOrMemory::RegisterIndex(input1_y), RegisterOrMemory::RegisterIndex(input2_x), RegisterOrMemory::RegisterIndex(input2_y)],
+                [RegisterOrMemory::HeapArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                brillig_context.black_box_op_instruction(BlackBoxOp::EmbeddedCurveAdd {
+                    input1_x: *input1_x,
+                    input1_y: *input1_y,
+                    input2_x: *input2_x,
+                    input2_y: *input2_y,
+                    result: *result_array,
+                });
+            } else {
+                unreachable!(
+                    "ICE: EmbeddedCurveAdd expects four register arguments and one array result"
+                )
+            }
+        }
+        BlackBoxFunc::EmbeddedCurveDouble => {
+            if let (
+                [RegisterOrMemory::RegisterIndex(input1_x), RegisterOrMemory::RegisterIndex(input1_y)],
+                [RegisterOrMemory::HeapArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                brillig_context.black_box_op_instruction(BlackBoxOp::EmbeddedCurveDouble {
+                    input1_x: *input1_x,
+                    input1_y: *input1_y,
+                    result: *result_array,
+                });
+            } else {
+                unreachable!(
+                    "ICE: EmbeddedCurveDouble expects two register arguments and one array result"
+                )
+            }
+        }
+        BlackBoxFunc::Poseidon2Permutation => {
+            if let (
+                [message, RegisterOrMemory::RegisterIndex(state_len)],
+                [RegisterOrMemory::HeapArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                let message_vector = convert_array_or_vector(brillig_context, message, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::Poseidon2Permutation {
+                    message: message_vector,
+                    output: *result_array,
+                    len: *state_len,
+                });
+            } else {
+                unreachable!("ICE: Poseidon2Permutation expects one array argument, a register for the state length, and one array result")
+            }
+        }
+        BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv => {
+            if let (
+                [RegisterOrMemory::RegisterIndex(lhs), RegisterOrMemory::RegisterIndex(lhs_modulus), RegisterOrMemory::RegisterIndex(rhs), RegisterOrMemory::RegisterIndex(_rhs_modulus)],
+                [RegisterOrMemory::RegisterIndex(output), RegisterOrMemory::RegisterIndex(output_modulus)],
+            ) = (function_arguments, function_results)
+            {
+                let (lhs, rhs, output) = (*lhs, *rhs, *output);
+                let op = match bb_func {
+                    BlackBoxFunc::BigIntAdd => BlackBoxOp::BigIntAdd { lhs, rhs, output },
+                    BlackBoxFunc::BigIntSub => BlackBoxOp::BigIntSub { lhs, rhs, output },
+                    BlackBoxFunc::BigIntMul => BlackBoxOp::BigIntMul { lhs, rhs, output },
+                    BlackBoxFunc::BigIntDiv => BlackBoxOp::BigIntDiv { lhs, rhs, output },
+                    _ => unreachable!("ICE: {bb_func} is not a big integer operation"),
+                };
+                brillig_context.black_box_op_instruction(op);
+                brillig_context.mov_instruction(*output_modulus, *lhs_modulus);
+            } else {
+                unreachable!(
+                    "ICE: {bb_func} expects two big integers as arguments and one big integer result"
+                )
+            }
+        }
+        BlackBoxFunc::BigIntFromLeBytes => {
+            if let (
+                [inputs, modulus],
+                [RegisterOrMemory::RegisterIndex(output), RegisterOrMemory::RegisterIndex(output_modulus)],
+            ) = (function_arguments, function_results)
+            {
+                let inputs_vector = convert_array_or_vector(brillig_context, inputs, bb_func);
+                let modulus_vector = convert_array_or_vector(brillig_context, modulus, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::BigIntFromLeBytes {
+                    inputs: inputs_vector,
+                    modulus: modulus_vector,
+                    output: *output,
+                });
+                // The Brillig VM keeps track of the modulus of each big integer itself.
+                brillig_context.const_instruction(*output_modulus, Value::from(0_usize));
+            } else {
+                unreachable!(
+                    "ICE: BigIntFromLeBytes expects two array arguments and one big integer result"
+                )
+            }
+        }
+        BlackBoxFunc::BigIntToLeBytes => {
+            if let (
+                [RegisterOrMemory::RegisterIndex(input), RegisterOrMemory::RegisterIndex(_modulus)],
+                [RegisterOrMemory::HeapArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                brillig_context.black_box_op_instruction(BlackBoxOp::BigIntToLeBytes {
+                    input: *input,
+                    output: *result_array,
+                });
+            } else {
+                unreachable!(
+                    "ICE: BigIntToLeBytes expects one big integer argument and one array result"
+                )
+            }
+        }
+        BlackBoxFunc::EcdsaSecp256k1Recover => {
+            if let (
+                [message, RegisterOrMemory::HeapArray(signature), RegisterOrMemory::RegisterIndex(recovery_id)],
+                [RegisterOrMemory::HeapArray(result_array)],
+            ) = (function_arguments, function_results)
+            {
+                let message_hash_vector =
+                    convert_array_or_vector(brillig_context, message, bb_func);
+                brillig_context.black_box_op_instruction(BlackBoxOp::EcdsaSecp256k1Recover {
+                    hashed_msg: message_hash_vector,
+                    signature: *signature,
+                    recovery_id: *recovery_id,
+                    output: *result_array,
+                });
+            } else {
+                unreachable!(
+                    "ICE: EcdsaSecp256k1Recover expects two array arguments, a register for the recovery id and one array result"
+                )
+            }
+        }
         _ => unimplemented!("ICE: Black box function {:?} is not implemented", bb_func),
     }
 }
@@ -1,6 +1,5 @@
 use crate::brillig::brillig_ir::{
     extract_heap_array, extract_register, extract_registers, BrilligBinaryOp, BrilligContext,
-    BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE,
 };
 use crate::ssa::ir::dfg::CallStack;
 use crate::ssa::ir::{
@@ -44,9 +43,15 @@ impl<'block> BrilligBlock<'block> {
         block_id: BasicBlockId,
         dfg: &DataFlowGraph,
     ) {
-        let live_in = function_context.liveness.get_live_in(&block_id);
-        let variables =
-            BlockVariables::new(live_in.clone(), function_context.all_block_parameters());
+        // Spilled variables stay in memory between instructions, so they don't need registers
+        let live_in = function_context
+            .liveness
+            .get_live_in(&block_id)
+            .iter()
+            .filter(|value_id| !function_context.register_allocation.is_spilled(value_id))
+            .copied()
+            .collect();
+        let variables = BlockVariables::new(live_in, function_context.all_block_parameters());
 
         brillig_context.set_allocated_registers(
             variables
@@ -82,6 +87,7 @@ impl<'block> BrilligBlock<'block> {
             block.terminator().expect("block is expected to be constructed");
 
         self.convert_ssa_terminator(terminator_instruction, dfg);
+        self.variables.flush_spilled_variables(self.function_context, self.brillig_context);
     }
 
     fn get_bit_size_from_ssa_type(typ: Type) -> u32 {
@@ -138,6 +144,8 @@ impl<'block> BrilligBlock<'block> {
                 call_stack: _,
             } => {
                 let target_block = &dfg[*destination_block];
+                let mut sources = Vec::new();
+                let mut destinations = Vec::new();
                 for (src, dest) in arguments.iter().zip(target_block.parameters()) {
                     // Destinations are block parameters so they should have been allocated previously.
                     let destination = self.variables.get_block_param(
@@ -147,8 +155,12 @@ impl<'block> BrilligBlock<'block> {
                         dfg,
                     );
                     let source = self.convert_ssa_value(*src, dfg);
-                    self.pass_variable(source, destination);
+                    sources.extend(extract_registers(source));
+                    destinations.extend(extract_registers(destination));
                 }
+                // Arguments are passed all at once, since they might be read from the registers of other parameters.
+                // Arguments coalesced with their parameter are already in place and don't need to be moved.
+                self.brillig_context.mov_registers_to_registers_instruction(sources, destinations);
                 self.brillig_context.jump_instruction(
                     self.create_block_label_for_current_function(*destination_block),
                 );
@@ -166,40 +178,6 @@ impl<'block> BrilligBlock<'block> {
         }
     }
 
-    /// Passes an arbitrary variable from the registers of the source to the registers of the destination
-    fn pass_variable(&mut self, source: RegisterOrMemory, destination: RegisterOrMemory) {
-        match (source, destination) {
-            (
-                RegisterOrMemory::RegisterIndex(source_register),
-                RegisterOrMemory::RegisterIndex(destination_register),
-            ) => {
-                self.brillig_context.mov_instruction(destination_register, source_register);
-            }
-            (
-                RegisterOrMemory::HeapArray(HeapArray { pointer: source_pointer, .. }),
-                RegisterOrMemory::HeapArray(HeapArray { pointer: destination_pointer, .. }),
-            ) => {
-                self.brillig_context.mov_instruction(destination_pointer, source_pointer);
-            }
-            (
-                RegisterOrMemory::HeapVector(HeapVector {
-                    pointer: source_pointer,
-                    size: source_size,
-                }),
-                RegisterOrMemory::HeapVector(HeapVector {
-                    pointer: destination_pointer,
-                    size: destination_size,
-                }),
-            ) => {
-                self.brillig_context.mov_instruction(destination_pointer, source_pointer);
-                self.brillig_context.mov_instruction(destination_size, source_size);
-            }
-            (_, _) => {
-                unreachable!("ICE: Cannot pass value from {:?} to {:?}", source, destination);
-            }
-        }
-    }
-
     /// Converts SSA Block parameters into Brillig Registers.
     fn convert_block_params(&mut self, block: &BasicBlock, dfg: &DataFlowGraph) {
         for param_id in block.parameters() {
@@ -557,13 +535,20 @@ impl<'block> BrilligBlock<'block> {
             _ => todo!("ICE: Instruction not supported {instruction:?}"),
         };
 
+        self.variables.flush_spilled_variables(self.function_context, self.brillig_context);
+
         let dead_variables = self
             .last_uses
             .get(&instruction_id)
             .expect("Last uses for instruction should have been computed");
 
         for dead_variable in dead_variables {
-            self.variables.remove_variable(dead_variable);
+            self.variables.remove_variable(
+                self.function_context,
+                self.brillig_context,
+                dead_variable,
+                dfg,
+            );
         }
         self.brillig_context.set_call_stack(CallStack::new());
     }
@@ -596,7 +581,7 @@ impl<'block> BrilligBlock<'block> {
             .pre_call_save_registers_prep_args(&argument_registers, &variables_to_save);
 
         // We don't save and restore constants, so we dump them before a external call since the callee might use the registers where they are allocated.
-        self.variables.dump_constants();
+        self.variables.dump_constants(self.brillig_context);
 
         // Call instruction, which will interpret above registers 0..num args
         self.brillig_context.add_external_call_instruction(label_of_function_to_call);
@@ -1052,7 +1037,12 @@ impl<'block> BrilligBlock<'block> {
             Value::Param { .. } | Value::Instruction { .. } => {
                 // All block parameters and instruction results should have already been
                 // converted to registers so we fetch from the cache.
-                self.variables.get_allocation(self.function_context, value_id, dfg)
+                self.variables.get_allocation(
+                    self.function_context,
+                    self.brillig_context,
+                    value_id,
+                    dfg,
+                )
             }
             Value::NumericConstant { constant, .. } => {
                 // Constants might have been converted previously or not, so we get or create and
@@ -1271,9 +1261,10 @@ pub(crate) fn convert_ssa_binary_op_to_brillig_binary_op(
             BinaryOp::Mul => BrilligBinaryOp::Field { op: BinaryFieldOp::Mul },
             BinaryOp::Div => BrilligBinaryOp::Field { op: BinaryFieldOp::Div },
             BinaryOp::Eq => BrilligBinaryOp::Field { op: BinaryFieldOp::Equals },
+            // Field elements are always smaller than 2^{max_num_bits}, so they can be compared as integers of that size
             BinaryOp::Lt => BrilligBinaryOp::Integer {
                 op: BinaryIntOp::LessThan,
-                bit_size: BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE,
+                bit_size: FieldElement::max_num_bits(),
             },
             _ => unreachable!(
                 "Field type cannot be used with {op}. This should have been caught by the frontend"
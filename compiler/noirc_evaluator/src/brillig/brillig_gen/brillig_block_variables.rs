@@ -1,13 +1,15 @@
-use acvm::brillig_vm::brillig::{HeapArray, HeapVector, RegisterIndex, RegisterOrMemory};
+use acvm::brillig_vm::brillig::{
+    BinaryIntOp, HeapArray, HeapVector, RegisterIndex, RegisterOrMemory,
+};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-    brillig::brillig_ir::{extract_register, BrilligContext},
+    brillig::brillig_ir::{extract_register, extract_registers, BrilligContext},
     ssa::ir::{
         basic_block::BasicBlockId,
         dfg::DataFlowGraph,
         types::{CompositeType, Type},
-        value::ValueId,
+        value::{Value, ValueId},
     },
 };
 
@@ -17,6 +19,10 @@ use super::brillig_fn::FunctionContext;
 pub(crate) struct BlockVariables {
     available_variables: HashSet<ValueId>,
     available_constants: HashMap<ValueId, RegisterOrMemory>,
+    /// Spilled variables currently held in temporary registers for the instruction being converted.
+    reloaded_spills: HashMap<ValueId, RegisterOrMemory>,
+    /// Spilled variables defined by the instruction being converted, to be written back to memory.
+    pending_spills: Vec<ValueId>,
 }
 
 impl BlockVariables {
@@ -28,7 +34,8 @@ impl BlockVariables {
         }
     }
 
-    /// Returns all non-constant variables that have not been removed at this point.
+    /// Returns all non-constant variables that have not been removed at this point,
+    /// along with the pointer to the spill area of the function if there is one.
     pub(crate) fn get_available_variables(
         &self,
         function_context: &FunctionContext,
@@ -42,6 +49,7 @@ impl BlockVariables {
                     .unwrap_or_else(|| panic!("ICE: Value not found in cache {value_id}"))
             })
             .cloned()
+            .chain(function_context.spill_area_pointer.map(RegisterOrMemory::RegisterIndex))
             .collect()
     }
 
@@ -54,7 +62,19 @@ impl BlockVariables {
         dfg: &DataFlowGraph,
     ) -> RegisterOrMemory {
         let value_id = dfg.resolve(value_id);
-        let variable = allocate_value(value_id, brillig_context, dfg);
+
+        if function_context.register_allocation.is_spilled(&value_id) {
+            // Spilled variables are computed into temporary registers and written to memory after the instruction.
+            let variable = allocate_value(value_id, brillig_context, dfg);
+            self.reloaded_spills.insert(value_id, variable);
+            self.pending_spills.push(value_id);
+            return variable;
+        }
+
+        let variable = match function_context.register_allocation.coalesced_parameter(&value_id) {
+            Some(parameter) => function_context.ssa_value_allocations[&parameter],
+            None => allocate_value(value_id, brillig_context, dfg),
+        };
 
         if function_context.ssa_value_allocations.insert(value_id, variable).is_some() {
             unreachable!("ICE: ValueId {value_id:?} was already in cache");
@@ -78,20 +98,51 @@ impl BlockVariables {
     }
 
     /// Removes a variable so it's not used anymore within this block.
-    pub(crate) fn remove_variable(&mut self, value_id: &ValueId) {
-        self.available_variables.remove(value_id);
+    /// Its registers are freed for reuse, unless they belong to a block parameter,
+    /// since those are allocated for the whole function.
+    pub(crate) fn remove_variable(
+        &mut self,
+        function_context: &FunctionContext,
+        brillig_context: &mut BrilligContext,
+        value_id: &ValueId,
+        dfg: &DataFlowGraph,
+    ) {
+        if !self.available_variables.remove(value_id)
+            || matches!(dfg[*value_id], Value::Param { .. })
+            || function_context.register_allocation.coalesced_parameter(value_id).is_some()
+        {
+            return;
+        }
+
+        let variable = function_context.ssa_value_allocations[value_id];
+        for register in extract_registers(variable) {
+            brillig_context.deallocate_register(register);
+        }
     }
 
     /// For a given SSA value id, return the corresponding cached allocation.
+    /// Spilled variables are loaded from memory into temporary registers the first time they are used by an instruction.
     pub(crate) fn get_allocation(
         &mut self,
         function_context: &FunctionContext,
+        brillig_context: &mut BrilligContext,
         value_id: ValueId,
         dfg: &DataFlowGraph,
     ) -> RegisterOrMemory {
         let value_id = dfg.resolve(value_id);
         if let Some(constant) = self.available_constants.get(&value_id) {
             *constant
+        } else if let Some(variable) = self.reloaded_spills.get(&value_id) {
+            *variable
+        } else if let Some(slot) = function_context.register_allocation.spill_slot(&value_id) {
+            let variable = allocate_value(value_id, brillig_context, dfg);
+            for (offset, register) in extract_registers(variable).into_iter().enumerate() {
+                let address = spill_slot_address(function_context, brillig_context, slot + offset);
+                brillig_context.load_instruction(register, address);
+                brillig_context.deallocate_register(address);
+            }
+            self.reloaded_spills.insert(value_id, variable);
+            variable
         } else {
             assert!(
                 self.available_variables.contains(&value_id),
@@ -130,8 +181,39 @@ impl BlockVariables {
     }
 
     /// Removes the allocations of all constants. Constants will need to be reallocated and reinitialized after this.
-    pub(crate) fn dump_constants(&mut self) {
-        self.available_constants.clear();
+    pub(crate) fn dump_constants(&mut self, brillig_context: &mut BrilligContext) {
+        for (_, constant) in self.available_constants.drain() {
+            for register in extract_registers(constant) {
+                brillig_context.deallocate_register(register);
+            }
+        }
+    }
+
+    /// Writes the spilled variables defined by the last converted instruction back to memory
+    /// and frees the temporary registers of every spilled variable that was loaded for it.
+    pub(crate) fn flush_spilled_variables(
+        &mut self,
+        function_context: &FunctionContext,
+        brillig_context: &mut BrilligContext,
+    ) {
+        for value_id in std::mem::take(&mut self.pending_spills) {
+            let slot = function_context
+                .register_allocation
+                .spill_slot(&value_id)
+                .expect("ICE: Pending spill should have a slot");
+            let variable = self.reloaded_spills[&value_id];
+            for (offset, register) in extract_registers(variable).into_iter().enumerate() {
+                let address = spill_slot_address(function_context, brillig_context, slot + offset);
+                brillig_context.store_instruction(address, register);
+                brillig_context.deallocate_register(address);
+            }
+        }
+
+        for (_, variable) in self.reloaded_spills.drain() {
+            for register in extract_registers(variable) {
+                brillig_context.deallocate_register(register);
+            }
+        }
     }
 
     /// For a given block parameter, return the allocation that was done globally to the function.
@@ -156,6 +238,19 @@ impl BlockVariables {
     }
 }
 
+/// Allocates a register holding the address of a cell of the spill area of the function.
+fn spill_slot_address(
+    function_context: &FunctionContext,
+    brillig_context: &mut BrilligContext,
+    slot: usize,
+) -> RegisterIndex {
+    let spill_area_pointer =
+        function_context.spill_area_pointer.expect("ICE: Spilled variables need a spill area");
+    let address = brillig_context.allocate_register();
+    brillig_context.usize_op(spill_area_pointer, address, BinaryIntOp::Add, slot);
+    address
+}
+
 /// Computes the length of an array. This will match with the indexes that SSA will issue
 pub(crate) fn compute_array_length(item_typ: &CompositeType, elem_count: usize) -> usize {
     item_typ.len() * elem_count
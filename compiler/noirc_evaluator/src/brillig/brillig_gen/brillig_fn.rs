@@ -1,4 +1,4 @@
-use acvm::brillig_vm::brillig::RegisterOrMemory;
+use acvm::brillig_vm::brillig::{RegisterIndex, RegisterOrMemory};
 use iter_extended::vecmap;
 
 use crate::{
@@ -16,7 +16,11 @@ use crate::{
 };
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use super::{brillig_block_variables::allocate_value, variable_liveness::VariableLiveness};
+use super::{
+    brillig_block_variables::allocate_value,
+    register_allocation::{RegisterAllocation, MAX_VARIABLE_REGISTERS},
+    variable_liveness::VariableLiveness,
+};
 
 pub(crate) struct FunctionContext {
     pub(crate) function_id: FunctionId,
@@ -28,10 +32,15 @@ pub(crate) struct FunctionContext {
     pub(crate) blocks: Vec<BasicBlockId>,
     /// Liveness information for each variable in the function.
     pub(crate) liveness: VariableLiveness,
+    /// The variables that share registers with block parameters or are spilled to memory.
+    pub(crate) register_allocation: RegisterAllocation,
+    /// The register pointing to the memory where spilled variables are kept, if any variable is spilled.
+    pub(crate) spill_area_pointer: Option<RegisterIndex>,
 }
 
 impl FunctionContext {
-    /// Creates a new function context. It will allocate parameters for all blocks and compute the liveness of every variable.
+    /// Creates a new function context. It will allocate parameters for all blocks, compute the liveness of every variable
+    /// and decide which variables are coalesced with block parameters or spilled to memory.
     pub(crate) fn new(function: &Function, brillig_context: &mut BrilligContext) -> Self {
         let id = function.id();
 
@@ -52,12 +61,25 @@ impl FunctionContext {
             block_parameters.insert(block_id, parameters);
         }
 
+        let liveness = VariableLiveness::from_function(function);
+        let register_allocation = RegisterAllocation::from_function(
+            function,
+            &reverse_post_order,
+            &liveness,
+            MAX_VARIABLE_REGISTERS,
+        );
+        // Like block parameters, the spill area pointer is allocated at the function level.
+        let spill_area_pointer = (register_allocation.spill_area_size() > 0)
+            .then(|| brillig_context.allocate_register());
+
         Self {
             function_id: id,
             ssa_value_allocations: ssa_variable_to_register_or_memory,
             block_parameters,
             blocks: reverse_post_order,
-            liveness: VariableLiveness::from_function(function),
+            liveness,
+            register_allocation,
+            spill_area_pointer,
         }
     }
 
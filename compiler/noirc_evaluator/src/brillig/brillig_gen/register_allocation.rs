@@ -0,0 +1,353 @@
+//! This module decides, before any bytecode is generated, where the variables of a function are kept.
+//!
+//! Variables that are only defined to be passed to a block parameter are coalesced with that parameter,
+//! so they are computed directly into the parameter's registers and the jump doesn't need to move them.
+//!
+//! The registers that the live variables of a function occupy are tracked with a linear scan over the blocks
+//! in reverse post order. Whenever they exceed a budget, the live variable with the fewest uses is spilled:
+//! it lives in a memory area reserved by the function on entry and is only loaded into temporary registers
+//! for the instructions that use it.
+use crate::ssa::ir::{
+    basic_block::BasicBlockId,
+    function::Function,
+    instruction::TerminatorInstruction,
+    types::Type,
+    value::{Value, ValueId},
+};
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::variable_liveness::{
+    collect_variables_of_value, variables_used_in_instruction, VariableLiveness,
+};
+
+/// The maximum number of registers that the variables of a function can occupy at the same time.
+/// It is kept well below the 2^16 registers of the Brillig VM, leaving room for the temporary registers
+/// used while generating the code of an instruction.
+pub(crate) const MAX_VARIABLE_REGISTERS: usize = 1 << 14;
+
+#[derive(Debug, Default)]
+pub(crate) struct RegisterAllocation {
+    /// Maps values to the block parameter whose registers they share.
+    coalesced: HashMap<ValueId, ValueId>,
+    /// Maps spilled values to their offset in the spill area of the function.
+    spill_slots: HashMap<ValueId, usize>,
+    /// The number of memory cells that the spill area of the function needs.
+    spill_area_size: usize,
+}
+
+impl RegisterAllocation {
+    /// Computes the coalesced and spilled variables of a function, given its blocks in reverse post order.
+    pub(crate) fn from_function(
+        func: &Function,
+        blocks: &[BasicBlockId],
+        liveness: &VariableLiveness,
+        register_budget: usize,
+    ) -> Self {
+        let mut allocation = RegisterAllocation {
+            coalesced: compute_coalesced_values(func, blocks, liveness),
+            ..Default::default()
+        };
+        allocation.compute_spills(func, blocks, liveness, register_budget);
+        allocation
+    }
+
+    /// Returns the block parameter that the given value shares its registers with, if any.
+    pub(crate) fn coalesced_parameter(&self, value_id: &ValueId) -> Option<ValueId> {
+        self.coalesced.get(value_id).copied()
+    }
+
+    /// Returns the offset in the spill area of the given value, if it has been spilled.
+    pub(crate) fn spill_slot(&self, value_id: &ValueId) -> Option<usize> {
+        self.spill_slots.get(value_id).copied()
+    }
+
+    pub(crate) fn is_spilled(&self, value_id: &ValueId) -> bool {
+        self.spill_slots.contains_key(value_id)
+    }
+
+    /// The number of memory cells that the function needs to reserve for its spilled variables.
+    pub(crate) fn spill_area_size(&self) -> usize {
+        self.spill_area_size
+    }
+
+    /// Walks the blocks in reverse post order keeping track of the registers held by the live variables,
+    /// spilling variables whenever the registers in use exceed `register_budget`.
+    fn compute_spills(
+        &mut self,
+        func: &Function,
+        blocks: &[BasicBlockId],
+        liveness: &VariableLiveness,
+        register_budget: usize,
+    ) {
+        let dfg = &func.dfg;
+        let use_counts = count_uses(func, blocks);
+
+        // Block parameters are allocated for the whole function, so their registers are always in use.
+        let parameter_registers: usize = blocks
+            .iter()
+            .flat_map(|block_id| dfg[*block_id].parameters())
+            .map(|parameter| registers_needed(&dfg.type_of_value(*parameter)))
+            .sum();
+
+        for block_id in blocks {
+            let mut live_variables: HashSet<ValueId> = liveness
+                .get_live_in(block_id)
+                .iter()
+                .filter(|value_id| self.holds_own_registers(value_id, func))
+                .copied()
+                .collect();
+            let mut registers_in_use = parameter_registers
+                + live_variables
+                    .iter()
+                    .map(|value_id| registers_needed(&dfg.type_of_value(*value_id)))
+                    .sum::<usize>();
+
+            let last_uses = liveness.get_last_uses(block_id);
+            for instruction_id in dfg[*block_id].instructions() {
+                for result in dfg.instruction_results(*instruction_id) {
+                    let result = dfg.resolve(*result);
+                    if self.holds_own_registers(&result, func) && live_variables.insert(result) {
+                        registers_in_use += registers_needed(&dfg.type_of_value(result));
+                    }
+                }
+
+                while registers_in_use > register_budget {
+                    let Some(spilled) = live_variables
+                        .iter()
+                        .min_by_key(|value_id| {
+                            (use_counts.get(*value_id).copied().unwrap_or_default(), **value_id)
+                        })
+                        .copied()
+                    else {
+                        break;
+                    };
+                    live_variables.remove(&spilled);
+
+                    let size = registers_needed(&dfg.type_of_value(spilled));
+                    registers_in_use -= size;
+                    self.spill_slots.insert(spilled, self.spill_area_size);
+                    self.spill_area_size += size;
+                }
+
+                let dead_variables =
+                    last_uses.get(instruction_id).expect("Last uses should have been computed");
+                for dead_variable in dead_variables {
+                    if live_variables.remove(dead_variable) {
+                        registers_in_use -= registers_needed(&dfg.type_of_value(*dead_variable));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether the value is a variable with registers of its own, which makes it a candidate for spilling.
+    fn holds_own_registers(&self, value_id: &ValueId, func: &Function) -> bool {
+        !matches!(func.dfg[*value_id], Value::Param { .. })
+            && !self.coalesced.contains_key(value_id)
+            && !self.spill_slots.contains_key(value_id)
+    }
+}
+
+/// A value defined in a block can share the registers of the block parameter it is passed to when
+/// the parameter is not alive in that block and the value is not used after the jump.
+fn compute_coalesced_values(
+    func: &Function,
+    blocks: &[BasicBlockId],
+    liveness: &VariableLiveness,
+) -> HashMap<ValueId, ValueId> {
+    let dfg = &func.dfg;
+    let mut coalesced = HashMap::default();
+
+    for block_id in blocks {
+        let block = &dfg[*block_id];
+        let Some(TerminatorInstruction::Jmp { destination, arguments, .. }) = block.terminator()
+        else {
+            continue;
+        };
+        if destination == block_id {
+            continue;
+        }
+
+        let live_in = liveness.get_live_in(block_id);
+        let destination_live_in = liveness.get_live_in(destination);
+        let defined_in_block: HashSet<ValueId> = block
+            .instructions()
+            .iter()
+            .flat_map(|instruction_id| dfg.instruction_results(*instruction_id))
+            .map(|result| dfg.resolve(*result))
+            .collect();
+
+        for (argument, parameter) in arguments.iter().zip(dfg[*destination].parameters()) {
+            let argument = dfg.resolve(*argument);
+            if defined_in_block.contains(&argument)
+                && !destination_live_in.contains(&argument)
+                && !live_in.contains(parameter)
+                && !coalesced.contains_key(&argument)
+            {
+                coalesced.insert(argument, *parameter);
+            }
+        }
+    }
+
+    coalesced
+}
+
+/// Counts how many times each variable is used by the instructions and terminators of the function.
+fn count_uses(func: &Function, blocks: &[BasicBlockId]) -> HashMap<ValueId, usize> {
+    let dfg = &func.dfg;
+    let mut use_counts: HashMap<ValueId, usize> = HashMap::default();
+
+    for block_id in blocks {
+        let block = &dfg[*block_id];
+        let mut used = Vec::new();
+        for instruction_id in block.instructions() {
+            used.extend(variables_used_in_instruction(&dfg[*instruction_id], dfg));
+        }
+        if let Some(terminator) = block.terminator() {
+            terminator.for_each_value(|value_id| {
+                used.extend(collect_variables_of_value(value_id, dfg));
+            });
+        }
+        for value_id in used {
+            *use_counts.entry(value_id).or_default() += 1;
+        }
+    }
+
+    use_counts
+}
+
+/// The number of registers that a variable of the given type is allocated.
+fn registers_needed(typ: &Type) -> usize {
+    match typ {
+        Type::Numeric(_) | Type::Reference | Type::Array(..) => 1,
+        Type::Slice(_) => 2,
+        Type::Function => {
+            unreachable!("ICE: Function values should have been removed from the SSA")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::brillig_vm::brillig::Value;
+
+    use crate::brillig::brillig_gen::tests::execute_main;
+    use crate::brillig::brillig_gen::variable_liveness::VariableLiveness;
+    use crate::brillig::brillig_ir::ReservedRegisters;
+    use crate::ssa::function_builder::FunctionBuilder;
+    use crate::ssa::ir::function::RuntimeType;
+    use crate::ssa::ir::instruction::BinaryOp;
+    use crate::ssa::ir::map::Id;
+    use crate::ssa::ir::post_order::PostOrder;
+    use crate::ssa::ir::types::Type;
+
+    use super::RegisterAllocation;
+
+    #[test]
+    fn coalesces_jump_arguments_with_parameters() {
+        // brillig fn main f0 {
+        //   b0(v0: Field):
+        //     v2 = add v0, Field 1
+        //     jmp b1(v2)
+        //   b1(v3: Field):
+        //     return v3
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+        let b1 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::field());
+        let one = builder.field_constant(1u128);
+        let v2 = builder.insert_binary(v0, BinaryOp::Add, one);
+        builder.terminate_with_jmp(b1, vec![v2]);
+
+        builder.switch_to_block(b1);
+        let v3 = builder.add_block_parameter(b1, Type::field());
+        builder.terminate_with_return(vec![v3]);
+
+        let ssa = builder.finish();
+        let func = ssa.main();
+        let mut blocks = PostOrder::with_function(func).as_slice().to_vec();
+        blocks.reverse();
+        let liveness = VariableLiveness::from_function(func);
+
+        let allocation = RegisterAllocation::from_function(func, &blocks, &liveness, usize::MAX);
+        assert_eq!(allocation.coalesced_parameter(&v2), Some(v3));
+        assert_eq!(allocation.coalesced_parameter(&v0), None);
+        assert_eq!(allocation.spill_area_size(), 0);
+    }
+
+    #[test]
+    fn spills_least_used_variables_over_budget() {
+        // brillig fn main f0 {
+        //   b0(v0: Field):
+        //     v1 = add v0, Field 1
+        //     v2 = add v0, Field 2
+        //     v3 = add v1, v1
+        //     v4 = add v3, v2
+        //     return v4
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+
+        let v0 = builder.add_parameter(Type::field());
+        let one = builder.field_constant(1u128);
+        let two = builder.field_constant(2u128);
+        let v1 = builder.insert_binary(v0, BinaryOp::Add, one);
+        let v2 = builder.insert_binary(v0, BinaryOp::Add, two);
+        let v3 = builder.insert_binary(v1, BinaryOp::Add, v1);
+        let v4 = builder.insert_binary(v3, BinaryOp::Add, v2);
+        builder.terminate_with_return(vec![v4]);
+
+        let ssa = builder.finish();
+        let func = ssa.main();
+        let blocks = vec![func.entry_block()];
+        let liveness = VariableLiveness::from_function(func);
+
+        // At most four registers are in use at the same time: v0, v1, v2 and v3.
+        let allocation = RegisterAllocation::from_function(func, &blocks, &liveness, 4);
+        assert_eq!(allocation.spill_area_size(), 0);
+
+        // With one register less, the variable with the fewest uses that is alive at that point is spilled.
+        let allocation = RegisterAllocation::from_function(func, &blocks, &liveness, 3);
+        assert!(allocation.is_spilled(&v2));
+        assert!(!allocation.is_spilled(&v0));
+        assert!(!allocation.is_spilled(&v1));
+        assert_eq!(allocation.spill_slot(&v2), Some(0));
+        assert_eq!(allocation.spill_area_size(), 1);
+    }
+
+    #[test]
+    fn reuses_registers_of_dead_variables() {
+        // brillig fn main f0 {
+        //   b0(v0: Field):
+        //     v2 = add v0, Field 1
+        //     v3 = add v2, Field 1
+        //     ...
+        //     v33 = add v32, Field 1
+        //     return v33
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+
+        let mut value = builder.add_parameter(Type::field());
+        let one = builder.field_constant(1u128);
+        for _ in 0..32 {
+            value = builder.insert_binary(value, BinaryOp::Add, one);
+        }
+        builder.terminate_with_return(vec![value]);
+
+        let ssa = builder.finish();
+        let execution = execute_main(&ssa, vec![Value::from(0_usize)]);
+        assert_eq!(execution.return_values, vec![Value::from(32_usize)]);
+
+        // Each variable is dead once the next one is computed, so a handful of registers are reused
+        // rather than one being used for each of the 33 variables.
+        assert!(
+            execution.registers_used < ReservedRegisters::len() + 8,
+            "expected fewer than {} registers to be used, but {} were",
+            ReservedRegisters::len() + 8,
+            execution.registers_used
+        );
+    }
+}
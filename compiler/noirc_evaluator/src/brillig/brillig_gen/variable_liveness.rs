@@ -42,7 +42,7 @@ fn find_back_edges(
 }
 
 /// Collects the underlying variables inside a value id. It might be more than one, for example in constant arrays that are constructed with multiple vars.
-fn collect_variables_of_value(value_id: ValueId, dfg: &DataFlowGraph) -> Vec<ValueId> {
+pub(crate) fn collect_variables_of_value(value_id: ValueId, dfg: &DataFlowGraph) -> Vec<ValueId> {
     let value_id = dfg.resolve(value_id);
     let value = &dfg[value_id];
 
@@ -72,7 +72,10 @@ fn collect_variables_of_value(value_id: ValueId, dfg: &DataFlowGraph) -> Vec<Val
     }
 }
 
-fn variables_used_in_instruction(instruction: &Instruction, dfg: &DataFlowGraph) -> Vec<ValueId> {
+pub(crate) fn variables_used_in_instruction(
+    instruction: &Instruction,
+    dfg: &DataFlowGraph,
+) -> Vec<ValueId> {
     let mut used = Vec::new();
 
     instruction.for_each_value(|value_id| {
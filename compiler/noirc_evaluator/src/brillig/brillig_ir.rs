@@ -25,8 +25,8 @@ use acvm::{
 };
 use debug_show::DebugShow;
 
-/// Integer arithmetic in Brillig is limited to 127 bit
-/// integers.
+/// Integer arithmetic in Brillig is limited to 128 bit
+/// integers, the widest integers supported in ACIR.
 ///
 /// We could lift this in the future and have Brillig
 /// do big integer arithmetic when it exceeds the field size
@@ -35,7 +35,7 @@ use debug_show::DebugShow;
 /// Since constrained functions do not have this property, it
 /// would mean that unconstrained functions will differ from
 /// constrained functions in terms of syntax compatibility.
-pub(crate) const BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE: u32 = 127;
+pub(crate) const BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE: u32 = 128;
 /// The Brillig VM does not apply a limit to the memory address space,
 /// As a convention, we take use 64 bits. This means that we assume that
 /// memory has 2^64 memory slots.
@@ -408,30 +408,57 @@ impl BrilligContext {
     }
 
     /// This function moves values from a set of registers to another set of registers.
-    /// It first moves all sources to new allocated registers to avoid overwriting.
+    /// The moves behave as if they all happened at the same time: moves whose source and
+    /// destination are the same register are dropped, and a temporary register is only
+    /// allocated when a cycle of moves (e.g. a swap) has to be broken.
     pub(crate) fn mov_registers_to_registers_instruction(
         &mut self,
         sources: Vec<RegisterIndex>,
         destinations: Vec<RegisterIndex>,
     ) {
-        let new_sources: Vec<_> = sources
-            .iter()
-            .map(|source| {
-                let new_source = self.allocate_register();
-                self.mov_instruction(new_source, *source);
-                new_source
-            })
+        let mut pending_moves: Vec<(RegisterIndex, RegisterIndex)> = sources
+            .into_iter()
+            .zip(destinations)
+            .filter(|(source, destination)| source != destination)
             .collect();
-        for (new_source, destination) in new_sources.iter().zip(destinations.iter()) {
-            self.mov_instruction(*destination, *new_source);
-            self.deallocate_register(*new_source);
+        let mut temporary_registers = Vec::new();
+
+        while !pending_moves.is_empty() {
+            // A move can be emitted once no other pending move still needs to read its destination.
+            let ready_move = pending_moves.iter().position(|(_, destination)| {
+                !pending_moves.iter().any(|(source, _)| source == destination)
+            });
+
+            if let Some(index) = ready_move {
+                let (source, destination) = pending_moves.remove(index);
+                self.mov_instruction(destination, source);
+            } else {
+                // Every destination is still read by another move, so the remaining moves form cycles.
+                // Save the value of one of the destinations and read it from the copy instead.
+                let (_, destination) = pending_moves[0];
+                let temporary_register = self.allocate_register();
+                self.mov_instruction(temporary_register, destination);
+                for (source, _) in pending_moves.iter_mut() {
+                    if *source == destination {
+                        *source = temporary_register;
+                    }
+                }
+                temporary_registers.push(temporary_register);
+            }
+        }
+
+        for temporary_register in temporary_registers {
+            self.deallocate_register(temporary_register);
         }
     }
 
     /// Emits a `mov` instruction.
     ///
-    /// Copies the value at `source` into `destination`
+    /// Copies the value at `source` into `destination`. Moving a register onto itself emits nothing.
     pub(crate) fn mov_instruction(&mut self, destination: RegisterIndex, source: RegisterIndex) {
+        if destination == source {
+            return;
+        }
         self.debug_show.mov_instruction(destination, source);
         self.push_opcode(BrilligOpcode::Mov { destination, source });
     }
@@ -681,8 +708,8 @@ impl BrilligContext {
 
     /// Emits a modulo instruction against 2**target_bit_size
     ///
-    /// Integer arithmetic in Brillig is currently constrained to 127 bit integers.
-    /// We restrict the cast operation, so that integer types over 127 bits
+    /// Integer arithmetic in Brillig is currently constrained to 128 bit integers.
+    /// We restrict the cast operation, so that integer types over 128 bits
     /// cannot be created.
     pub(crate) fn cast_instruction(
         &mut self,
@@ -1053,6 +1080,22 @@ pub(crate) mod tests {
         ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
             Ok((4_u128.into(), 5_u128.into()))
         }
+        fn ec_add(
+            &self,
+            _input1_x: &FieldElement,
+            _input1_y: &FieldElement,
+            _input2_x: &FieldElement,
+            _input2_y: &FieldElement,
+        ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+            Ok((5_u128.into(), 6_u128.into()))
+        }
+        fn ec_double(
+            &self,
+            _input1_x: &FieldElement,
+            _input1_y: &FieldElement,
+        ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+            Ok((7_u128.into(), 8_u128.into()))
+        }
     }
 
     pub(crate) fn create_context() -> BrilligContext {
@@ -1144,4 +1187,37 @@ pub(crate) mod tests {
         let status = vm.process_opcodes();
         assert_eq!(status, VMStatus::Finished);
     }
+
+    /// Test that register to register moves behave as a parallel move
+    #[test]
+    fn test_brillig_ir_parallel_moves() {
+        let mut context = BrilligContext::new(true);
+        let r_a = context.allocate_register();
+        let r_b = context.allocate_register();
+        let r_c = context.allocate_register();
+        let r_d = context.allocate_register();
+        context.const_instruction(r_a, Value::from(1_usize));
+        context.const_instruction(r_b, Value::from(2_usize));
+        context.const_instruction(r_c, Value::from(3_usize));
+
+        // Rotate the values of a, b and c while also copying a into d.
+        context.mov_registers_to_registers_instruction(
+            vec![r_a, r_b, r_c, r_a],
+            vec![r_b, r_c, r_a, r_d],
+        );
+        context.stop_instruction();
+
+        let bytecode = context.artifact().finish().byte_code;
+        let moves =
+            bytecode.iter().filter(|opcode| matches!(opcode, BrilligOpcode::Mov { .. })).count();
+        // Four moves plus a single one to break the rotation cycle.
+        assert_eq!(moves, 5);
+
+        let vm = create_and_run_vm(vec![], vec![], &bytecode);
+        let registers = vm.get_registers();
+        assert_eq!(registers.get(r_a), Value::from(3_usize));
+        assert_eq!(registers.get(r_b), Value::from(1_usize));
+        assert_eq!(registers.get(r_c), Value::from(2_usize));
+        assert_eq!(registers.get(r_d), Value::from(1_usize));
+    }
 }
@@ -353,6 +353,9 @@ impl DebugShow {
             BlackBoxOp::Blake2s { message, output } => {
                 debug_println!(self.enable_debug_trace, "  BLAKE2S {} -> {}", message, output);
             }
+            BlackBoxOp::Blake3 { message, output } => {
+                debug_println!(self.enable_debug_trace, "  BLAKE3 {} -> {}", message, output);
+            }
             BlackBoxOp::HashToField128Security { message, output } => {
                 debug_println!(
                     self.enable_debug_trace,
@@ -439,6 +442,110 @@ impl DebugShow {
                     result
                 );
             }
+            BlackBoxOp::Keccakf1600 { message, output } => {
+                debug_println!(self.enable_debug_trace, "  KECCAKF1600 {} -> {}", message, output);
+            }
+            BlackBoxOp::Sha256Compression { input, hash_values, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  SHA256_COMPRESSION {} {} -> {}",
+                    input,
+                    hash_values,
+                    output
+                );
+            }
+            BlackBoxOp::EmbeddedCurveAdd { input1_x, input1_y, input2_x, input2_y, result } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  EMBEDDED_CURVE_ADD ({} {}) ({} {}) -> {}",
+                    input1_x,
+                    input1_y,
+                    input2_x,
+                    input2_y,
+                    result
+                );
+            }
+            BlackBoxOp::EmbeddedCurveDouble { input1_x, input1_y, result } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  EMBEDDED_CURVE_DOUBLE ({} {}) -> {}",
+                    input1_x,
+                    input1_y,
+                    result
+                );
+            }
+            BlackBoxOp::Poseidon2Permutation { message, output, len } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  POSEIDON2_PERMUTATION {} {} -> {}",
+                    message,
+                    len,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntAdd { lhs, rhs, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_ADD {} {} -> {}",
+                    lhs,
+                    rhs,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntSub { lhs, rhs, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_SUB {} {} -> {}",
+                    lhs,
+                    rhs,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntMul { lhs, rhs, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_MUL {} {} -> {}",
+                    lhs,
+                    rhs,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntDiv { lhs, rhs, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_DIV {} {} -> {}",
+                    lhs,
+                    rhs,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntFromLeBytes { inputs, modulus, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_FROM_LE_BYTES {} {} -> {}",
+                    inputs,
+                    modulus,
+                    output
+                );
+            }
+            BlackBoxOp::BigIntToLeBytes { input, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  BIGINT_TO_LE_BYTES {} -> {}",
+                    input,
+                    output
+                );
+            }
+            BlackBoxOp::EcdsaSecp256k1Recover { hashed_msg, signature, recovery_id, output } => {
+                debug_println!(
+                    self.enable_debug_trace,
+                    "  ECDSA_SECP256K1_RECOVER {} {} {} -> {}",
+                    hashed_msg,
+                    signature,
+                    recovery_id,
+                    output
+                );
+            }
         }
     }
 
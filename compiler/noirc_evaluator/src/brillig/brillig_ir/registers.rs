@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use acvm::acir::brillig::RegisterIndex;
 
 use super::ReservedRegisters;
@@ -8,7 +10,9 @@ use super::ReservedRegisters;
 /// Each has a stack base pointer from which all stack allocations can be offset.
 pub(crate) struct BrilligRegistersContext {
     /// A free-list of registers that have been deallocated and can be used again.
-    deallocated_registers: Vec<RegisterIndex>,
+    /// It is kept ordered so that the lowest free register is always reused first,
+    /// which keeps the register space of the function as compact as possible.
+    deallocated_registers: BTreeSet<RegisterIndex>,
     /// A usize indicating the next un-used register.
     next_free_register_index: usize,
 }
@@ -17,7 +21,7 @@ impl BrilligRegistersContext {
     /// Initial register allocation
     pub(crate) fn new() -> Self {
         Self {
-            deallocated_registers: Vec::new(),
+            deallocated_registers: BTreeSet::new(),
             next_free_register_index: ReservedRegisters::len(),
         }
     }
@@ -34,10 +38,10 @@ impl BrilligRegistersContext {
                 }
             },
         );
-        let mut deallocated_registers = Vec::new();
+        let mut deallocated_registers = BTreeSet::new();
         for i in ReservedRegisters::len()..next_free_register_index {
             if !preallocated_registers.contains(&RegisterIndex::from(i)) {
-                deallocated_registers.push(RegisterIndex::from(i));
+                deallocated_registers.insert(RegisterIndex::from(i));
             }
         }
 
@@ -49,7 +53,7 @@ impl BrilligRegistersContext {
         let index = register.to_usize();
         if index < self.next_free_register_index {
             // If it could be allocated, check if it's in the deallocated list and remove it from there
-            self.deallocated_registers.retain(|&r| r != register);
+            self.deallocated_registers.remove(&register);
         } else {
             // If it couldn't yet be, expand the register space.
             self.next_free_register_index = index + 1;
@@ -59,8 +63,8 @@ impl BrilligRegistersContext {
     /// Creates a new register.
     pub(crate) fn allocate_register(&mut self) -> RegisterIndex {
         // If we have a register in our free list of deallocated registers,
-        // consume it first. This prioritizes reuse.
-        if let Some(register) = self.deallocated_registers.pop() {
+        // consume the lowest one first. This prioritizes reuse.
+        if let Some(register) = self.deallocated_registers.pop_first() {
             return register;
         }
         // Otherwise, move to our latest register.
@@ -70,10 +74,41 @@ impl BrilligRegistersContext {
     }
 
     /// Push a register to the deallocation list, ready for reuse.
-    /// TODO(AD): currently, register deallocation is only done with immediate values.
-    /// TODO(AD): See https://github.com/noir-lang/noir/issues/1720
     pub(crate) fn deallocate_register(&mut self, register_index: RegisterIndex) {
-        assert!(!self.deallocated_registers.contains(&register_index));
-        self.deallocated_registers.push(register_index);
+        assert!(self.deallocated_registers.insert(register_index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::acir::brillig::RegisterIndex;
+
+    use super::BrilligRegistersContext;
+    use crate::brillig::brillig_ir::ReservedRegisters;
+
+    #[test]
+    fn reuses_lowest_deallocated_register_first() {
+        let first_user_register = ReservedRegisters::len();
+        let mut registers = BrilligRegistersContext::new();
+
+        let allocated: Vec<_> = (0..4).map(|_| registers.allocate_register()).collect();
+        registers.deallocate_register(allocated[3]);
+        registers.deallocate_register(allocated[1]);
+
+        assert_eq!(registers.allocate_register(), RegisterIndex::from(first_user_register + 1));
+        assert_eq!(registers.allocate_register(), RegisterIndex::from(first_user_register + 3));
+        assert_eq!(registers.allocate_register(), RegisterIndex::from(first_user_register + 4));
+    }
+
+    #[test]
+    fn preallocated_registers_leave_gaps_free() {
+        let first_user_register = ReservedRegisters::len();
+        let mut registers = BrilligRegistersContext::from_preallocated_registers(vec![
+            RegisterIndex::from(first_user_register + 2),
+            RegisterIndex::from(first_user_register),
+        ]);
+
+        assert_eq!(registers.allocate_register(), RegisterIndex::from(first_user_register + 1));
+        assert_eq!(registers.allocate_register(), RegisterIndex::from(first_user_register + 3));
     }
 }
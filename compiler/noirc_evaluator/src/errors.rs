@@ -42,6 +42,20 @@ pub enum RuntimeError {
     UnknownLoopBound { call_stack: CallStack },
     #[error("Argument is not constant")]
     AssertConstantFailed { call_stack: CallStack },
+    #[error("Arguments of a call to a comptime function must be known at compile-time")]
+    ComptimeArgumentNotConstant { call_stack: CallStack },
+    #[error("Could not evaluate call to a comptime function in a branch depending on a runtime condition: {reason}")]
+    ComptimeCallInBranch { reason: String, call_stack: CallStack },
+    #[error("Comptime functions cannot be called from within a loop of an unconstrained function")]
+    ComptimeCallInLoop { call_stack: CallStack },
+    #[error("Could not evaluate call to a comptime function: {reason}")]
+    ComptimeEvaluationFailed { reason: String, call_stack: CallStack },
+    #[error("Big integers with different moduli cannot be combined")]
+    BigIntModulus { call_stack: CallStack },
+    #[error("{name} must be known at compile-time and cannot depend on a condition known only at runtime")]
+    BigIntNotConstant { name: String, call_stack: CallStack },
+    #[error("{name} is only available in unconstrained functions")]
+    UnconstrainedOnly { name: String, call_stack: CallStack },
 }
 
 // We avoid showing the actual lhs and rhs since most of the time they are just 0
@@ -63,16 +77,19 @@ impl From<SsaReport> for FileDiagnostic {
     fn from(error: SsaReport) -> FileDiagnostic {
         match error {
             SsaReport::Warning(warning) => {
-                let InternalWarning::ReturnConstant { ref call_stack } = warning;
+                let (call_stack, secondary_message) = match &warning {
+                    InternalWarning::ReturnConstant { call_stack } => {
+                        (call_stack, "constant value".to_string())
+                    }
+                    InternalWarning::UncheckedSsaPasses { call_stack, .. } => {
+                        (call_stack, "the passes over this program were not checked".to_string())
+                    }
+                };
                 let call_stack = vecmap(call_stack, |location| *location);
                 let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
+                let span = call_stack.last().map(|location| location.span).unwrap_or_default();
                 let message = warning.to_string();
-                let location = call_stack.last().expect("Expected RuntimeError to have a location");
-                let diagnostic = Diagnostic::simple_warning(
-                    message,
-                    "constant value".to_string(),
-                    location.span,
-                );
+                let diagnostic = Diagnostic::simple_warning(message, secondary_message, span);
                 diagnostic.in_file(file_id).with_call_stack(call_stack)
             }
         }
@@ -83,6 +100,8 @@ impl From<SsaReport> for FileDiagnostic {
 pub enum InternalWarning {
     #[error("Returning a constant value is not allowed")]
     ReturnConstant { call_stack: CallStack },
+    #[error("Could not check the SSA passes as {reason}")]
+    UncheckedSsaPasses { reason: String, call_stack: CallStack },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error)]
@@ -122,6 +141,13 @@ impl RuntimeError {
             | RuntimeError::UnInitialized { call_stack, .. }
             | RuntimeError::UnknownLoopBound { call_stack }
             | RuntimeError::AssertConstantFailed { call_stack }
+            | RuntimeError::ComptimeArgumentNotConstant { call_stack }
+            | RuntimeError::ComptimeCallInBranch { call_stack, .. }
+            | RuntimeError::ComptimeCallInLoop { call_stack }
+            | RuntimeError::ComptimeEvaluationFailed { call_stack, .. }
+            | RuntimeError::BigIntModulus { call_stack }
+            | RuntimeError::BigIntNotConstant { call_stack, .. }
+            | RuntimeError::UnconstrainedOnly { call_stack, .. }
             | RuntimeError::IntegerOutOfBounds { call_stack, .. }
             | RuntimeError::UnsupportedIntegerSize { call_stack, .. } => call_stack,
         }
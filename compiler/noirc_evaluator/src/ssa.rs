@@ -9,73 +9,97 @@
 
 use std::collections::BTreeSet;
 
-use crate::errors::{RuntimeError, SsaReport};
-use acvm::acir::{
-    circuit::{Circuit, PublicInputs},
-    native_types::Witness,
+use crate::brillig::brillig_ir::artifact::GeneratedBrillig;
+use crate::errors::{InternalWarning, RuntimeError, SsaReport};
+use acvm::{
+    acir::{
+        circuit::{brillig::BrilligBytecode, Circuit, PublicInputs},
+        native_types::Witness,
+    },
+    FieldElement,
 };
 
+use iter_extended::vecmap;
 use noirc_errors::debug_info::DebugInfo;
 
 use noirc_abi::Abi;
 
 use noirc_frontend::{hir::Context, monomorphization::ast::Program};
 
-use self::{abi_gen::gen_abi, acir_gen::GeneratedAcir, ssa_gen::Ssa};
+use self::{
+    abi_gen::gen_abi,
+    acir_gen::GeneratedAcir,
+    interpreter::{InterpreterError, Value},
+    ir::{
+        dfg::CallStack,
+        instruction::TerminatorInstruction,
+        types::{NumericType, Type},
+    },
+    ssa_gen::Ssa,
+};
 
 pub mod abi_gen;
 mod acir_gen;
 pub(super) mod function_builder;
+mod interpreter;
 pub mod ir;
 mod opt;
+#[cfg(test)]
+mod parser;
 pub mod ssa_gen;
+mod verifier;
 
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
-/// convert the final SSA into ACIR and return it.
+/// convert the final SSA into ACIR and return it along with the ACIR of any `#[fold]` functions
+/// and the bytecode of the unconstrained functions called from ACIR.
+///
+/// If `check_ssa_passes` is true, the SSA is interpreted on several seeded inputs after each pass
+/// to check that the pass didn't change the results of the program.
 pub(crate) fn optimize_into_acir(
     program: Program,
     print_ssa_passes: bool,
     print_brillig_trace: bool,
-) -> Result<GeneratedAcir, RuntimeError> {
+    check_ssa_passes: bool,
+) -> Result<(GeneratedAcir, Vec<GeneratedAcir>, Vec<GeneratedBrillig>), RuntimeError> {
     let abi_distinctness = program.return_distinctness;
-    let ssa = SsaBuilder::new(program, print_ssa_passes)?
-        .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
-        .run_pass(Ssa::inline_functions, "After Inlining:")
-        // Run mem2reg with the CFG separated into blocks
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
-        .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
-        .run_pass(Ssa::simplify_cfg, "After Simplifying:")
-        // Run mem2reg before flattening to handle any promotion
-        // of values that can be accessed after loop unrolling.
-        // If there are slice mergers uncovered by loop unrolling
-        // and this pass is missed, slice merging will fail inside of flattening.
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::flatten_cfg, "After Flattening:")
-        // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::fold_constants, "After Constant Folding:")
-        .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:")
-        .finish();
+    let mut builder = SsaBuilder::new(program, print_ssa_passes)?;
+    if check_ssa_passes {
+        builder = builder.check_passes_with_seeded_inputs();
+    }
+    let mut builder = builder.run_passes()?;
+    let ssa_warnings = std::mem::take(&mut builder.warnings);
+    let ssa = builder.finish();
 
     let brillig = ssa.to_brillig(print_brillig_trace);
     let last_array_uses = ssa.find_last_array_uses();
-    ssa.into_acir(brillig, abi_distinctness, &last_array_uses)
+    let (mut generated_acir, generated_functions, generated_brillig) =
+        ssa.into_acir(brillig, abi_distinctness, &last_array_uses)?;
+    generated_acir.warnings.extend(ssa_warnings);
+    Ok((generated_acir, generated_functions, generated_brillig))
 }
 
 /// Compiles the [`Program`] into [`ACIR`][acvm::acir::circuit::Circuit].
 ///
 /// The output ACIR is is backend-agnostic and so must go through a transformation pass before usage in proof generation.
+///
+/// Alongside the circuit for `main`, this returns a circuit for each `#[fold]` function and the
+/// bytecode of each unconstrained function called from ACIR, indexed by the `id` of the call opcodes
+/// and Brillig call opcodes which refer to them respectively.
+#[allow(clippy::type_complexity)]
 pub fn create_circuit(
     context: &Context,
     program: Program,
     enable_ssa_logging: bool,
     enable_brillig_logging: bool,
-) -> Result<(Circuit, DebugInfo, Abi, Vec<SsaReport>), RuntimeError> {
+    check_ssa_passes: bool,
+) -> Result<
+    (Circuit, Vec<Circuit>, Vec<BrilligBytecode>, DebugInfo, Abi, Vec<SsaReport>),
+    RuntimeError,
+> {
     let func_sig = program.main_function_signature.clone();
-    let mut generated_acir =
-        optimize_into_acir(program, enable_ssa_logging, enable_brillig_logging)?;
+    let (mut generated_acir, generated_functions, generated_brillig) =
+        optimize_into_acir(program, enable_ssa_logging, enable_brillig_logging, check_ssa_passes)?;
     let opcodes = generated_acir.take_opcodes();
     let GeneratedAcir {
         current_witness_index,
@@ -120,19 +144,191 @@ pub fn create_circuit(
     let (optimized_circuit, transformation_map) = acvm::compiler::optimize(circuit);
     debug_info.update_acir(transformation_map);
 
-    Ok((optimized_circuit, debug_info, abi, warnings))
+    // Opcode locations within the called functions are not covered by the debug info.
+    let functions = vecmap(generated_functions, |generated_acir| {
+        let (function_circuit, _) = acvm::compiler::optimize(function_circuit(generated_acir));
+        function_circuit
+    });
+
+    let unconstrained_functions = vecmap(generated_brillig, |generated_brillig| BrilligBytecode {
+        bytecode: generated_brillig.byte_code,
+    });
+
+    Ok((optimized_circuit, functions, unconstrained_functions, debug_info, abi, warnings))
+}
+
+/// Builds the circuit of a `#[fold]` function. Its parameters and return values are all private
+/// as they are only ever passed between circuits through a call opcode.
+fn function_circuit(mut generated_acir: GeneratedAcir) -> Circuit {
+    let opcodes = generated_acir.take_opcodes();
+    let GeneratedAcir {
+        current_witness_index,
+        return_witnesses,
+        input_witnesses,
+        assert_messages,
+        ..
+    } = generated_acir;
+
+    Circuit {
+        current_witness_index,
+        opcodes,
+        private_parameters: input_witnesses.into_iter().collect(),
+        public_parameters: PublicInputs::default(),
+        return_values: PublicInputs(return_witnesses.into_iter().collect()),
+        assert_messages: assert_messages.into_iter().collect(),
+    }
 }
 
 // This is just a convenience object to bundle the ssa with `print_ssa_passes` for debug printing.
 struct SsaBuilder {
     ssa: Ssa,
     print_ssa_passes: bool,
+
+    /// Extended by [`SsaBuilder::check_passes_with_inputs`].
+    interpreter_checks: Vec<InterpreterCheck>,
+
+    /// Set once the SSA uses a feature which the interpreter doesn't support, after which the
+    /// passes are no longer checked.
+    interpreter_checks_skipped: bool,
+
+    /// Set by [`SsaBuilder::expect_no_references`].
+    references_removed: bool,
+
+    /// Warnings about the SSA passes, which are reported alongside those from ACIR generation.
+    warnings: Vec<SsaReport>,
+}
+
+/// The number of pseudo-random input sets on which [`SsaBuilder::check_passes_with_seeded_inputs`]
+/// interprets the SSA, in addition to the all-zero and all-ones input sets.
+const RANDOM_INPUT_SETS: u64 = 4;
+
+/// Inputs to `main` on which the SSA is interpreted after each pass, along with the results
+/// of interpreting the SSA before the first of these passes.
+struct InterpreterCheck {
+    inputs: Vec<Value>,
+    expected: Result<Vec<Value>, InterpreterError>,
 }
 
 impl SsaBuilder {
     fn new(program: Program, print_ssa_passes: bool) -> Result<SsaBuilder, RuntimeError> {
         let ssa = ssa_gen::generate_ssa(program)?;
-        Ok(SsaBuilder { print_ssa_passes, ssa }.print("Initial SSA:"))
+        Ok(SsaBuilder::from_ssa(ssa, print_ssa_passes))
+    }
+
+    fn from_ssa(ssa: Ssa, print_ssa_passes: bool) -> SsaBuilder {
+        SsaBuilder {
+            print_ssa_passes,
+            ssa,
+            interpreter_checks: Vec::new(),
+            interpreter_checks_skipped: false,
+            references_removed: false,
+            warnings: Vec::new(),
+        }
+        .print("Initial SSA:")
+    }
+
+    /// Interprets the SSA on the given inputs after each following pass and panics if its results
+    /// differ from those of the SSA as it is now, indicating that the pass has miscompiled the program.
+    /// This can be called several times to check the passes on each set of inputs.
+    ///
+    /// Failing executions are considered equivalent to each other regardless of the error.
+    /// If the SSA uses a feature which the interpreter doesn't support, the passes can't be
+    /// checked, so a warning is reported and the check is skipped.
+    fn check_passes_with_inputs(mut self, inputs: Vec<Value>) -> Self {
+        if self.interpreter_checks_skipped {
+            return self;
+        }
+        let expected = self.ssa.interpret(inputs.clone());
+        if let Err(error @ InterpreterError::Unsupported(_)) = &expected {
+            let reason = error.to_string();
+            return self.skip_interpreter_checks(reason);
+        }
+        self.interpreter_checks.push(InterpreterCheck { inputs, expected });
+        self
+    }
+
+    /// Checks the passes as [`SsaBuilder::check_passes_with_inputs`] does on several input sets
+    /// for `main`: all zeros, all ones and a few pseudo-random ones from fixed seeds, so that
+    /// the checks are reproducible.
+    fn check_passes_with_seeded_inputs(mut self) -> Self {
+        let main = self.ssa.main();
+        let parameter_types =
+            vecmap(main.parameters(), |parameter| main.dfg.type_of_value(*parameter));
+
+        self = self.check_passes_with_inputs(vecmap(&parameter_types, Value::zero_of_type));
+        self = self.check_passes_with_inputs(vecmap(&parameter_types, |typ| {
+            input_of_type(typ, &mut all_ones)
+        }));
+        for seed in 0..RANDOM_INPUT_SETS {
+            let mut random = SplitMix64(seed);
+            self = self.check_passes_with_inputs(vecmap(&parameter_types, |typ| {
+                input_of_type(typ, &mut |numeric_type| random.next_value(numeric_type))
+            }));
+        }
+        self
+    }
+
+    /// Stops checking the passes, reporting a warning at the return of `main` which explains why.
+    fn skip_interpreter_checks(mut self, reason: String) -> Self {
+        self.interpreter_checks.clear();
+        if !self.interpreter_checks_skipped {
+            self.interpreter_checks_skipped = true;
+            let call_stack = self.main_return_call_stack();
+            self.warnings.push(SsaReport::Warning(InternalWarning::UncheckedSsaPasses {
+                reason,
+                call_stack,
+            }));
+        }
+        self
+    }
+
+    /// Returns the call stack of the first return in `main`, or an empty call stack if it has none.
+    fn main_return_call_stack(&self) -> CallStack {
+        let main = self.ssa.main();
+        main.reachable_blocks()
+            .into_iter()
+            .find_map(|block| match main.dfg[block].terminator() {
+                Some(TerminatorInstruction::Return { call_stack, .. }) => Some(call_stack.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Runs the optimization passes which are applied to the SSA before it is converted into ACIR.
+    fn run_passes(self) -> Result<Self, RuntimeError> {
+        Ok(self
+            .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
+            .run_pass(Ssa::inline_functions, "After Inlining:")
+            // Run mem2reg with the CFG separated into blocks
+            .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+            .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
+            .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
+            .run_pass(Ssa::simplify_cfg, "After Simplifying:")
+            // Loop indices are only known after unrolling, and calls to comptime functions often use them.
+            // Simplifying first removes the branches on constant conditions, whose calls are always executed.
+            .try_run_pass(Ssa::evaluate_comptime_calls, "After Comptime Evaluation:")?
+            // Deduplicate instructions across blocks while Brillig functions still have their own CFG.
+            .run_pass(Ssa::global_value_numbering, "After Global Value Numbering:")
+            // Run mem2reg before flattening to handle any promotion
+            // of values that can be accessed after loop unrolling.
+            // If there are slice mergers uncovered by loop unrolling
+            // and this pass is missed, slice merging will fail inside of flattening.
+            .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+            .run_pass(Ssa::flatten_cfg, "After Flattening:")
+            // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
+            .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+            // Brillig functions keep their loops, so hoist any loop invariants out of them.
+            .run_pass(Ssa::loop_invariant_code_motion, "After Loop Invariant Code Motion:")
+            .run_pass(Ssa::fold_constants, "After Constant Folding:")
+            .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:")
+            .expect_no_references("After Dead Instruction Elimination:"))
+    }
+
+    /// Marks that ACIR functions should no longer contain any references, which the verifier
+    /// checks after this point in debug builds.
+    fn expect_no_references(mut self, msg: &str) -> Self {
+        self.references_removed = true;
+        self.verify(msg)
     }
 
     fn finish(self) -> Ssa {
@@ -142,7 +338,7 @@ impl SsaBuilder {
     /// Runs the given SSA pass and prints the SSA afterward if `print_ssa_passes` is true.
     fn run_pass(mut self, pass: fn(Ssa) -> Ssa, msg: &str) -> Self {
         self.ssa = pass(self.ssa);
-        self.print(msg)
+        self.print(msg).verify(msg).check_results(msg)
     }
 
     /// The same as `run_pass` but for passes that may fail
@@ -152,7 +348,7 @@ impl SsaBuilder {
         msg: &str,
     ) -> Result<Self, RuntimeError> {
         self.ssa = pass(self.ssa)?;
-        Ok(self.print(msg))
+        Ok(self.print(msg).verify(msg).check_results(msg))
     }
 
     fn print(self, msg: &str) -> Self {
@@ -161,4 +357,275 @@ impl SsaBuilder {
         }
         self
     }
+
+    /// Checks that the SSA is still well-formed in debug builds, panicking with the name of the
+    /// pass which was just run if not.
+    fn verify(self, msg: &str) -> Self {
+        if cfg!(debug_assertions) {
+            if let Err(error) = self.ssa.verify(self.references_removed) {
+                panic!("ICE: the SSA is invalid {}: {error}\n{}", pass_description(msg), self.ssa);
+            }
+        }
+        self
+    }
+
+    fn check_results(self, msg: &str) -> Self {
+        for InterpreterCheck { inputs, expected } in &self.interpreter_checks {
+            let actual = self.ssa.interpret(inputs.clone());
+            if let Err(error @ InterpreterError::Unsupported(_)) = &actual {
+                let reason = format!("{error} {}", pass_description(msg));
+                return self.skip_interpreter_checks(reason);
+            }
+            let unchanged = match (expected, &actual) {
+                (Ok(expected), Ok(actual)) => expected == actual,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            };
+            assert!(
+                unchanged,
+                "ICE: interpreting the SSA gave {actual:?} rather than {expected:?} {}\n{}",
+                pass_description(msg),
+                self.ssa
+            );
+        }
+        self
+    }
+}
+
+/// Returns an input of the given type for `main`, using `numeric_input` for each numeric value.
+/// Inputs of other types are zero-valued.
+fn input_of_type(typ: &Type, numeric_input: &mut impl FnMut(NumericType) -> FieldElement) -> Value {
+    match typ {
+        Type::Numeric(numeric_type) => {
+            Value::Numeric { value: numeric_input(*numeric_type), typ: *numeric_type }
+        }
+        Type::Array(element_types, length) => {
+            let elements = (0..*length)
+                .flat_map(|_| vecmap(element_types.iter(), |typ| input_of_type(typ, numeric_input)))
+                .collect();
+            Value::Array { elements, typ: typ.clone() }
+        }
+        _ => Value::zero_of_type(typ),
+    }
+}
+
+/// Returns the value of the given type with all of its bits set, or `-1` for fields.
+fn all_ones(typ: NumericType) -> FieldElement {
+    match typ {
+        NumericType::NativeField => -FieldElement::one(),
+        NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => {
+            FieldElement::from(low_bits_mask(bit_size))
+        }
+    }
+}
+
+fn low_bits_mask(bit_size: u32) -> u128 {
+    if bit_size >= 128 {
+        u128::MAX
+    } else {
+        (1 << bit_size) - 1
+    }
+}
+
+/// A splitmix64 generator, which is enough to pick varied inputs without depending on `rand`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value of the given type made of a random number of random low bits, so that
+    /// small values such as loop bounds are as likely as large ones. Fields may also be negated.
+    fn next_value(&mut self, typ: NumericType) -> FieldElement {
+        let bits = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+        let width = match typ {
+            NumericType::NativeField => 128,
+            NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => {
+                bit_size.min(128)
+            }
+        };
+        let value = FieldElement::from(bits & low_bits_mask(self.next_u64() as u32 % (width + 1)));
+        match typ {
+            NumericType::NativeField if self.next_u64() % 2 == 1 => -value,
+            _ => value,
+        }
+    }
+}
+
+/// Turns a message such as "After Inlining:" into "after inlining" for use in panic messages.
+fn pass_description(msg: &str) -> String {
+    msg.trim_end_matches(':').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use acvm::FieldElement;
+
+    use crate::errors::{InternalWarning, SsaReport};
+
+    use super::{
+        interpreter::Value,
+        ir::{
+            instruction::{Binary, BinaryOp, Instruction, TerminatorInstruction},
+            types::NumericType,
+        },
+        ssa_gen::Ssa,
+        SsaBuilder,
+    };
+
+    /// Sums the first `v0` elements of an array in a loop, then squares the sum in a Brillig function.
+    const SRC: &str = "
+acir fn main f0 {
+  b0(v0: u32, v1: [Field; 3]):
+    v2 = allocate
+    store Field 0 at v2
+    jmp b1(u32 0)
+  b1(v3: u32):
+    v4 = lt v3, u32 3
+    jmpif v4 then: b2, else: b3
+  b2():
+    v5 = lt v3, v0
+    jmpif v5 then: b4, else: b5
+  b4():
+    v6 = array_get v1, index v3 -> Field
+    v7 = load v2 -> Field
+    v8 = add v7, v6
+    store v8 at v2
+    jmp b5()
+  b5():
+    v9 = add v3, u32 1
+    jmp b1(v9)
+  b3():
+    v10 = load v2 -> Field
+    v11 = call f1(v10) -> Field
+    return v11
+}
+brillig fn square f1 {
+  b0(v0: Field):
+    v1 = mul v0, v0
+    return v1
+}";
+
+    fn inputs(count: u128) -> Vec<Value> {
+        let count =
+            Value::Numeric { value: count.into(), typ: NumericType::Unsigned { bit_size: 32 } };
+        let array =
+            Value::array(vec![1u128.into(), 2u128.into(), 3u128.into()], NumericType::NativeField);
+        vec![count, array]
+    }
+
+    #[test]
+    fn passes_preserve_program_results() {
+        for (count, expected) in [(0, 0u128), (2, 9), (5, 36)] {
+            let ssa = Ssa::from_str(SRC).unwrap();
+            let ssa = SsaBuilder::from_ssa(ssa, false)
+                .check_passes_with_inputs(inputs(count))
+                .run_passes()
+                .unwrap()
+                .finish();
+
+            let results = ssa.interpret(inputs(count)).unwrap();
+            assert_eq!(results, vec![Value::field(FieldElement::from(expected))]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "interpreting the SSA gave")]
+    fn detects_passes_which_change_program_results() {
+        fn replace_additions_with_subtractions(mut ssa: Ssa) -> Ssa {
+            let main = ssa.main_mut();
+            for block in main.reachable_blocks() {
+                for instruction in main.dfg[block].instructions().to_vec() {
+                    if let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Add }) =
+                        main.dfg[instruction]
+                    {
+                        main.dfg[instruction] = Instruction::binary(BinaryOp::Sub, lhs, rhs);
+                    }
+                }
+            }
+            ssa
+        }
+
+        let ssa = Ssa::from_str(SRC).unwrap();
+        SsaBuilder::from_ssa(ssa, false)
+            .check_passes_with_inputs(inputs(2))
+            .run_pass(replace_additions_with_subtractions, "After Miscompiling:");
+    }
+
+    #[test]
+    #[should_panic(expected = "interpreting the SSA gave")]
+    fn seeded_inputs_detect_passes_which_preserve_results_on_zero_inputs() {
+        fn replace_multiplications_with_additions(mut ssa: Ssa) -> Ssa {
+            let main = ssa.main_mut();
+            for block in main.reachable_blocks() {
+                for instruction in main.dfg[block].instructions().to_vec() {
+                    if let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Mul }) =
+                        main.dfg[instruction]
+                    {
+                        main.dfg[instruction] = Instruction::binary(BinaryOp::Add, lhs, rhs);
+                    }
+                }
+            }
+            ssa
+        }
+
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v1 = mul v0, v0
+    return v1
+}";
+        let zero = vec![Value::field(FieldElement::zero())];
+        SsaBuilder::from_ssa(Ssa::from_str(src).unwrap(), false)
+            .check_passes_with_inputs(zero)
+            .run_pass(replace_multiplications_with_additions, "After Miscompiling:");
+
+        SsaBuilder::from_ssa(Ssa::from_str(src).unwrap(), false)
+            .check_passes_with_seeded_inputs()
+            .run_pass(replace_multiplications_with_additions, "After Miscompiling:");
+    }
+
+    #[test]
+    fn skips_checking_passes_of_programs_the_interpreter_does_not_support() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    call print(v0)
+    return v0
+}";
+        let ssa = Ssa::from_str(src).unwrap();
+        let builder = SsaBuilder::from_ssa(ssa, false).check_passes_with_seeded_inputs();
+
+        assert!(builder.interpreter_checks.is_empty());
+        assert!(matches!(
+            builder.warnings.as_slice(),
+            [SsaReport::Warning(InternalWarning::UncheckedSsaPasses { .. })]
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "the SSA is invalid after dropping jump arguments")]
+    fn detects_passes_which_produce_invalid_ssa() {
+        fn drop_jump_arguments(mut ssa: Ssa) -> Ssa {
+            let main = ssa.main_mut();
+            let entry = main.entry_block();
+            if let TerminatorInstruction::Jmp { arguments, .. } =
+                main.dfg[entry].unwrap_terminator_mut()
+            {
+                arguments.clear();
+            }
+            ssa
+        }
+
+        let ssa = Ssa::from_str(SRC).unwrap();
+        SsaBuilder::from_ssa(ssa, false)
+            .run_pass(drop_jump_arguments, "After Dropping Jump Arguments:");
+    }
 }
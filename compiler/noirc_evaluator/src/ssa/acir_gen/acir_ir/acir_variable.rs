@@ -1,3 +1,4 @@
+use super::big_int::BigIntContext;
 use super::generated_acir::GeneratedAcir;
 use crate::brillig::brillig_gen::brillig_directive;
 use crate::brillig::brillig_ir::artifact::GeneratedBrillig;
@@ -9,7 +10,7 @@ use crate::ssa::ir::{instruction::Endian, types::NumericType};
 use acvm::acir::circuit::brillig::{BrilligInputs, BrilligOutputs};
 use acvm::acir::circuit::opcodes::{BlockId, MemOp};
 use acvm::acir::circuit::Opcode;
-use acvm::brillig_vm::{brillig::Value, Registers, VMStatus, VM};
+use acvm::brillig_vm::{brillig::Value, ExecutionLimits, Registers, VMStatus, VM};
 use acvm::{
     acir::{
         brillig::Opcode as BrilligOpcode,
@@ -107,6 +108,9 @@ pub(crate) struct AcirContext {
     /// then the `acir_ir` will be populated to assert this
     /// addition.
     acir_ir: GeneratedAcir,
+
+    /// The identifiers of the big integers and moduli used by the circuit.
+    big_int_ctx: BigIntContext,
 }
 
 impl AcirContext {
@@ -305,9 +309,10 @@ impl AcirContext {
 
         let results = self.brillig(
             predicate,
-            inverse_code,
+            &inverse_code,
             vec![AcirValue::Var(var, AcirType::field())],
             vec![AcirType::field()],
+            None,
         )?;
         let inverted_var = Self::expect_one_var(results);
 
@@ -405,7 +410,7 @@ impl AcirContext {
             // max - ((max - a) AND (max -b))
             // Subtracting from max flips the bits, so this is effectively:
             // (NOT a) NAND (NOT b)
-            let max = self.add_constant(FieldElement::from((1_u128 << bit_size) - 1));
+            let max = self.add_constant(FieldElement::from(u128::MAX >> (128 - bit_size)));
             let a = self.sub_var(max, lhs)?;
             let b = self.sub_var(max, rhs)?;
             let inputs = vec![AcirValue::Var(a, typ.clone()), AcirValue::Var(b, typ)];
@@ -548,7 +553,7 @@ impl AcirContext {
     pub(crate) fn not_var(&mut self, x: AcirVar, typ: AcirType) -> Result<AcirVar, RuntimeError> {
         let bit_size = typ.bit_size();
         // Subtracting from max flips the bits
-        let max = self.add_constant(FieldElement::from((1_u128 << bit_size) - 1));
+        let max = self.add_constant(FieldElement::from(u128::MAX >> (128 - bit_size)));
         self.sub_var(max, x)
     }
 
@@ -572,11 +577,15 @@ impl AcirContext {
             // If `lhs` and `rhs` are known constants then we can calculate the result at compile time.
             // `rhs` must be non-zero.
             (Some(lhs_const), Some(rhs_const)) if rhs_const != FieldElement::zero() => {
-                let quotient = lhs_const.to_u128() / rhs_const.to_u128();
-                let remainder = lhs_const.to_u128() - quotient * rhs_const.to_u128();
+                let lhs_big = BigUint::from_bytes_be(&lhs_const.to_be_bytes());
+                let rhs_big = BigUint::from_bytes_be(&rhs_const.to_be_bytes());
+                let quotient = &lhs_big / &rhs_big;
+                let remainder = lhs_big - &quotient * rhs_big;
 
-                let quotient_var = self.add_constant(FieldElement::from(quotient));
-                let remainder_var = self.add_constant(FieldElement::from(remainder));
+                let quotient_var =
+                    self.add_constant(FieldElement::from_be_bytes_reduce(&quotient.to_bytes_be()));
+                let remainder_var =
+                    self.add_constant(FieldElement::from_be_bytes_reduce(&remainder.to_bytes_be()));
                 return Ok((quotient_var, remainder_var));
             }
 
@@ -630,25 +639,28 @@ impl AcirContext {
 
         // Avoids overflow: 'q*b+r < 2^max_q_bits*2^max_rhs_bits'
         let mut avoid_overflow = false;
+        let mut wide_division = false;
         if max_q_bits + max_rhs_bits >= FieldElement::max_num_bits() - 1 {
             // q*b+r can overflow; we avoid this when b is constant
             if self.var_to_expression(rhs)?.is_const() {
                 avoid_overflow = true;
             } else {
-                // we do not support unbounded division
-                unreachable!("overflow in unbounded division");
+                // otherwise q*b is computed by splitting q and b into limbs
+                assert!(bit_size <= 128, "overflow in unbounded division");
+                wide_division = true;
             }
         }
 
         let [q_value, r_value]: [AcirValue; 2] = self
             .brillig(
                 predicate,
-                brillig_directive::directive_quotient(bit_size + 1),
+                &brillig_directive::directive_quotient(bit_size + 1),
                 vec![
                     AcirValue::Var(lhs, AcirType::unsigned(bit_size)),
                     AcirValue::Var(rhs, AcirType::unsigned(bit_size)),
                 ],
                 vec![AcirType::unsigned(max_q_bits), AcirType::unsigned(max_rhs_bits)],
+                None,
             )?
             .try_into()
             .expect("quotient only returns two values");
@@ -674,7 +686,15 @@ impl AcirContext {
         // When the predicate is 0, the equation always passes.
         // When the predicate is 1, the euclidean division needs to be
         // true.
-        let rhs_constraint = self.mul_var(rhs, quotient_var)?;
+        let rhs_constraint = if wide_division {
+            // q*b must not exceed the integer type, so the product of the high limbs must be zero
+            let (low_terms, high_product) = self.limb_products(rhs, quotient_var, bit_size)?;
+            let zero = self.add_constant(FieldElement::zero());
+            self.assert_eq_var(high_product, zero, None)?;
+            low_terms
+        } else {
+            self.mul_var(rhs, quotient_var)?
+        };
         let rhs_constraint = self.add_var(rhs_constraint, remainder_var)?;
         let rhs_constraint = self.mul_var(rhs_constraint, predicate)?;
 
@@ -745,9 +765,9 @@ impl AcirContext {
 
         let mut lhs_offset = self.add_var(lhs, offset)?;
 
-        // Optimization when rhs is const and fits within a u128
+        // Optimization when rhs is const and the next power of two fits within a u128
         let rhs_expr = self.var_to_expression(rhs)?;
-        if rhs_expr.is_const() && rhs_expr.q_c.fits_in_u128() {
+        if rhs_expr.is_const() && rhs_expr.q_c.num_bits() < 128 {
             // We try to move the offset to rhs
             let rhs_offset = if self.is_constant_one(&offset) && rhs_expr.q_c.to_u128() >= 1 {
                 lhs_offset = lhs;
@@ -894,6 +914,50 @@ impl AcirContext {
         Ok(variable)
     }
 
+    /// Returns an `AcirVar` constrained to be `lhs * rhs mod 2^{bit_size}`, for integers which are
+    /// too wide for their product to fit within the field modulus.
+    pub(crate) fn wide_mul_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<AcirVar, RuntimeError> {
+        // The product of the high limbs is a multiple of 2^{bit_size}, so it vanishes modulo 2^{bit_size}.
+        let (low_terms, _) = self.limb_products(lhs, rhs, bit_size)?;
+        let limb_bits = (bit_size + 1) / 2;
+        self.truncate_var(low_terms, bit_size, bit_size + limb_bits + 2)
+    }
+
+    /// Splits `lhs` and `rhs`, integers of `bit_size` bits, into a low limb of `(bit_size + 1) / 2` bits
+    /// and a high limb, so that:
+    /// `lhs * rhs = lhs_lo * rhs_lo + 2^{limb_bits} * (lhs_lo * rhs_hi + lhs_hi * rhs_lo) + 2^{2 * limb_bits} * lhs_hi * rhs_hi`
+    ///
+    /// Returns the sum of the first two terms, which has at most `bit_size + limb_bits + 2` bits,
+    /// and the product of the high limbs. Neither overflows the field for integers of up to 128 bits.
+    fn limb_products(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let limb_bits = (bit_size + 1) / 2;
+        let limb_modulus = self
+            .add_constant(FieldElement::from(2_u128).pow(&FieldElement::from(limb_bits as u128)));
+        let one = self.add_constant(FieldElement::one());
+        let (lhs_hi, lhs_lo) = self.euclidean_division_var(lhs, limb_modulus, bit_size, one)?;
+        let (rhs_hi, rhs_lo) = self.euclidean_division_var(rhs, limb_modulus, bit_size, one)?;
+
+        let low_product = self.mul_var(lhs_lo, rhs_lo)?;
+        let lhs_cross_product = self.mul_var(lhs_lo, rhs_hi)?;
+        let rhs_cross_product = self.mul_var(lhs_hi, rhs_lo)?;
+        let cross_product = self.add_var(lhs_cross_product, rhs_cross_product)?;
+        let cross_product = self.mul_var(cross_product, limb_modulus)?;
+        let low_terms = self.add_var(low_product, cross_product)?;
+
+        let high_product = self.mul_var(lhs_hi, rhs_hi)?;
+        Ok((low_terms, high_product))
+    }
+
     /// Returns an `AcirVar` which will be constrained to be lhs mod 2^{rhs}
     /// In order to do this, we 'simply' perform euclidean division of lhs by 2^{rhs}
     /// The remainder of the division is then lhs mod 2^{rhs}
@@ -994,6 +1058,10 @@ impl AcirContext {
         bit_size: u32,
         predicate: AcirVar,
     ) -> Result<AcirVar, RuntimeError> {
+        if bit_size >= FieldElement::max_num_bits() {
+            return self.field_less_than_var(lhs, rhs, predicate);
+        }
+
         // Flip the result of calling more than equal method to
         // compute less than.
         let comparison = self.more_than_eq_var(lhs, rhs, bit_size, predicate)?;
@@ -1002,16 +1070,85 @@ impl AcirContext {
         self.sub_var(one, comparison) // comparison_negated
     }
 
+    /// Returns an `AcirVar` which will be `1` if lhs < rhs as field elements
+    /// (i.e. as integers in the range `[0, p)`) and `0` otherwise.
+    ///
+    /// Both operands are decomposed into two 128-bit limbs which are then compared lexicographically,
+    /// as field elements are too large to be compared with a single bound constraint.
+    fn field_less_than_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        predicate: AcirVar,
+    ) -> Result<AcirVar, RuntimeError> {
+        let (lhs_hi, lhs_lo) = self.field_limbs(lhs)?;
+        let (rhs_hi, rhs_lo) = self.field_limbs(rhs)?;
+
+        // lhs < rhs <=> lhs_hi < rhs_hi || (lhs_hi == rhs_hi && lhs_lo < rhs_lo)
+        let hi_less_than = self.less_than_var(lhs_hi, rhs_hi, 128, predicate)?;
+        let hi_equal = self.eq_var(lhs_hi, rhs_hi)?;
+        let lo_less_than = self.less_than_var(lhs_lo, rhs_lo, 128, predicate)?;
+        let lo_decides = self.mul_var(hi_equal, lo_less_than)?;
+
+        // At most one of the two terms can be one, so their sum is boolean.
+        self.add_var(hi_less_than, lo_decides)
+    }
+
+    /// Decomposes `field` into `(hi, lo)` such that `field = hi * 2^128 + lo` with `lo < 2^128`.
+    ///
+    /// The range constraints of the euclidean division alone would allow `hi * 2^128 + lo` to be
+    /// `field + p`, so the limbs are also constrained to be smaller than those of the modulus,
+    /// which makes the decomposition unique.
+    fn field_limbs(&mut self, field: AcirVar) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let limb_modulus_value = FieldElement::from(2_u128).pow(&FieldElement::from(128_u128));
+        let limb_modulus = self.add_constant(limb_modulus_value);
+        let one = self.add_constant(FieldElement::one());
+        let (hi, lo) =
+            self.euclidean_division_var(field, limb_modulus, FieldElement::max_num_bits(), one)?;
+
+        // (hi, lo) < (p_hi, p_lo) <=> p - 1 - (hi * 2^128 + lo) is non-negative, which is checked
+        // by subtracting the limbs with a borrow and range constraining both differences:
+        //   borrow = lo > p_lo - 1
+        //   r_lo = p_lo - 1 - lo + borrow * 2^128 < 2^128
+        //   r_hi = p_hi - hi - borrow < 2^128
+        let modulus = FieldElement::modulus();
+        let p_hi_big = &modulus >> 128;
+        let p_lo_big = &modulus - (&p_hi_big << 128);
+        let p_hi = self.add_constant(FieldElement::from_be_bytes_reduce(&p_hi_big.to_bytes_be()));
+        let p_lo_minus_one = self.add_constant(
+            FieldElement::from_be_bytes_reduce(&p_lo_big.to_bytes_be()) - FieldElement::one(),
+        );
+
+        let borrow = self.less_than_var(p_lo_minus_one, lo, 128, one)?;
+
+        let lo_difference = self.sub_var(p_lo_minus_one, lo)?;
+        let lo_difference = self.add_mul_var(lo_difference, limb_modulus_value, borrow)?;
+        self.range_constrain_var(lo_difference, &NumericType::Unsigned { bit_size: 128 })?;
+
+        let hi_difference = self.sub_var(p_hi, hi)?;
+        let hi_difference = self.sub_var(hi_difference, borrow)?;
+        self.range_constrain_var(hi_difference, &NumericType::Unsigned { bit_size: 128 })?;
+
+        Ok((hi, lo))
+    }
+
     /// Calls a Blackbox function on the given inputs and returns a given set of outputs
     /// to represent the result of the blackbox function.
     pub(crate) fn black_box_function(
         &mut self,
         name: BlackBoxFunc,
         mut inputs: Vec<AcirValue>,
-        output_count: usize,
+        mut output_count: usize,
     ) -> Result<Vec<AcirVar>, RuntimeError> {
-        // Separate out any arguments that should be constants
-        let constants = match name {
+        // Separate out any arguments that should be constants, along with the constant
+        // results of calls which don't output any witnesses.
+        let (constants, constant_outputs) = match name {
+            BlackBoxFunc::EcdsaSecp256k1Recover => {
+                return Err(RuntimeError::UnconstrainedOnly {
+                    name: name.to_string(),
+                    call_stack: self.get_call_stack(),
+                });
+            }
             BlackBoxFunc::PedersenCommitment | BlackBoxFunc::PedersenHash => {
                 // The last argument of pedersen is the domain separator, which must be a constant
                 let domain_var = match inputs.pop() {
@@ -1035,9 +1172,81 @@ impl AcirContext {
                     }
                 };
 
-                vec![domain_constant]
+                (vec![domain_constant], Vec::new())
+            }
+            BlackBoxFunc::Poseidon2Permutation => {
+                // The last argument of poseidon2 is the length of the state, which must be a constant
+                let len_var = match inputs.pop() {
+                    Some(len_var) => len_var.into_var()?,
+                    None => {
+                        return Err(RuntimeError::InternalError(InternalError::MissingArg {
+                            name: "poseidon2 permutation call".to_string(),
+                            arg: "state length".to_string(),
+                            call_stack: self.get_call_stack(),
+                        }))
+                    }
+                };
+
+                let len_constant = match self.vars[&len_var].as_constant() {
+                    Some(len_constant) => len_constant,
+                    None => {
+                        return Err(RuntimeError::InternalError(InternalError::NotAConstant {
+                            name: "state length".to_string(),
+                            call_stack: self.get_call_stack(),
+                        }))
+                    }
+                };
+
+                (vec![len_constant], Vec::new())
+            }
+            BlackBoxFunc::BigIntAdd
+            | BlackBoxFunc::BigIntSub
+            | BlackBoxFunc::BigIntMul
+            | BlackBoxFunc::BigIntDiv => {
+                // Big integers are passed as their identifier and the identifier of their modulus,
+                // which are assigned at compile-time and so must be constants.
+                let big_int_ids = try_vecmap(std::mem::take(&mut inputs), AcirValue::into_var)?;
+                let big_int_ids = self.big_int_constants(&big_int_ids, "Big integer")?;
+                let [lhs, lhs_modulus, rhs, rhs_modulus] = big_int_ids[..] else {
+                    unreachable!("ICE: {name} expects two big integers");
+                };
+                if lhs_modulus != rhs_modulus {
+                    return Err(RuntimeError::BigIntModulus { call_stack: self.get_call_stack() });
+                }
+
+                output_count = 0;
+                let output = self.big_int_ctx.new_big_int();
+                (vec![lhs, rhs, output], vec![output, lhs_modulus])
+            }
+            BlackBoxFunc::BigIntFromLeBytes => {
+                // The last argument is the modulus, which must be known at compile-time
+                let modulus = match inputs.pop() {
+                    Some(modulus) => vecmap(modulus.flatten(), |(var, _)| var),
+                    None => {
+                        return Err(RuntimeError::InternalError(InternalError::MissingArg {
+                            name: "big integer call".to_string(),
+                            arg: "modulus".to_string(),
+                            call_stack: self.get_call_stack(),
+                        }))
+                    }
+                };
+                let modulus = self.big_int_constants(&modulus, "Big integer modulus")?;
+                let modulus_bytes = vecmap(&modulus, |byte| byte.to_u128() as u8);
+                let modulus_id =
+                    self.big_int_ctx.get_or_insert_modulus(BigUint::from_bytes_le(&modulus_bytes));
+
+                output_count = 0;
+                let output = self.big_int_ctx.new_big_int();
+                let mut constants = vec![output];
+                constants.extend(modulus);
+                (constants, vec![output, modulus_id])
             }
-            _ => vec![],
+            BlackBoxFunc::BigIntToLeBytes => {
+                let big_int_ids = try_vecmap(std::mem::take(&mut inputs), AcirValue::into_var)?;
+                let big_int_ids = self.big_int_constants(&big_int_ids, "Big integer")?;
+                (vec![big_int_ids[0]], Vec::new())
+            }
+            _ => (vec![], Vec::new()),
         };
 
         // Convert `AcirVar` to `FunctionInput`
@@ -1046,6 +1255,11 @@ impl AcirContext {
         // Call Black box with `FunctionInput`
         let outputs = self.acir_ir.call_black_box(name, &inputs, constants, output_count)?;
 
+        // Big integer operations return the constant identifiers of their result.
+        if !constant_outputs.is_empty() {
+            return Ok(vecmap(constant_outputs, |output| self.add_constant(output)));
+        }
+
         // Convert `Witness` values which are now constrained to be the output of the
         // black box function call into `AcirVar`s.
         //
@@ -1054,6 +1268,24 @@ impl AcirContext {
         Ok(vecmap(&outputs, |witness_index| self.add_data(AcirVarData::Witness(*witness_index))))
     }
 
+    /// Returns the constant values of the identifiers passed to a big integer black box function.
+    /// Returns the values of the ids and moduli of big integers, which are tracked at compile-time.
+    ///
+    /// Flattening merges the values of the branches of a runtime condition, so a big integer
+    /// chosen by such a condition no longer has a constant id and is reported to the user.
+    fn big_int_constants(
+        &self,
+        vars: &[AcirVar],
+        name: &str,
+    ) -> Result<Vec<FieldElement>, RuntimeError> {
+        try_vecmap(vars, |var| {
+            self.vars[var].as_constant().ok_or_else(|| RuntimeError::BigIntNotConstant {
+                name: name.to_string(),
+                call_stack: self.get_call_stack(),
+            })
+        })
+    }
+
     /// Black box function calls expect their inputs to be in a specific data structure (FunctionInput).
     ///
     /// This function will convert `AcirVar` into `FunctionInput` for a blackbox function call.
@@ -1179,12 +1411,18 @@ impl AcirContext {
         id
     }
 
+    /// Executes the given Brillig code on `inputs`.
+    ///
+    /// If `brillig_function_index` is set, the code is the unconstrained function with that index in
+    /// the program's list of unconstrained functions and is called through a Brillig call opcode
+    /// rather than being embedded into the circuit.
     pub(crate) fn brillig(
         &mut self,
         predicate: AcirVar,
-        generated_brillig: GeneratedBrillig,
+        generated_brillig: &GeneratedBrillig,
         inputs: Vec<AcirValue>,
         outputs: Vec<AcirType>,
+        brillig_function_index: Option<u32>,
     ) -> Result<Vec<AcirValue>, InternalError> {
         let b_inputs = try_vecmap(inputs, |i| match i {
             AcirValue::Var(var, _) => Ok(BrilligInputs::Single(self.var_to_expression(var)?)),
@@ -1225,8 +1463,15 @@ impl AcirContext {
                 acir_value
             }
         });
-        let predicate = self.var_to_expression(predicate)?;
-        self.acir_ir.brillig(Some(predicate), generated_brillig, b_inputs, b_outputs);
+        let predicate = Some(self.var_to_expression(predicate)?);
+        match brillig_function_index {
+            Some(id) => {
+                self.acir_ir.brillig_call(predicate, generated_brillig, b_inputs, b_outputs, id);
+            }
+            None => {
+                self.acir_ir.brillig(predicate, generated_brillig.clone(), b_inputs, b_outputs);
+            }
+        }
 
         Ok(outputs_var)
     }
@@ -1290,6 +1535,44 @@ impl AcirContext {
         (AcirValue::Array(array_values), witnesses)
     }
 
+    /// Calls the non-inlined ACIR function with the given `id`.
+    ///
+    /// The inputs are flattened into a list of witnesses and fresh witnesses are allocated
+    /// for the outputs, which are assigned by the callee's circuit when solving.
+    pub(crate) fn call_acir_function(
+        &mut self,
+        id: u32,
+        predicate: AcirVar,
+        inputs: Vec<AcirValue>,
+        outputs: Vec<AcirType>,
+    ) -> Result<Vec<AcirValue>, InternalError> {
+        let mut input_expressions = Vec::new();
+        for input in inputs {
+            self.brillig_array_input(&mut input_expressions, input)?;
+        }
+        let inputs = vecmap(input_expressions, |expr| self.acir_ir.get_or_create_witness(&expr));
+
+        let mut output_witnesses = Vec::new();
+        let outputs_var = vecmap(outputs, |output| match output {
+            AcirType::NumericType(_) => {
+                let witness_index = self.acir_ir.next_witness_index();
+                output_witnesses.push(witness_index);
+                let var = self.add_data(AcirVarData::Witness(witness_index));
+                AcirValue::Var(var, output.clone())
+            }
+            AcirType::Array(element_types, size) => {
+                let (acir_value, mut witnesses) = self.brillig_array_output(&element_types, size);
+                output_witnesses.append(&mut witnesses);
+                acir_value
+            }
+        });
+
+        let predicate = Some(self.var_to_expression(predicate)?);
+        self.acir_ir.push_opcode(Opcode::Call { id, inputs, outputs: output_witnesses, predicate });
+
+        Ok(outputs_var)
+    }
+
     fn execute_brillig(
         &mut self,
         code: &[BrilligOpcode],
@@ -1559,6 +1842,63 @@ impl From<Expression> for AcirVarData {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct AcirVar(usize);
 
+/// The maximum number of Brillig opcodes which will be executed when evaluating
+/// a call to an unconstrained function with constant inputs at compile time.
+const BRILLIG_CONSTANT_EVALUATION_STEP_LIMIT: usize = 1_000_000;
+
+/// A black box solver which supports none of the black box functions that need a backend,
+/// as these can't be evaluated at compile time.
+struct NullBbSolver;
+
+impl BlackBoxFunctionSolver for NullBbSolver {
+    fn schnorr_verify(
+        &self,
+        _public_key_x: &FieldElement,
+        _public_key_y: &FieldElement,
+        _signature: &[u8],
+        _message: &[u8],
+    ) -> Result<bool, BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::SchnorrVerify))
+    }
+    fn pedersen_commitment(
+        &self,
+        _inputs: &[FieldElement],
+        _domain_separator: u32,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::PedersenCommitment))
+    }
+    fn pedersen_hash(
+        &self,
+        _inputs: &[FieldElement],
+        _domain_separator: u32,
+    ) -> Result<FieldElement, BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::PedersenHash))
+    }
+    fn fixed_base_scalar_mul(
+        &self,
+        _low: &FieldElement,
+        _high: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::FixedBaseScalarMul))
+    }
+    fn ec_add(
+        &self,
+        _input1_x: &FieldElement,
+        _input1_y: &FieldElement,
+        _input2_x: &FieldElement,
+        _input2_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::EmbeddedCurveAdd))
+    }
+    fn ec_double(
+        &self,
+        _input_x: &FieldElement,
+        _input_y: &FieldElement,
+    ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
+        Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::EmbeddedCurveDouble))
+    }
+}
+
 /// Attempts to execute the provided [`Brillig`][`acvm::acir::brillig`] bytecode
 ///
 /// Returns the finished state of the Brillig VM if execution can complete.
@@ -1568,41 +1908,6 @@ fn execute_brillig(
     code: &[BrilligOpcode],
     inputs: &[BrilligInputs],
 ) -> Option<(Registers, Vec<Value>)> {
-    struct NullBbSolver;
-
-    impl BlackBoxFunctionSolver for NullBbSolver {
-        fn schnorr_verify(
-            &self,
-            _public_key_x: &FieldElement,
-            _public_key_y: &FieldElement,
-            _signature: &[u8],
-            _message: &[u8],
-        ) -> Result<bool, BlackBoxResolutionError> {
-            Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::SchnorrVerify))
-        }
-        fn pedersen_commitment(
-            &self,
-            _inputs: &[FieldElement],
-            _domain_separator: u32,
-        ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
-            Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::PedersenCommitment))
-        }
-        fn pedersen_hash(
-            &self,
-            _inputs: &[FieldElement],
-            _domain_separator: u32,
-        ) -> Result<FieldElement, BlackBoxResolutionError> {
-            Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::PedersenHash))
-        }
-        fn fixed_base_scalar_mul(
-            &self,
-            _low: &FieldElement,
-            _high: &FieldElement,
-        ) -> Result<(FieldElement, FieldElement), BlackBoxResolutionError> {
-            Err(BlackBoxResolutionError::Unsupported(BlackBoxFunc::FixedBaseScalarMul))
-        }
-    }
-
     // Set input values
     let mut input_register_values: Vec<Value> = Vec::with_capacity(inputs.len());
     let mut input_memory: Vec<Value> = Vec::new();
@@ -1629,6 +1934,10 @@ fn execute_brillig(
     // Instantiate a Brillig VM given the solved input registers and memory, along with the Brillig bytecode.
     let input_registers = Registers::load(input_register_values);
     let mut vm = VM::new(input_registers, input_memory, code, Vec::new(), &NullBbSolver);
+    vm.set_limits(ExecutionLimits {
+        max_steps: Some(BRILLIG_CONSTANT_EVALUATION_STEP_LIMIT),
+        ..ExecutionLimits::default()
+    });
 
     // Run the Brillig VM on these inputs, bytecode, etc!
     let vm_status = vm.process_opcodes();
@@ -1643,6 +1952,11 @@ fn execute_brillig(
             // TODO: Return an error stating that the brillig function failed.
             None
         }
+        VMStatus::LimitExceeded { .. } => {
+            // The function may never terminate so we leave it to be executed at runtime,
+            // where the user has control over any execution limits.
+            None
+        }
         VMStatus::ForeignCallWait { .. } => {
             // If execution can't complete then keep the opcode
 
@@ -1655,3 +1969,73 @@ fn execute_brillig(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::{
+        acir::{circuit::Opcode, native_types::WitnessMap},
+        pwg::{ACVMStatus, ACVM},
+        FieldElement,
+    };
+    use num_bigint::BigUint;
+
+    use super::{AcirContext, NullBbSolver};
+
+    /// Solves the decomposition of `x` into 128-bit limbs with the quotient hint replaced by
+    /// the given limbs.
+    fn solve_field_limbs(x: FieldElement, hi: FieldElement, lo: FieldElement) -> ACVMStatus {
+        let mut context = AcirContext::default();
+        let x_var = context.add_variable();
+        let x_witness = context.var_to_witness(x_var).unwrap();
+        let (hi_var, lo_var) = context.field_limbs(x_var).unwrap();
+        let hi_witness = context.var_to_witness(hi_var).unwrap();
+        let lo_witness = context.var_to_witness(lo_var).unwrap();
+
+        let mut opcodes = context.finish(vec![x_witness.0], Vec::new()).take_opcodes();
+        let hint_index = opcodes
+            .iter()
+            .position(|opcode| matches!(opcode, Opcode::Brillig(_)))
+            .expect("the quotient should be computed by a brillig opcode");
+        opcodes.remove(hint_index);
+
+        let witness_map = WitnessMap::from(std::collections::BTreeMap::from([
+            (x_witness, x),
+            (hi_witness, hi),
+            (lo_witness, lo),
+        ]));
+        let mut acvm = ACVM::new(&NullBbSolver, &opcodes, witness_map);
+        acvm.solve()
+    }
+
+    fn modulus_limbs() -> (FieldElement, FieldElement) {
+        let modulus = FieldElement::modulus();
+        let hi = &modulus >> 128;
+        let lo: BigUint = &modulus - (&hi << 128);
+        (
+            FieldElement::from_be_bytes_reduce(&hi.to_bytes_be()),
+            FieldElement::from_be_bytes_reduce(&lo.to_bytes_be()),
+        )
+    }
+
+    #[test]
+    fn field_limbs_accepts_canonical_decomposition() {
+        let two_pow_128 = FieldElement::from(2_u128).pow(&FieldElement::from(128_u128));
+        let status = solve_field_limbs(two_pow_128, FieldElement::one(), FieldElement::zero());
+        assert_eq!(status, ACVMStatus::Solved);
+
+        // p - 1 is the largest field element, whose decomposition has the largest limbs allowed.
+        let (p_hi, p_lo) = modulus_limbs();
+        let status = solve_field_limbs(-FieldElement::one(), p_hi, p_lo - FieldElement::one());
+        assert_eq!(status, ACVMStatus::Solved);
+    }
+
+    #[test]
+    fn field_limbs_rejects_decomposition_of_value_plus_modulus() {
+        // 2^128 + p = (p_hi + 1) * 2^128 + p_lo satisfies the euclidean division constraints
+        // but isn't smaller than the modulus.
+        let two_pow_128 = FieldElement::from(2_u128).pow(&FieldElement::from(128_u128));
+        let (p_hi, p_lo) = modulus_limbs();
+        let status = solve_field_limbs(two_pow_128, p_hi + FieldElement::one(), p_lo);
+        assert!(matches!(status, ACVMStatus::Failure(_)), "forged limbs were accepted: {status:?}");
+    }
+}
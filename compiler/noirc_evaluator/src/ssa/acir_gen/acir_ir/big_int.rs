@@ -0,0 +1,34 @@
+use acvm::FieldElement;
+use num_bigint::BigUint;
+
+/// Assigns the identifiers of the big integers created by a circuit
+/// and keeps track of the moduli they are defined over.
+///
+/// Big integers and moduli are referred to by constant identifiers in ACIR,
+/// which lets us reject operations mixing different moduli at compile-time.
+#[derive(Debug, Default)]
+pub(crate) struct BigIntContext {
+    moduli: Vec<BigUint>,
+    num_big_integers: u32,
+}
+
+impl BigIntContext {
+    /// Returns the identifier of a new big integer.
+    pub(crate) fn new_big_int(&mut self) -> FieldElement {
+        let id = self.num_big_integers;
+        self.num_big_integers += 1;
+        FieldElement::from(id as u128)
+    }
+
+    /// Returns the identifier of `modulus`, registering it if it has not been seen before.
+    pub(crate) fn get_or_insert_modulus(&mut self, modulus: BigUint) -> FieldElement {
+        let id = match self.moduli.iter().position(|known| known == &modulus) {
+            Some(id) => id,
+            None => {
+                self.moduli.push(modulus);
+                self.moduli.len() - 1
+            }
+        };
+        FieldElement::from(id as u128)
+    }
+}
@@ -155,6 +155,7 @@ impl GeneratedAcir {
             BlackBoxFunc::Blake2s => {
                 BlackBoxFuncCall::Blake2s { inputs: inputs[0].clone(), outputs }
             }
+            BlackBoxFunc::Blake3 => BlackBoxFuncCall::Blake3 { inputs: inputs[0].clone(), outputs },
             BlackBoxFunc::HashToField128Security => BlackBoxFuncCall::HashToField128Security {
                 inputs: inputs[0].clone(),
                 output: outputs[0],
@@ -246,6 +247,64 @@ impl GeneratedAcir {
                     output_aggregation_object: outputs,
                 }
             }
+            BlackBoxFunc::Poseidon2Permutation => BlackBoxFuncCall::Poseidon2Permutation {
+                inputs: inputs[0].clone(),
+                outputs,
+                len: constants[0].to_u128() as u32,
+            },
+            BlackBoxFunc::EmbeddedCurveAdd => BlackBoxFuncCall::EmbeddedCurveAdd {
+                input1_x: inputs[0][0],
+                input1_y: inputs[1][0],
+                input2_x: inputs[2][0],
+                input2_y: inputs[3][0],
+                outputs: (outputs[0], outputs[1]),
+            },
+            BlackBoxFunc::Keccakf1600 => {
+                BlackBoxFuncCall::Keccakf1600 { inputs: inputs[0].clone(), outputs }
+            }
+            BlackBoxFunc::Sha256Compression => BlackBoxFuncCall::Sha256Compression {
+                inputs: inputs[0].clone(),
+                hash_values: inputs[1].clone(),
+                outputs,
+            },
+            BlackBoxFunc::EmbeddedCurveDouble => BlackBoxFuncCall::EmbeddedCurveDouble {
+                input_x: inputs[0][0],
+                input_y: inputs[1][0],
+                outputs: (outputs[0], outputs[1]),
+            },
+            BlackBoxFunc::BigIntAdd => BlackBoxFuncCall::BigIntAdd {
+                lhs: constants[0].to_u128() as u32,
+                rhs: constants[1].to_u128() as u32,
+                output: constants[2].to_u128() as u32,
+            },
+            BlackBoxFunc::BigIntSub => BlackBoxFuncCall::BigIntSub {
+                lhs: constants[0].to_u128() as u32,
+                rhs: constants[1].to_u128() as u32,
+                output: constants[2].to_u128() as u32,
+            },
+            BlackBoxFunc::BigIntMul => BlackBoxFuncCall::BigIntMul {
+                lhs: constants[0].to_u128() as u32,
+                rhs: constants[1].to_u128() as u32,
+                output: constants[2].to_u128() as u32,
+            },
+            BlackBoxFunc::BigIntDiv => BlackBoxFuncCall::BigIntDiv {
+                lhs: constants[0].to_u128() as u32,
+                rhs: constants[1].to_u128() as u32,
+                output: constants[2].to_u128() as u32,
+            },
+            BlackBoxFunc::BigIntFromLeBytes => BlackBoxFuncCall::BigIntFromLeBytes {
+                inputs: inputs[0].clone(),
+                modulus: vecmap(&constants[1..], |byte| byte.to_u128() as u8),
+                output: constants[0].to_u128() as u32,
+            },
+            BlackBoxFunc::BigIntToLeBytes => {
+                BlackBoxFuncCall::BigIntToLeBytes { input: constants[0].to_u128() as u32, outputs }
+            }
+            BlackBoxFunc::EcdsaSecp256k1Recover => {
+                unreachable!(
+                    "ICE: {func_name} has no ACIR opcode and should be rejected before ACIR gen"
+                )
+            }
         };
 
         self.push_opcode(AcirOpcode::BlackBoxFuncCall(black_box_func_call));
@@ -523,6 +582,40 @@ impl GeneratedAcir {
         }
     }
 
+    /// Calls the unconstrained function with index `brillig_function_index`, whose bytecode is `generated_brillig`.
+    ///
+    /// The bytecode itself is not added to the circuit but its debug information is attached to the call.
+    pub(crate) fn brillig_call(
+        &mut self,
+        predicate: Option<Expression>,
+        generated_brillig: &GeneratedBrillig,
+        inputs: Vec<BrilligInputs>,
+        outputs: Vec<BrilligOutputs>,
+        brillig_function_index: u32,
+    ) {
+        let opcode =
+            AcirOpcode::BrilligCall { id: brillig_function_index, inputs, outputs, predicate };
+        self.push_opcode(opcode);
+        for (brillig_index, call_stack) in &generated_brillig.locations {
+            self.locations.insert(
+                OpcodeLocation::Brillig {
+                    acir_index: self.opcodes.len() - 1,
+                    brillig_index: *brillig_index,
+                },
+                call_stack.clone(),
+            );
+        }
+        for (brillig_index, message) in &generated_brillig.assert_messages {
+            self.assert_messages.insert(
+                OpcodeLocation::Brillig {
+                    acir_index: self.opcodes.len() - 1,
+                    brillig_index: *brillig_index,
+                },
+                message.clone(),
+            );
+        }
+    }
+
     /// Generate gates and control bits witnesses which ensure that out_expr is a permutation of in_expr
     /// Add the control bits of the sorting network used to generate the constrains
     /// into the PermutationSort directive for solving in ACVM.
@@ -573,6 +666,7 @@ fn black_box_func_expected_input_size(name: BlackBoxFunc) -> Option<usize> {
         BlackBoxFunc::Keccak256
         | BlackBoxFunc::SHA256
         | BlackBoxFunc::Blake2s
+        | BlackBoxFunc::Blake3
         | BlackBoxFunc::PedersenCommitment
         | BlackBoxFunc::PedersenHash
         | BlackBoxFunc::HashToField128Security => None,
@@ -589,8 +683,28 @@ fn black_box_func_expected_input_size(name: BlackBoxFunc) -> Option<usize> {
         // Inputs for fixed based scalar multiplication
         // is the low and high limbs of the scalar
         BlackBoxFunc::FixedBaseScalarMul => Some(2),
+        // The permutation takes the 25 lanes of the Keccak state
+        BlackBoxFunc::Keccakf1600 => Some(25),
+        // The compression takes a block of 16 words and a state of 8 words
+        BlackBoxFunc::Sha256Compression => Some(24),
+        // Inputs for embedded curve addition are the coordinates of two points
+        BlackBoxFunc::EmbeddedCurveAdd => Some(4),
+        // Inputs for embedded curve doubling are the coordinates of a point
+        BlackBoxFunc::EmbeddedCurveDouble => Some(2),
         // Recursive aggregation has a variable number of inputs
         BlackBoxFunc::RecursiveAggregation => None,
+        // The permutation takes a state of any length
+        BlackBoxFunc::Poseidon2Permutation => None,
+        // Big integer operations only take the constant identifiers of their operands
+        BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv
+        | BlackBoxFunc::BigIntToLeBytes => Some(0),
+        // Big integers can be created from any number of bytes
+        BlackBoxFunc::BigIntFromLeBytes => None,
+        // Public key recovery is only available in Brillig
+        BlackBoxFunc::EcdsaSecp256k1Recover => None,
     }
 }
 
@@ -602,7 +716,10 @@ fn black_box_expected_output_size(name: BlackBoxFunc) -> Option<usize> {
         // or the operation.
         BlackBoxFunc::AND | BlackBoxFunc::XOR => Some(1),
         // 32 byte hash algorithms
-        BlackBoxFunc::Keccak256 | BlackBoxFunc::SHA256 | BlackBoxFunc::Blake2s => Some(32),
+        BlackBoxFunc::Keccak256
+        | BlackBoxFunc::SHA256
+        | BlackBoxFunc::Blake2s
+        | BlackBoxFunc::Blake3 => Some(32),
         // Hash to field returns a field element
         BlackBoxFunc::HashToField128Security => Some(1),
         // Pedersen commitment returns a point
@@ -619,8 +736,26 @@ fn black_box_expected_output_size(name: BlackBoxFunc) -> Option<usize> {
         // Output of fixed based scalar mul over the embedded curve
         // will be 2 field elements representing the point.
         BlackBoxFunc::FixedBaseScalarMul => Some(2),
+        // The permutation returns the 25 lanes of the permuted state
+        BlackBoxFunc::Keccakf1600 => Some(25),
+        // The compression returns the 8 words of the new state
+        BlackBoxFunc::Sha256Compression => Some(8),
+        // Embedded curve operations return the coordinates of the resulting point.
+        BlackBoxFunc::EmbeddedCurveAdd | BlackBoxFunc::EmbeddedCurveDouble => Some(2),
         // Recursive aggregation has a variable number of outputs
         BlackBoxFunc::RecursiveAggregation => None,
+        // The permutation returns a state of the same length as its input
+        BlackBoxFunc::Poseidon2Permutation => None,
+        // Big integers are kept by the solver rather than in witnesses
+        BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv
+        | BlackBoxFunc::BigIntFromLeBytes => Some(0),
+        // Big integers can be converted to any number of bytes
+        BlackBoxFunc::BigIntToLeBytes => None,
+        // Public key recovery is only available in Brillig
+        BlackBoxFunc::EcdsaSecp256k1Recover => None,
     }
 }
 
@@ -0,0 +1,174 @@
+//! This module decides, before a function is converted to ACIR, how the accesses to each of its
+//! arrays at indices which are not known at compile-time are lowered.
+//!
+//! Such accesses can either use memory opcodes, or be flattened into arithmetic which selects the
+//! accessed element among all the elements of the array. Memory is much cheaper for large arrays
+//! but has a fixed cost per block which flattening avoids for small arrays with few accesses.
+//! Backends implement memory blocks which are never written to as read-only tables, which are
+//! cheaper than read-write ones, so the cost of memory depends on whether the array is written to.
+//!
+//! An array and the arrays produced by setting its elements form a family whose accesses are all
+//! lowered the same way, as they share the values of the array.
+use fxhash::FxHashMap as HashMap;
+
+use crate::ssa::ir::{
+    function::Function,
+    instruction::{Instruction, InstructionId},
+    types::Type,
+    value::ValueId,
+};
+
+/// The estimated number of opcodes needed to initialize each element of a memory block.
+const MEMORY_INIT_COST: usize = 1;
+/// The estimated number of opcodes of a read from a memory block which is never written to.
+const READ_ONLY_MEMORY_ACCESS_COST: usize = 2;
+/// The estimated number of opcodes of a read or a write to a memory block which is written to.
+const READ_WRITE_MEMORY_ACCESS_COST: usize = 6;
+/// The estimated number of opcodes per element of a flattened read: an equality check with the
+/// index and a product with the element.
+const FLATTENED_READ_COST: usize = 3;
+/// The estimated number of opcodes per element of a flattened write: an equality check with the
+/// index, its product with the predicate and the selection of the new element.
+const FLATTENED_WRITE_COST: usize = 4;
+
+/// How the accesses at non-constant indices of an array are lowered to ACIR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ArrayLowering {
+    /// Select the accessed element with arithmetic over all the elements of the array.
+    Flatten,
+    /// Use a memory block which is never written to.
+    ReadOnlyMemory,
+    /// Use a memory block which is written to.
+    ReadWriteMemory,
+}
+
+/// The accesses made to an array family which are counted towards the cost of lowering it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ArrayAccesses {
+    /// Reads at an index which is not a constant.
+    pub(super) reads: usize,
+    /// All writes, as writes at a constant index also need memory when made under a predicate.
+    pub(super) writes: usize,
+    /// Writes which aren't the last use of their array, so a memory block would have to be copied.
+    pub(super) copies: usize,
+}
+
+impl ArrayAccesses {
+    /// Chooses the cheapest lowering for an array of `len` elements.
+    pub(super) fn choose_lowering(&self, len: usize) -> ArrayLowering {
+        let flattened_cost =
+            len * (FLATTENED_READ_COST * self.reads + FLATTENED_WRITE_COST * self.writes);
+        let init_cost = len * MEMORY_INIT_COST;
+
+        if self.writes == 0 {
+            let memory_cost = init_cost + READ_ONLY_MEMORY_ACCESS_COST * self.reads;
+            return if flattened_cost < memory_cost {
+                ArrayLowering::Flatten
+            } else {
+                ArrayLowering::ReadOnlyMemory
+            };
+        }
+
+        // Copying a block reads each element of the source block and initializes a new block.
+        let copy_cost = len * (READ_WRITE_MEMORY_ACCESS_COST + MEMORY_INIT_COST);
+        let memory_cost = init_cost
+            + READ_WRITE_MEMORY_ACCESS_COST * (self.reads + self.writes)
+            + copy_cost * self.copies;
+        if flattened_cost < memory_cost {
+            ArrayLowering::Flatten
+        } else {
+            ArrayLowering::ReadWriteMemory
+        }
+    }
+}
+
+/// The accesses made to each array family of a function.
+#[derive(Debug, Default)]
+pub(super) struct ArrayLoweringPlan {
+    /// Maps each array set by the function to the first array of its family.
+    families: HashMap<ValueId, ValueId>,
+    /// The accesses made to each family, indexed by the first array of the family.
+    accesses: HashMap<ValueId, ArrayAccesses>,
+}
+
+impl ArrayLoweringPlan {
+    pub(super) fn from_function(
+        function: &Function,
+        last_array_uses: &HashMap<ValueId, InstructionId>,
+    ) -> Self {
+        let dfg = &function.dfg;
+        let mut plan = ArrayLoweringPlan::default();
+
+        for block in function.reachable_blocks() {
+            for instruction_id in dfg[block].instructions() {
+                match &dfg[*instruction_id] {
+                    Instruction::ArrayGet { array, index } => {
+                        if dfg.get_numeric_constant(*index).is_none() {
+                            let family = plan.family(dfg.resolve(*array));
+                            plan.accesses.entry(family).or_default().reads += 1;
+                        }
+                    }
+                    Instruction::ArraySet { array, .. } => {
+                        let array = dfg.resolve(*array);
+                        let family = plan.family(array);
+                        let accesses = plan.accesses.entry(family).or_default();
+                        accesses.writes += 1;
+                        if last_array_uses.get(&array) != Some(instruction_id) {
+                            accesses.copies += 1;
+                        }
+
+                        let result = dfg.instruction_results(*instruction_id)[0];
+                        plan.families.insert(result, family);
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        plan
+    }
+
+    /// Chooses how the accesses to an array of the given type are lowered.
+    ///
+    /// Only arrays of numeric elements can be flattened, as each of their elements is a single variable.
+    pub(super) fn lowering(&self, array: ValueId, typ: &Type) -> ArrayLowering {
+        let accesses = self.accesses.get(&self.family(array)).copied().unwrap_or_default();
+        match typ {
+            Type::Array(element_types, len)
+                if *len > 0 && matches!(element_types.as_slice(), [Type::Numeric(_)]) =>
+            {
+                accesses.choose_lowering(*len)
+            }
+            _ if accesses.writes == 0 => ArrayLowering::ReadOnlyMemory,
+            _ => ArrayLowering::ReadWriteMemory,
+        }
+    }
+
+    fn family(&self, array: ValueId) -> ValueId {
+        self.families.get(&array).copied().unwrap_or(array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArrayAccesses, ArrayLowering};
+
+    #[test]
+    fn reads_use_read_only_memory() {
+        let accesses = ArrayAccesses { reads: 3, writes: 0, copies: 0 };
+        assert_eq!(accesses.choose_lowering(2), ArrayLowering::ReadOnlyMemory);
+        assert_eq!(accesses.choose_lowering(100), ArrayLowering::ReadOnlyMemory);
+    }
+
+    #[test]
+    fn small_arrays_with_copied_writes_are_flattened() {
+        let accesses = ArrayAccesses { reads: 1, writes: 1, copies: 1 };
+        assert_eq!(accesses.choose_lowering(2), ArrayLowering::Flatten);
+    }
+
+    #[test]
+    fn large_written_arrays_use_read_write_memory() {
+        let accesses = ArrayAccesses { reads: 10, writes: 10, copies: 0 };
+        assert_eq!(accesses.choose_lowering(64), ArrayLowering::ReadWriteMemory);
+    }
+}
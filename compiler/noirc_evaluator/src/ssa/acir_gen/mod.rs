@@ -1,16 +1,18 @@
 //! This file holds the pass to convert from Noir's SSA IR to ACIR.
 mod acir_ir;
+mod array_lowering;
 
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::ops::RangeInclusive;
 
 use self::acir_ir::acir_variable::{AcirContext, AcirType, AcirVar};
+use self::array_lowering::{ArrayLowering, ArrayLoweringPlan};
 use super::ir::dfg::CallStack;
 use super::{
     ir::{
         dfg::DataFlowGraph,
-        function::{Function, RuntimeType},
+        function::{Function, FunctionId, RuntimeType},
         instruction::{
             Binary, BinaryOp, Instruction, InstructionId, Intrinsic, TerminatorInstruction,
         },
@@ -34,9 +36,30 @@ use im::Vector;
 use iter_extended::{try_vecmap, vecmap};
 use noirc_frontend::Distinctness;
 
+/// The widest integer type which can be used in ACIR.
+///
+/// Operands of up to 128 bits can be split into two limbs whose partial products
+/// fit within the field modulus, see `AcirContext::wide_mul_var`.
+const MAX_INTEGER_BIT_SIZE: u32 = 128;
+
+/// State which is shared between the ACIR generation of each function of the program.
+#[derive(Default)]
+struct SharedContext {
+    /// Maps each non-inlined ACIR function to the `id` used by call opcodes to refer to it.
+    acir_function_ids: HashMap<FunctionId, u32>,
+
+    /// Maps each Brillig function called from ACIR to the `id` used by Brillig call opcodes to refer to it.
+    brillig_function_ids: HashMap<FunctionId, u32>,
+
+    /// The bytecode of each Brillig function called from ACIR, indexed by its `id`.
+    ///
+    /// Each function is only generated once, however many times it is called.
+    generated_brillig: Vec<GeneratedBrillig>,
+}
+
 /// Context struct for the acir generation pass.
 /// May be similar to the Evaluator struct in the current SSA IR.
-struct Context {
+struct Context<'a> {
     /// Maps SSA values to `AcirVar`.
     ///
     /// This is needed so that we only create a single
@@ -74,6 +97,12 @@ struct Context {
     /// Number of the next BlockId, it is used to construct
     /// a new BlockId
     max_block_id: u32,
+
+    /// How the accesses at non-constant indices of the function's arrays are lowered.
+    array_lowering: ArrayLoweringPlan,
+
+    /// State shared with the ACIR generation of the program's other functions.
+    shared_context: &'a mut SharedContext,
 }
 
 #[derive(Clone)]
@@ -125,39 +154,64 @@ impl AcirValue {
 }
 
 impl Ssa {
+    /// Converts the SSA into ACIR, returning the circuit for `main`, the circuits of any non-inlined
+    /// ACIR functions and the bytecode of the Brillig functions called from ACIR. The `id` of each
+    /// call opcode and Brillig call opcode is an index into the respective list.
     pub(crate) fn into_acir(
         self,
         brillig: Brillig,
         abi_distinctness: Distinctness,
         last_array_uses: &HashMap<ValueId, InstructionId>,
-    ) -> Result<GeneratedAcir, RuntimeError> {
-        let context = Context::new();
-        let mut generated_acir = context.convert_ssa(self, brillig, last_array_uses)?;
-
-        match abi_distinctness {
-            Distinctness::Distinct => {
-                // Create a witness for each return witness we have
-                // to guarantee that the return witnesses are distinct
-                let distinct_return_witness: Vec<_> = generated_acir
-                    .return_witnesses
-                    .clone()
-                    .into_iter()
-                    .map(|return_witness| {
-                        generated_acir
-                            .create_witness_for_expression(&Expression::from(return_witness))
-                    })
-                    .collect();
+    ) -> Result<(GeneratedAcir, Vec<GeneratedAcir>, Vec<GeneratedBrillig>), RuntimeError> {
+        let acir_function_ids: HashMap<FunctionId, u32> = self
+            .functions
+            .values()
+            .filter(|function| function.id() != self.main_id && function.is_folded_acir())
+            .enumerate()
+            .map(|(index, function)| (function.id(), index as u32))
+            .collect();
+
+        let mut shared_context = SharedContext { acir_function_ids, ..SharedContext::default() };
 
-                generated_acir.return_witnesses = distinct_return_witness;
-                Ok(generated_acir)
+        let mut functions = Vec::with_capacity(shared_context.acir_function_ids.len());
+        for function in self.functions.values() {
+            if shared_context.acir_function_ids.contains_key(&function.id()) {
+                let context = Context::new(&mut shared_context);
+                let generated_acir =
+                    context.convert_acir_function(function, &self, &brillig, last_array_uses)?;
+                // The callee's return values are read back in witness order so they must be distinct.
+                functions.push(make_return_witnesses_distinct(generated_acir));
             }
-            Distinctness::DuplicationAllowed => Ok(generated_acir),
         }
+
+        let context = Context::new(&mut shared_context);
+        let generated_acir = context.convert_ssa(self, brillig, last_array_uses)?;
+
+        let generated_acir = match abi_distinctness {
+            Distinctness::Distinct => make_return_witnesses_distinct(generated_acir),
+            Distinctness::DuplicationAllowed => generated_acir,
+        };
+        Ok((generated_acir, functions, shared_context.generated_brillig))
     }
 }
 
-impl Context {
-    fn new() -> Context {
+/// Creates a witness for each return witness to guarantee that the return witnesses are distinct.
+fn make_return_witnesses_distinct(mut generated_acir: GeneratedAcir) -> GeneratedAcir {
+    let distinct_return_witness: Vec<_> = generated_acir
+        .return_witnesses
+        .clone()
+        .into_iter()
+        .map(|return_witness| {
+            generated_acir.create_witness_for_expression(&Expression::from(return_witness))
+        })
+        .collect();
+
+    generated_acir.return_witnesses = distinct_return_witness;
+    generated_acir
+}
+
+impl<'a> Context<'a> {
+    fn new(shared_context: &'a mut SharedContext) -> Context<'a> {
         let mut acir_context = AcirContext::default();
         let current_side_effects_enabled_var = acir_context.add_constant(FieldElement::one());
 
@@ -169,6 +223,8 @@ impl Context {
             memory_blocks: HashMap::default(),
             internal_memory_blocks: HashMap::default(),
             max_block_id: 0,
+            array_lowering: ArrayLoweringPlan::default(),
+            shared_context,
         }
     }
 
@@ -181,24 +237,28 @@ impl Context {
     ) -> Result<GeneratedAcir, RuntimeError> {
         let main_func = ssa.main();
         match main_func.runtime() {
-            RuntimeType::Acir => self.convert_acir_main(main_func, &ssa, brillig, last_array_uses),
+            RuntimeType::Acir => {
+                self.convert_acir_function(main_func, &ssa, &brillig, last_array_uses)
+            }
             RuntimeType::Brillig => self.convert_brillig_main(main_func, brillig),
         }
     }
 
-    fn convert_acir_main(
+    /// Converts a flattened ACIR function, either `main` or a non-inlined function, into ACIR.
+    fn convert_acir_function(
         mut self,
-        main_func: &Function,
+        function: &Function,
         ssa: &Ssa,
-        brillig: Brillig,
+        brillig: &Brillig,
         last_array_uses: &HashMap<ValueId, InstructionId>,
     ) -> Result<GeneratedAcir, RuntimeError> {
-        let dfg = &main_func.dfg;
-        let entry_block = &dfg[main_func.entry_block()];
+        let dfg = &function.dfg;
+        self.array_lowering = ArrayLoweringPlan::from_function(function, last_array_uses);
+        let entry_block = &dfg[function.entry_block()];
         let input_witness = self.convert_ssa_block_params(entry_block.parameters(), dfg)?;
 
         for instruction_id in entry_block.instructions() {
-            self.convert_ssa_instruction(*instruction_id, dfg, ssa, &brillig, last_array_uses)?;
+            self.convert_ssa_instruction(*instruction_id, dfg, ssa, brillig, last_array_uses)?;
         }
 
         let warnings = self.convert_ssa_return(entry_block.unwrap_terminator(), dfg)?;
@@ -222,13 +282,15 @@ impl Context {
         let outputs: Vec<AcirType> =
             vecmap(main_func.returns(), |result_id| dfg.type_of_value(*result_id).into());
 
-        let code = self.gen_brillig_for(main_func, &brillig)?;
+        let id = self.brillig_function_id(main_func, &brillig)?;
+        let code = &self.shared_context.generated_brillig[id as usize];
 
         let output_values = self.acir_context.brillig(
             self.current_side_effects_enabled_var,
             code,
             inputs,
             outputs,
+            Some(id),
         )?;
         let output_vars: Vec<_> = output_values
             .iter()
@@ -435,17 +497,59 @@ impl Context {
                     Value::Function(id) => {
                         let func = &ssa.functions[id];
                         match func.runtime() {
-                            RuntimeType::Acir => unimplemented!(
-                                "expected an intrinsic/brillig call, but found {func:?}. All ACIR methods should be inlined"
-                            ),
+                            RuntimeType::Acir => {
+                                let Some(id) = self.shared_context.acir_function_ids.get(&func.id()).copied() else {
+                                    unimplemented!(
+                                        "expected an intrinsic/brillig call, but found {func:?}. Only #[fold] ACIR methods should not be inlined"
+                                    )
+                                };
+
+                                let inputs = vecmap(arguments, |arg| self.convert_value(*arg, dfg));
+                                let outputs: Vec<AcirType> = vecmap(result_ids, |result_id| {
+                                    dfg.type_of_value(*result_id).into()
+                                });
+
+                                let output_values = self.acir_context.call_acir_function(
+                                    id,
+                                    self.current_side_effects_enabled_var,
+                                    inputs,
+                                    outputs,
+                                )?;
+
+                                // Compiler sanity check
+                                assert_eq!(result_ids.len(), output_values.len(), "ICE: The number of ACIR call output values should match the result ids in SSA");
+
+                                for result in result_ids.iter().zip(output_values) {
+                                    if let AcirValue::Array(_) = &result.1 {
+                                        let array_id = dfg.resolve(*result.0);
+                                        let block_id = self.block_id(&array_id);
+                                        let array_typ = dfg.type_of_value(array_id);
+                                        self.initialize_array(
+                                            block_id,
+                                            array_typ.flattened_size(),
+                                            Some(result.1.clone()),
+                                        )?;
+                                    }
+                                    self.ssa_values.insert(*result.0, result.1);
+                                }
+                            }
                             RuntimeType::Brillig => {
                                 let inputs = vecmap(arguments, |arg| self.convert_value(*arg, dfg));
 
-                                let code = self.gen_brillig_for(func, brillig)?;
+                                let id = self.brillig_function_id(func, brillig)?;
+                                let code = &self.shared_context.generated_brillig[id as usize];
 
-                                let outputs: Vec<AcirType> = vecmap(result_ids, |result_id| dfg.type_of_value(*result_id).into());
+                                let outputs: Vec<AcirType> = vecmap(result_ids, |result_id| {
+                                    dfg.type_of_value(*result_id).into()
+                                });
 
-                                let output_values = self.acir_context.brillig(self.current_side_effects_enabled_var, code, inputs, outputs)?;
+                                let output_values = self.acir_context.brillig(
+                                    self.current_side_effects_enabled_var,
+                                    code,
+                                    inputs,
+                                    outputs,
+                                    Some(id),
+                                )?;
 
                                 // Compiler sanity check
                                 assert_eq!(result_ids.len(), output_values.len(), "ICE: The number of Brillig output values should match the result ids in SSA");
@@ -455,7 +559,11 @@ impl Context {
                                         let array_id = dfg.resolve(*result.0);
                                         let block_id = self.block_id(&array_id);
                                         let array_typ = dfg.type_of_value(array_id);
-                                        self.initialize_array(block_id, array_typ.flattened_size(), Some(result.1.clone()))?;
+                                        self.initialize_array(
+                                            block_id,
+                                            array_typ.flattened_size(),
+                                            Some(result.1.clone()),
+                                        )?;
                                     }
                                     self.ssa_values.insert(*result.0, result.1);
                                 }
@@ -534,6 +642,24 @@ impl Context {
         Ok(())
     }
 
+    /// Returns the `id` of the given Brillig function in the program's list of unconstrained functions,
+    /// generating its bytecode if this is the first time it is called from ACIR.
+    fn brillig_function_id(
+        &mut self,
+        func: &Function,
+        brillig: &Brillig,
+    ) -> Result<u32, InternalError> {
+        if let Some(id) = self.shared_context.brillig_function_ids.get(&func.id()) {
+            return Ok(*id);
+        }
+
+        let code = self.gen_brillig_for(func, brillig)?;
+        let id = self.shared_context.generated_brillig.len() as u32;
+        self.shared_context.generated_brillig.push(code);
+        self.shared_context.brillig_function_ids.insert(func.id(), id);
+        Ok(id)
+    }
+
     fn gen_brillig_for(
         &self,
         func: &Function,
@@ -590,6 +716,10 @@ impl Context {
             return Ok(());
         }
 
+        if self.handle_flattened_access(instruction, dfg, index, array, store_value)? {
+            return Ok(());
+        }
+
         let (new_index, new_value) =
             self.convert_array_operation_inputs(array, dfg, index, store_value)?;
 
@@ -683,6 +813,84 @@ impl Context {
         Ok(false)
     }
 
+    /// Handle accesses to arrays which are cheaper to flatten than to put in memory: the accessed
+    /// element is selected with arithmetic over all the elements of the array.
+    fn handle_flattened_access(
+        &mut self,
+        instruction: InstructionId,
+        dfg: &DataFlowGraph,
+        index: ValueId,
+        array: ValueId,
+        store_value: Option<ValueId>,
+    ) -> Result<bool, RuntimeError> {
+        let array_typ = dfg.type_of_value(array);
+        if self.array_lowering.lowering(dfg.resolve(array), &array_typ) != ArrayLowering::Flatten {
+            return Ok(false);
+        }
+        let AcirValue::Array(elements) = self.convert_value(array, dfg) else {
+            return Ok(false);
+        };
+        let elements = try_vecmap(elements, |element| match element {
+            AcirValue::Var(var, typ) => Ok((var, typ)),
+            _ => Err(InternalError::UnExpected {
+                expected: "a numeric array element".to_owned(),
+                found: format!("{element:?}"),
+                call_stack: self.acir_context.get_call_stack(),
+            }),
+        })?;
+
+        // Accesses under a false predicate are made at index 0, which is always in bounds.
+        let index_var = self.convert_numeric_value(index, dfg)?;
+        let index_var =
+            self.acir_context.mul_var(index_var, self.current_side_effects_enabled_var)?;
+
+        // Exactly one element must be the accessed one, which also checks that the index is in bounds.
+        let mut is_accessed = Vec::with_capacity(elements.len());
+        let mut accessed_count = self.acir_context.add_constant(FieldElement::zero());
+        for position in 0..elements.len() {
+            let position = self.acir_context.add_constant(FieldElement::from(position as u128));
+            let is_element = self.acir_context.eq_var(index_var, position)?;
+            accessed_count = self.acir_context.add_var(accessed_count, is_element)?;
+            is_accessed.push(is_element);
+        }
+        let one = self.acir_context.add_constant(FieldElement::one());
+        self.acir_context.assert_eq_var(
+            accessed_count,
+            one,
+            Some("Index out of bounds".to_owned()),
+        )?;
+
+        let value = match store_value {
+            Some(store_value) => {
+                // Each element becomes `element + is_written * (value - element)`.
+                let store_var = self.convert_numeric_value(store_value, dfg)?;
+                let mut new_elements = im::Vector::new();
+                for ((element, typ), is_element) in elements.into_iter().zip(is_accessed) {
+                    let is_written = self
+                        .acir_context
+                        .mul_var(is_element, self.current_side_effects_enabled_var)?;
+                    let change = self.acir_context.sub_var(store_var, element)?;
+                    let change = self.acir_context.mul_var(is_written, change)?;
+                    let new_element = self.acir_context.add_var(element, change)?;
+                    new_elements.push_back(AcirValue::Var(new_element, typ));
+                }
+                AcirValue::Array(new_elements)
+            }
+            None => {
+                let typ = elements[0].1.clone();
+                let mut result = self.acir_context.add_constant(FieldElement::zero());
+                for ((element, _), is_element) in elements.into_iter().zip(is_accessed) {
+                    let selected = self.acir_context.mul_var(element, is_element)?;
+                    result = self.acir_context.add_var(result, selected)?;
+                }
+                AcirValue::Var(result, typ)
+            }
+        };
+
+        self.define_result(dfg, instruction, value);
+        Ok(true)
+    }
+
     /// We need to properly setup the inputs for array operations in ACIR.
     /// From the original SSA values we compute the following AcirVars:
     /// - new_index is the index of the array. ACIR memory operations work with a flat memory, so we fully flattened the specified index
@@ -1341,20 +1549,20 @@ impl Context {
         let rhs = self.convert_numeric_value(binary.rhs, dfg)?;
 
         let binary_type = self.type_of_binary_operation(binary, dfg);
+        let mut is_wide_integer = false;
         match &binary_type {
             Type::Numeric(NumericType::Unsigned { bit_size })
             | Type::Numeric(NumericType::Signed { bit_size }) => {
-                // Conservative max bit size that is small enough such that two operands can be
-                // multiplied and still fit within the field modulus. This is necessary for the
-                // truncation technique: result % 2^bit_size to be valid.
-                let max_integer_bit_size = FieldElement::max_num_bits() / 2;
-                if *bit_size > max_integer_bit_size {
+                if *bit_size > MAX_INTEGER_BIT_SIZE {
                     return Err(RuntimeError::UnsupportedIntegerSize {
                         num_bits: *bit_size,
-                        max_num_bits: max_integer_bit_size,
+                        max_num_bits: MAX_INTEGER_BIT_SIZE,
                         call_stack: self.acir_context.get_call_stack(),
                     });
                 }
+                // The product of two integers wider than half the field does not fit within the
+                // field modulus, so it must be computed by splitting the operands into limbs.
+                is_wide_integer = *bit_size > FieldElement::max_num_bits() / 2;
             }
             _ => {}
         }
@@ -1365,6 +1573,7 @@ impl Context {
         match binary.operator {
             BinaryOp::Add => self.acir_context.add_var(lhs, rhs),
             BinaryOp::Sub => self.acir_context.sub_var(lhs, rhs),
+            BinaryOp::Mul if is_wide_integer => self.acir_context.wide_mul_var(lhs, rhs, bit_count),
             BinaryOp::Mul => self.acir_context.mul_var(lhs, rhs),
             BinaryOp::Div => self.acir_context.div_var(
                 lhs,
@@ -1483,18 +1692,23 @@ impl Context {
     ) -> Result<AcirVar, RuntimeError> {
         let mut var = self.convert_numeric_value(value_id, dfg)?;
         match &dfg[value_id] {
-            Value::Instruction { instruction, .. } => {
-                if matches!(
-                    &dfg[*instruction],
-                    Instruction::Binary(Binary { operator: BinaryOp::Sub, .. })
-                ) {
+            Value::Instruction { instruction, .. } => match &dfg[*instruction] {
+                Instruction::Binary(Binary { operator: BinaryOp::Sub, .. }) => {
                     // Subtractions must first have the integer modulus added before truncation can be
                     // applied. This is done in order to prevent underflow.
-                    let integer_modulus =
-                        self.acir_context.add_constant(FieldElement::from(2_u128.pow(bit_size)));
+                    let integer_modulus = self.acir_context.add_constant(
+                        FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)),
+                    );
                     var = self.acir_context.add_var(var, integer_modulus)?;
                 }
-            }
+                Instruction::Binary(Binary { operator: BinaryOp::Mul, .. })
+                    if bit_size > FieldElement::max_num_bits() / 2 =>
+                {
+                    // Products of wide integers are already reduced by `wide_mul_var`.
+                    return Ok(var);
+                }
+                _ => {}
+            },
             Value::Param { .. } => {
                 // Binary operations on params may have been entirely simplified if the operation
                 // results in the identity of the parameter
@@ -0,0 +1,394 @@
+use acvm::{acir::BlackBoxFunc, BlackBoxResolutionError, FieldElement};
+use iter_extended::{try_vecmap, vecmap};
+use num_bigint::BigUint;
+
+use crate::ssa::ir::{
+    instruction::{Endian, Intrinsic},
+    types::{NumericType, Type},
+};
+
+use super::{cast, InterpreterError, Value};
+
+/// Evaluates a call to an intrinsic function, mirroring how ACIR generation lowers each intrinsic
+/// and how the SSA simplifies calls to the slice intrinsics.
+///
+/// `result_types` are the types of the call's results, which are needed for intrinsics
+/// such as `from_field` or `to_le_bits` whose results cannot be inferred from their arguments.
+pub(super) fn call_intrinsic(
+    intrinsic: Intrinsic,
+    arguments: Vec<Value>,
+    result_types: &[Type],
+) -> Result<Vec<Value>, InterpreterError> {
+    match intrinsic {
+        Intrinsic::Sort => {
+            let (mut elements, typ) = expect_array(&arguments[0])?;
+            let mut keys = try_vecmap(&elements, |element| expect_numeric(element).map(|x| x.0))?;
+            keys.sort_by_key(|key| BigUint::from_bytes_be(&key.to_be_bytes()));
+            let element_type = element_numeric_type(&typ)?;
+            for (index, value) in keys.into_iter().enumerate() {
+                elements.set(index, Value::Numeric { value, typ: element_type });
+            }
+            Ok(vec![Value::Array { elements, typ }])
+        }
+        Intrinsic::ArrayLen => match arguments.as_slice() {
+            [Value::Array { typ: Type::Array(_, length), .. }] => {
+                Ok(vec![Value::field((*length as u128).into())])
+            }
+            // Slices are passed along with their length
+            [length @ Value::Numeric { .. }, Value::Array { .. }] => Ok(vec![length.clone()]),
+            _ => Err(invalid_arguments(intrinsic, &arguments)),
+        },
+        // Whether the arguments are constant is checked at compile time.
+        Intrinsic::AssertConstant => Ok(Vec::new()),
+        Intrinsic::SlicePushBack => {
+            let (length, mut elements, typ) = expect_slice(&arguments)?;
+            let new_elements = &arguments[2..];
+            // Merged slices may have a larger capacity than their length. As in the simplification
+            // of `slice_push_back`, a single element is written just after the slice's length.
+            let index = expect_index(&length)?;
+            if new_elements.len() == 1 && index < elements.len() {
+                elements.set(index, new_elements[0].clone());
+            }
+            elements.extend(new_elements.iter().cloned());
+            Ok(vec![increment(length), Value::Array { elements, typ }])
+        }
+        Intrinsic::SlicePushFront => {
+            let (length, elements, typ) = expect_slice(&arguments)?;
+            let mut new_elements: im::Vector<Value> = arguments[2..].iter().cloned().collect();
+            new_elements.append(elements);
+            Ok(vec![increment(length), Value::Array { elements: new_elements, typ }])
+        }
+        Intrinsic::SlicePopBack => {
+            let (length, elements, typ) = expect_slice(&arguments)?;
+            let element_count = result_types.len() - 2;
+            // The popped elements are read from before the slice's length, leaving its capacity
+            // unchanged, as in the simplification of `slice_pop_back`.
+            let end = expect_index(&length)? * element_count;
+            if end < element_count {
+                return Err(InterpreterError::EmptySlice);
+            }
+            if end > elements.len() {
+                let (index, length) = (end - 1, elements.len());
+                return Err(InterpreterError::IndexOutOfBounds { index, length });
+            }
+            let popped = elements.clone().slice(end - element_count..end);
+            let mut results = vec![decrement(length), Value::Array { elements, typ }];
+            results.extend(popped);
+            Ok(results)
+        }
+        Intrinsic::SlicePopFront => {
+            let (length, mut elements, typ) = expect_slice(&arguments)?;
+            let element_count = result_types.len() - 2;
+            if elements.len() < element_count {
+                return Err(InterpreterError::EmptySlice);
+            }
+            let remaining = elements.split_off(element_count);
+            let mut results: Vec<_> = elements.into_iter().collect();
+            results.push(decrement(length));
+            results.push(Value::Array { elements: remaining, typ });
+            Ok(results)
+        }
+        Intrinsic::SliceInsert => {
+            let (length, mut elements, typ) = expect_slice(&arguments)?;
+            let inserted = &arguments[3..];
+            let index = expect_index(&arguments[2])? * inserted.len();
+            // As in ACIR, inserting past the end of the slice leaves it unchanged as the index
+            // is checked against the slice's length before the call.
+            if index <= elements.len() {
+                for (offset, element) in inserted.iter().enumerate() {
+                    elements.insert(index + offset, element.clone());
+                }
+            }
+            Ok(vec![increment(length), Value::Array { elements, typ }])
+        }
+        Intrinsic::SliceRemove => {
+            let (length, mut elements, typ) = expect_slice(&arguments)?;
+            let element_count = result_types.len() - 2;
+            let index = expect_index(&arguments[2])? * element_count;
+            let removed = if index + element_count <= elements.len() {
+                vecmap(0..element_count, |_| elements.remove(index))
+            } else {
+                // A dummy value which is never used if the index was checked before the call.
+                vecmap(&result_types[2..], Value::zero_of_type)
+            };
+            let mut results = vec![decrement(length), Value::Array { elements, typ }];
+            results.extend(removed);
+            Ok(results)
+        }
+        // Strings are already represented as bytes
+        Intrinsic::StrAsBytes => Ok(vec![arguments[0].clone()]),
+        Intrinsic::ToBits(endian) => {
+            let (value, _) = expect_numeric(&arguments[0])?;
+            let (limb_count, _) = expect_numeric(&arguments[1])?;
+            to_radix(endian, value, 2, limb_count.to_u128() as u32, &result_types[1])
+        }
+        Intrinsic::ToRadix(endian) => {
+            let (value, _) = expect_numeric(&arguments[0])?;
+            let (radix, _) = expect_numeric(&arguments[1])?;
+            let (limb_count, _) = expect_numeric(&arguments[2])?;
+            let radix = radix.to_u128() as u32;
+            to_radix(endian, value, radix, limb_count.to_u128() as u32, &result_types[1])
+        }
+        Intrinsic::BlackBox(func) => call_black_box(func, &arguments),
+        Intrinsic::AsField => {
+            let (value, _) = expect_numeric(&arguments[0])?;
+            Ok(vec![Value::field(value)])
+        }
+        Intrinsic::FromField => {
+            let (value, _) = expect_numeric(&arguments[0])?;
+            let typ = match &result_types[0] {
+                Type::Numeric(typ) => *typ,
+                other => {
+                    return Err(InterpreterError::InvalidSsa(format!(
+                        "from_field cannot return a {other}"
+                    )))
+                }
+            };
+            Ok(vec![Value::Numeric { value: cast(value, typ), typ }])
+        }
+        Intrinsic::WrappingShiftLeft => Err(InterpreterError::Unsupported(intrinsic.to_string())),
+    }
+}
+
+fn call_black_box(func: BlackBoxFunc, arguments: &[Value]) -> Result<Vec<Value>, InterpreterError> {
+    match func {
+        BlackBoxFunc::SHA256 => hash(acvm::blackbox_solver::sha256, &expect_bytes(&arguments[0])?),
+        BlackBoxFunc::Blake2s => {
+            hash(acvm::blackbox_solver::blake2s, &expect_bytes(&arguments[0])?)
+        }
+        BlackBoxFunc::Blake3 => hash(acvm::blackbox_solver::blake3, &expect_bytes(&arguments[0])?),
+        BlackBoxFunc::Keccak256 => {
+            let input = expect_bytes(&arguments[0])?;
+            let num_bytes = expect_index(&arguments[1])?;
+            if num_bytes > input.len() {
+                let length = input.len();
+                return Err(InterpreterError::IndexOutOfBounds { index: num_bytes, length });
+            }
+            hash(acvm::blackbox_solver::keccak256, &input[..num_bytes])
+        }
+        BlackBoxFunc::HashToField128Security => {
+            let input = expect_bytes(&arguments[0])?;
+            let field = acvm::blackbox_solver::hash_to_field_128_security(&input)?;
+            Ok(vec![Value::field(field)])
+        }
+        BlackBoxFunc::EcdsaSecp256k1 | BlackBoxFunc::EcdsaSecp256r1 => {
+            let public_key_x = expect_bytes(&arguments[0])?;
+            let public_key_y = expect_bytes(&arguments[1])?;
+            let signature = expect_bytes(&arguments[2])?;
+            let hashed_message = expect_bytes(&arguments[3])?;
+            let (Ok(public_key_x), Ok(public_key_y), Ok(signature)) = (
+                <[u8; 32]>::try_from(public_key_x),
+                <[u8; 32]>::try_from(public_key_y),
+                <[u8; 64]>::try_from(signature),
+            ) else {
+                return Err(InterpreterError::InvalidSsa(format!(
+                    "incorrectly sized inputs to {func}"
+                )));
+            };
+            let verify = if func == BlackBoxFunc::EcdsaSecp256k1 {
+                acvm::blackbox_solver::ecdsa_secp256k1_verify
+            } else {
+                acvm::blackbox_solver::ecdsa_secp256r1_verify
+            };
+            let valid = verify(&hashed_message, &public_key_x, &public_key_y, &signature)?;
+            Ok(vec![Value::bool(valid)])
+        }
+        BlackBoxFunc::EcdsaSecp256k1Recover => {
+            let hashed_message = expect_bytes(&arguments[0])?;
+            let signature = expect_bytes(&arguments[1])?;
+            let (recovery_id, _) = expect_numeric(&arguments[2])?;
+            let (Ok(hashed_message), Ok(signature)) =
+                (<[u8; 32]>::try_from(hashed_message), <[u8; 64]>::try_from(signature))
+            else {
+                return Err(InterpreterError::InvalidSsa(format!(
+                    "incorrectly sized inputs to {func}"
+                )));
+            };
+            let (public_key_x, public_key_y) = acvm::blackbox_solver::ecdsa_secp256k1_recover(
+                &hashed_message,
+                &signature,
+                recovery_id.to_u128() as u8,
+            )?;
+            let public_key = vecmap([public_key_x, public_key_y].concat(), |byte| {
+                FieldElement::from(byte as u128)
+            });
+            Ok(vec![Value::array(public_key, NumericType::Unsigned { bit_size: 8 })])
+        }
+        BlackBoxFunc::Keccakf1600 => {
+            let lanes = try_vecmap(expect_fields(&arguments[0])?, |lane| {
+                lane.try_to_u64().ok_or_else(|| {
+                    InterpreterError::InvalidSsa(format!("{func} lane {lane} is not a u64"))
+                })
+            })?;
+            let Ok(state) = <[u64; 25]>::try_from(lanes) else {
+                return Err(InterpreterError::InvalidSsa(format!(
+                    "incorrectly sized inputs to {func}"
+                )));
+            };
+            let state = acvm::blackbox_solver::keccakf1600(state)?;
+            let state = vecmap(state, |lane| FieldElement::from(lane as u128));
+            Ok(vec![Value::array(state, NumericType::Unsigned { bit_size: 64 })])
+        }
+        BlackBoxFunc::Sha256Compression => {
+            let to_words = |value: &Value| {
+                try_vecmap(expect_fields(value)?, |word| {
+                    word.try_to_u64().and_then(|word| u32::try_from(word).ok()).ok_or_else(|| {
+                        InterpreterError::InvalidSsa(format!("{func} word {word} is not a u32"))
+                    })
+                })
+            };
+            let (Ok(block), Ok(state)) = (
+                <[u32; 16]>::try_from(to_words(&arguments[0])?),
+                <[u32; 8]>::try_from(to_words(&arguments[1])?),
+            ) else {
+                return Err(InterpreterError::InvalidSsa(format!(
+                    "incorrectly sized inputs to {func}"
+                )));
+            };
+            let state = acvm::blackbox_solver::sha256_compression(state, block)?;
+            let state = vecmap(state, |word| FieldElement::from(word as u128));
+            Ok(vec![Value::array(state, NumericType::Unsigned { bit_size: 32 })])
+        }
+        BlackBoxFunc::Poseidon2Permutation => {
+            let input = expect_fields(&arguments[0])?;
+            let (len, _) = expect_numeric(&arguments[1])?;
+            let state = acvm::blackbox_solver::poseidon2_permutation(&input, len.to_u128() as u32)?;
+            Ok(vec![Value::array(state, NumericType::NativeField)])
+        }
+        // These rely on an implementation in the backend.
+        BlackBoxFunc::SchnorrVerify
+        | BlackBoxFunc::PedersenCommitment
+        | BlackBoxFunc::PedersenHash
+        | BlackBoxFunc::FixedBaseScalarMul
+        | BlackBoxFunc::EmbeddedCurveAdd
+        | BlackBoxFunc::EmbeddedCurveDouble
+        | BlackBoxFunc::RecursiveAggregation
+        // Big integers are kept by the solver rather than in values.
+        | BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv
+        | BlackBoxFunc::BigIntFromLeBytes
+        | BlackBoxFunc::BigIntToLeBytes => {
+            Err(InterpreterError::Unsupported(format!("black box function {func}")))
+        }
+        BlackBoxFunc::AND | BlackBoxFunc::XOR | BlackBoxFunc::RANGE => {
+            Err(InterpreterError::InvalidSsa(format!(
+                "{func} should have been replaced during SSA gen"
+            )))
+        }
+    }
+}
+
+fn hash(
+    hash_function: fn(&[u8]) -> Result<[u8; 32], BlackBoxResolutionError>,
+    input: &[u8],
+) -> Result<Vec<Value>, InterpreterError> {
+    let hash = vecmap(hash_function(input)?, |byte| FieldElement::from(byte as u128));
+    Ok(vec![Value::array(hash, NumericType::Unsigned { bit_size: 8 })])
+}
+
+/// Decomposes `value` into `limb_count` limbs of the given radix, returned as a slice along with its length.
+fn to_radix(
+    endian: Endian,
+    value: FieldElement,
+    radix: u32,
+    limb_count: u32,
+    result_type: &Type,
+) -> Result<Vec<Value>, InterpreterError> {
+    let digits = BigUint::from_bytes_be(&value.to_be_bytes()).to_radix_le(radix);
+    if digits.len() > limb_count as usize && !value.is_zero() {
+        return Err(InterpreterError::RadixDecomposition { value, radix, limb_count });
+    }
+
+    let element_type = element_numeric_type(result_type)?;
+    let mut limbs = vecmap(0..limb_count as usize, |index| {
+        let digit = digits.get(index).copied().unwrap_or_default();
+        Value::Numeric { value: FieldElement::from(digit as u128), typ: element_type }
+    });
+    if endian == Endian::Big {
+        limbs.reverse();
+    }
+
+    let length = Value::field((limb_count as u128).into());
+    Ok(vec![length, Value::Array { elements: limbs.into(), typ: result_type.clone() }])
+}
+
+fn expect_numeric(value: &Value) -> Result<(FieldElement, NumericType), InterpreterError> {
+    match value {
+        Value::Numeric { value, typ } => Ok((*value, *typ)),
+        other => Err(InterpreterError::InvalidSsa(format!("expected a number, found {other}"))),
+    }
+}
+
+fn expect_index(value: &Value) -> Result<usize, InterpreterError> {
+    let (index, _) = expect_numeric(value)?;
+    index
+        .try_to_u64()
+        .map(|index| index as usize)
+        .ok_or_else(|| InterpreterError::InvalidSsa(format!("index {index} does not fit in a u64")))
+}
+
+fn expect_array(value: &Value) -> Result<(im::Vector<Value>, Type), InterpreterError> {
+    match value {
+        Value::Array { elements, typ } => Ok((elements.clone(), typ.clone())),
+        other => Err(InterpreterError::InvalidSsa(format!("expected an array, found {other}"))),
+    }
+}
+
+/// Slice intrinsics take the slice's length followed by its contents as their first two arguments.
+fn expect_slice(arguments: &[Value]) -> Result<(Value, im::Vector<Value>, Type), InterpreterError> {
+    let (elements, typ) = expect_array(&arguments[1])?;
+    expect_numeric(&arguments[0])?;
+    Ok((arguments[0].clone(), elements, typ))
+}
+
+/// Returns the bytes held in an array of `u8`s.
+fn expect_bytes(value: &Value) -> Result<Vec<u8>, InterpreterError> {
+    let (elements, _) = expect_array(value)?;
+    try_vecmap(&elements, |element| {
+        let (byte, _) = expect_numeric(element)?;
+        Ok(*byte.to_be_bytes().last().expect("fields are 32 bytes long"))
+    })
+}
+
+fn expect_fields(value: &Value) -> Result<Vec<FieldElement>, InterpreterError> {
+    let (elements, _) = expect_array(value)?;
+    try_vecmap(&elements, |element| Ok(expect_numeric(element)?.0))
+}
+
+fn element_numeric_type(typ: &Type) -> Result<NumericType, InterpreterError> {
+    match typ {
+        Type::Array(element_types, _) | Type::Slice(element_types) => {
+            match element_types.as_slice() {
+                [Type::Numeric(element_type)] => Ok(*element_type),
+                _ => {
+                    Err(InterpreterError::InvalidSsa(format!("expected numeric elements in {typ}")))
+                }
+            }
+        }
+        other => Err(InterpreterError::InvalidSsa(format!("expected an array, found {other}"))),
+    }
+}
+
+fn increment(length: Value) -> Value {
+    match length {
+        Value::Numeric { value, typ } => Value::Numeric { value: value + FieldElement::one(), typ },
+        other => other,
+    }
+}
+
+fn decrement(length: Value) -> Value {
+    match length {
+        Value::Numeric { value, typ } => Value::Numeric { value: value - FieldElement::one(), typ },
+        other => other,
+    }
+}
+
+fn invalid_arguments(intrinsic: Intrinsic, arguments: &[Value]) -> InterpreterError {
+    let arguments = vecmap(arguments, ToString::to_string);
+    InterpreterError::InvalidSsa(format!(
+        "invalid arguments to {intrinsic}: {}",
+        arguments.join(", ")
+    ))
+}
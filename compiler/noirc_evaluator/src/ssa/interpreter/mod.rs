@@ -0,0 +1,628 @@
+//! This module contains an interpreter which executes an [`Ssa`] program directly on concrete inputs.
+//!
+//! Its purpose is differential testing of the optimization passes: interpreting the SSA before
+//! and after a pass on the same inputs should give the same results, so any difference points to
+//! a miscompilation in that pass long before it shows up in the generated ACIR.
+//!
+//! The semantics follow those of the ACIR generated from the SSA. In particular, while side effects
+//! are disabled by an `enable_side_effects` instruction, operations which would otherwise fail
+//! (division by zero, out of bounds array accesses and calls into Brillig functions) are skipped
+//! and produce default values, as their results are discarded by the flattened control flow.
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use acvm::{BlackBoxResolutionError, FieldElement};
+use iter_extended::{try_vecmap, vecmap};
+use num_bigint::{BigInt, BigUint};
+use thiserror::Error;
+
+use super::{
+    ir::{
+        function::{Function, FunctionId, RuntimeType},
+        instruction::{Binary, BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        types::{NumericType, Type},
+        value::{Value as IrValue, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+pub(crate) use self::value::Value;
+
+mod intrinsics;
+mod value;
+
+#[derive(Debug, Error)]
+pub(crate) enum InterpreterError {
+    #[error("constraint {lhs} == {rhs} failed{}", format_message(.message))]
+    ConstraintFailed { lhs: String, rhs: String, message: Option<String> },
+    #[error("index {index} is out of bounds for an array of length {length}")]
+    IndexOutOfBounds { index: usize, length: usize },
+    #[error("attempted to divide by zero")]
+    DivisionByZero,
+    #[error("attempted to pop an element from an empty slice")]
+    EmptySlice,
+    #[error("attempted to load from a reference before storing a value in it")]
+    UninitializedReference,
+    #[error("{value} cannot be decomposed into {limb_count} limbs of radix {radix}")]
+    RadixDecomposition { value: FieldElement, radix: u32, limb_count: u32 },
+    #[error(transparent)]
+    BlackBox(#[from] BlackBoxResolutionError),
+    #[error("{0} is not supported by the SSA interpreter")]
+    Unsupported(String),
+    #[error("invalid SSA: {0}")]
+    InvalidSsa(String),
+}
+
+fn format_message(message: &Option<String>) -> String {
+    message.as_ref().map(|message| format!(": '{message}'")).unwrap_or_default()
+}
+
+impl Ssa {
+    /// Executes the program's `main` function on the given inputs, returning the values it returns.
+    pub(crate) fn interpret(&self, inputs: Vec<Value>) -> Result<Vec<Value>, InterpreterError> {
+        self.interpret_function(self.main_id, inputs)
+    }
+
+    /// Executes the function with the given id on the given arguments, returning the values it returns.
+    pub(crate) fn interpret_function(
+        &self,
+        id: FunctionId,
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        Interpreter { ssa: self }.call_function(id, arguments)
+    }
+}
+
+struct Interpreter<'ssa> {
+    ssa: &'ssa Ssa,
+}
+
+/// The state of a single function call.
+struct CallContext<'ssa> {
+    function: &'ssa Function,
+
+    /// The values assigned to the function's block parameters and instruction results so far.
+    values: HashMap<ValueId, Value>,
+
+    /// Set by `enable_side_effects` instructions.
+    side_effects_enabled: bool,
+}
+
+impl<'ssa> Interpreter<'ssa> {
+    fn call_function(
+        &self,
+        id: FunctionId,
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let function = self
+            .ssa
+            .functions
+            .get(&id)
+            .ok_or_else(|| InterpreterError::InvalidSsa(format!("{id} is not defined")))?;
+        let mut context =
+            CallContext { function, values: HashMap::new(), side_effects_enabled: true };
+
+        let mut block = function.entry_block();
+        let mut arguments = arguments;
+        loop {
+            let parameters = function.dfg.block_parameters(block);
+            if parameters.len() != arguments.len() {
+                return Err(InterpreterError::InvalidSsa(format!(
+                    "{block} in {id} expects {} arguments but was given {}",
+                    parameters.len(),
+                    arguments.len()
+                )));
+            }
+            context.values.extend(parameters.iter().copied().zip(arguments));
+
+            for instruction in function.dfg[block].instructions() {
+                self.execute_instruction(&mut context, *instruction)?;
+            }
+
+            let terminator = function.dfg[block].terminator().ok_or_else(|| {
+                InterpreterError::InvalidSsa(format!("{block} in {id} has no terminator"))
+            })?;
+            match terminator {
+                TerminatorInstruction::Jmp { destination, arguments: jmp_arguments, .. } => {
+                    arguments = try_vecmap(jmp_arguments, |argument| context.lookup(*argument))?;
+                    block = *destination;
+                }
+                TerminatorInstruction::JmpIf { condition, then_destination, else_destination } => {
+                    arguments = Vec::new();
+                    block = if context.lookup_bool(*condition)? {
+                        *then_destination
+                    } else {
+                        *else_destination
+                    };
+                }
+                TerminatorInstruction::Return { return_values, .. } => {
+                    return try_vecmap(return_values, |value| context.lookup(*value));
+                }
+            }
+        }
+    }
+
+    fn execute_instruction(
+        &self,
+        context: &mut CallContext<'ssa>,
+        instruction_id: InstructionId,
+    ) -> Result<(), InterpreterError> {
+        let function = context.function;
+        let dfg = &function.dfg;
+        let results = dfg.instruction_results(instruction_id);
+
+        let values = match &dfg[instruction_id] {
+            Instruction::Binary(binary) => vec![context.evaluate_binary(binary)?],
+            Instruction::Cast(value, typ) => {
+                let (value, _) = context.lookup_numeric(*value)?;
+                let typ = numeric_type(typ)?;
+                vec![Value::Numeric { value: cast(value, typ), typ }]
+            }
+            Instruction::Not(value) => {
+                let (value, typ) = context.lookup_numeric(*value)?;
+                let bit_size = integer_bit_size(typ, "not")?;
+                let max = (BigUint::from(1u32) << bit_size) - 1u32;
+                let value = to_field(max - truncate(value, bit_size));
+                vec![Value::Numeric { value, typ }]
+            }
+            Instruction::Truncate { value, bit_size, .. } => {
+                let (value, typ) = context.lookup_numeric(*value)?;
+                vec![Value::Numeric { value: to_field(truncate(value, *bit_size)), typ }]
+            }
+            Instruction::Constrain(lhs, rhs, message) => {
+                let lhs = context.lookup(*lhs)?;
+                let rhs = context.lookup(*rhs)?;
+                if !values_equal(&lhs, &rhs) {
+                    let (lhs, rhs, message) = (lhs.to_string(), rhs.to_string(), message.clone());
+                    return Err(InterpreterError::ConstraintFailed { lhs, rhs, message });
+                }
+                Vec::new()
+            }
+            Instruction::Call { func, arguments } => {
+                let arguments = try_vecmap(arguments, |argument| context.lookup(*argument))?;
+                let result_types = vecmap(results, |result| dfg.type_of_value(*result));
+                match context.lookup(*func)? {
+                    Value::Function(id) => {
+                        let is_brillig = self.ssa.functions.get(&id).map(|callee| callee.runtime())
+                            == Some(RuntimeType::Brillig);
+                        if is_brillig && !context.side_effects_enabled {
+                            // ACIR doesn't execute Brillig calls under a false predicate.
+                            vecmap(&result_types, Value::zero_of_type)
+                        } else {
+                            self.call_function(id, arguments)?
+                        }
+                    }
+                    Value::Intrinsic(intrinsic) => {
+                        intrinsics::call_intrinsic(intrinsic, arguments, &result_types)?
+                    }
+                    Value::ForeignFunction(name) => match name.as_str() {
+                        // Printing has no effect on the program's results.
+                        "println" => Vec::new(),
+                        _ => {
+                            return Err(InterpreterError::Unsupported(format!(
+                                "foreign function '{name}'"
+                            )))
+                        }
+                    },
+                    other => {
+                        return Err(InterpreterError::InvalidSsa(format!(
+                            "attempted to call {other}"
+                        )))
+                    }
+                }
+            }
+            Instruction::Allocate => vec![Value::Reference(Rc::new(RefCell::new(None)))],
+            Instruction::Load { address } => {
+                let reference = context.lookup_reference(*address)?;
+                let value = reference.borrow().clone();
+                vec![value.ok_or(InterpreterError::UninitializedReference)?]
+            }
+            Instruction::Store { address, value } => {
+                let reference = context.lookup_reference(*address)?;
+                let value = context.lookup(*value)?;
+                *reference.borrow_mut() = Some(value);
+                Vec::new()
+            }
+            Instruction::EnableSideEffects { condition } => {
+                context.side_effects_enabled = context.lookup_bool(*condition)?;
+                Vec::new()
+            }
+            Instruction::ArrayGet { array, index } => {
+                let (elements, _) = context.lookup_array(*array)?;
+                let index = context.lookup_index(*index)?;
+                match elements.get(index) {
+                    Some(element) => vec![element.clone()],
+                    None if context.side_effects_enabled => {
+                        let length = elements.len();
+                        return Err(InterpreterError::IndexOutOfBounds { index, length });
+                    }
+                    None => vec![Value::zero_of_type(&dfg.type_of_value(results[0]))],
+                }
+            }
+            Instruction::ArraySet { array, index, value } => {
+                let (mut elements, typ) = context.lookup_array(*array)?;
+                let index = context.lookup_index(*index)?;
+                let value = context.lookup(*value)?;
+                // Under a false predicate ACIR writes the existing element back in place.
+                if context.side_effects_enabled {
+                    if index >= elements.len() {
+                        let length = elements.len();
+                        return Err(InterpreterError::IndexOutOfBounds { index, length });
+                    }
+                    elements.set(index, value);
+                }
+                vec![Value::Array { elements, typ }]
+            }
+        };
+
+        if values.len() != results.len() {
+            return Err(InterpreterError::InvalidSsa(format!(
+                "{:?} produced {} values but has {} results",
+                dfg[instruction_id],
+                values.len(),
+                results.len()
+            )));
+        }
+        context.values.extend(results.iter().copied().zip(values));
+        Ok(())
+    }
+}
+
+impl<'ssa> CallContext<'ssa> {
+    fn lookup(&self, value: ValueId) -> Result<Value, InterpreterError> {
+        let dfg = &self.function.dfg;
+        let value = dfg.resolve(value);
+        match &dfg[value] {
+            IrValue::NumericConstant { constant, typ } => {
+                Ok(Value::Numeric { value: *constant, typ: numeric_type(typ)? })
+            }
+            IrValue::Array { array, typ } => {
+                let elements: Result<_, _> =
+                    array.iter().map(|element| self.lookup(*element)).collect();
+                Ok(Value::Array { elements: elements?, typ: typ.clone() })
+            }
+            IrValue::Function(id) => Ok(Value::Function(*id)),
+            IrValue::Intrinsic(intrinsic) => Ok(Value::Intrinsic(*intrinsic)),
+            IrValue::ForeignFunction(name) => Ok(Value::ForeignFunction(name.clone())),
+            IrValue::Instruction { .. } | IrValue::Param { .. } => {
+                self.values.get(&value).cloned().ok_or_else(|| {
+                    InterpreterError::InvalidSsa(format!("{value} is used before being defined"))
+                })
+            }
+        }
+    }
+
+    fn lookup_numeric(
+        &self,
+        value: ValueId,
+    ) -> Result<(FieldElement, NumericType), InterpreterError> {
+        match self.lookup(value)? {
+            Value::Numeric { value, typ } => Ok((value, typ)),
+            other => Err(InterpreterError::InvalidSsa(format!("expected a number, found {other}"))),
+        }
+    }
+
+    fn lookup_bool(&self, value: ValueId) -> Result<bool, InterpreterError> {
+        Ok(!self.lookup_numeric(value)?.0.is_zero())
+    }
+
+    fn lookup_index(&self, value: ValueId) -> Result<usize, InterpreterError> {
+        let (index, _) = self.lookup_numeric(value)?;
+        index.try_to_u64().map(|index| index as usize).ok_or_else(|| {
+            InterpreterError::InvalidSsa(format!("array index {index} does not fit in a u64"))
+        })
+    }
+
+    fn lookup_array(&self, value: ValueId) -> Result<(im::Vector<Value>, Type), InterpreterError> {
+        match self.lookup(value)? {
+            Value::Array { elements, typ } => Ok((elements, typ)),
+            other => Err(InterpreterError::InvalidSsa(format!("expected an array, found {other}"))),
+        }
+    }
+
+    fn lookup_reference(
+        &self,
+        value: ValueId,
+    ) -> Result<Rc<RefCell<Option<Value>>>, InterpreterError> {
+        match self.lookup(value)? {
+            Value::Reference(reference) => Ok(reference),
+            other => {
+                Err(InterpreterError::InvalidSsa(format!("expected a reference, found {other}")))
+            }
+        }
+    }
+
+    fn evaluate_binary(&self, binary: &Binary) -> Result<Value, InterpreterError> {
+        let (lhs, typ) = self.lookup_numeric(binary.lhs)?;
+        let (rhs, _) = self.lookup_numeric(binary.rhs)?;
+        evaluate_binary(binary.operator, lhs, rhs, typ, self.side_effects_enabled)
+    }
+}
+
+/// Evaluates a binary operation on two numbers of the given type.
+///
+/// Integer arithmetic wraps around on overflow in the same way as constant folding does, which
+/// is equivalent to the truncation the SSA performs after each operation.
+fn evaluate_binary(
+    operator: BinaryOp,
+    lhs: FieldElement,
+    rhs: FieldElement,
+    typ: NumericType,
+    side_effects_enabled: bool,
+) -> Result<Value, InterpreterError> {
+    // The division is only checked by ACIR when side effects are enabled.
+    let divides_by_zero = |rhs_is_zero: bool| {
+        if !(rhs_is_zero && matches!(operator, BinaryOp::Div | BinaryOp::Mod)) {
+            Ok(None)
+        } else if side_effects_enabled {
+            Err(InterpreterError::DivisionByZero)
+        } else {
+            Ok(Some(Value::Numeric { value: FieldElement::zero(), typ }))
+        }
+    };
+
+    let bit_size = match typ {
+        NumericType::NativeField => {
+            if let Some(zero) = divides_by_zero(rhs.is_zero())? {
+                return Ok(zero);
+            }
+            let value = match operator {
+                BinaryOp::Add => lhs + rhs,
+                BinaryOp::Sub => lhs - rhs,
+                BinaryOp::Mul => lhs * rhs,
+                BinaryOp::Div => lhs / rhs,
+                BinaryOp::Eq => return Ok(Value::bool(lhs == rhs)),
+                BinaryOp::Lt => return Ok(Value::bool(lhs < rhs)),
+                BinaryOp::Mod | BinaryOp::And | BinaryOp::Or | BinaryOp::Xor => {
+                    return Err(InterpreterError::InvalidSsa(format!(
+                        "{operator} is not defined on fields"
+                    )))
+                }
+            };
+            return Ok(Value::field(value));
+        }
+        NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => bit_size,
+    };
+
+    let is_signed = matches!(typ, NumericType::Signed { .. });
+    let modulus = BigUint::from(1u32) << bit_size;
+    // Operands are not truncated to the bit size of the type: constants may be typed as fields and
+    // unsigned division and comparisons in ACIR operate on the full values.
+    let (full_lhs, full_rhs) = (to_biguint(lhs), to_biguint(rhs));
+    let lhs = truncate(lhs, bit_size);
+    let rhs = truncate(rhs, bit_size);
+    let rhs_is_zero =
+        if is_signed { rhs == BigUint::from(0u32) } else { full_rhs == BigUint::from(0u32) };
+    if let Some(zero) = divides_by_zero(rhs_is_zero)? {
+        return Ok(zero);
+    }
+    let value = match operator {
+        BinaryOp::Add => lhs + rhs,
+        BinaryOp::Sub => lhs + &modulus - rhs,
+        BinaryOp::Mul => lhs * rhs,
+        BinaryOp::Div if is_signed => {
+            from_signed(to_signed(&lhs, bit_size) / to_signed(&rhs, bit_size), bit_size)
+        }
+        BinaryOp::Mod if is_signed => {
+            from_signed(to_signed(&lhs, bit_size) % to_signed(&rhs, bit_size), bit_size)
+        }
+        BinaryOp::Div => full_lhs / full_rhs,
+        BinaryOp::Mod => full_lhs % full_rhs,
+        BinaryOp::Eq => return Ok(Value::bool(full_lhs == full_rhs)),
+        BinaryOp::Lt if is_signed => {
+            return Ok(Value::bool(to_signed(&lhs, bit_size) < to_signed(&rhs, bit_size)))
+        }
+        BinaryOp::Lt => return Ok(Value::bool(full_lhs < full_rhs)),
+        BinaryOp::And => lhs & rhs,
+        BinaryOp::Or => lhs | rhs,
+        BinaryOp::Xor => lhs ^ rhs,
+    };
+    Ok(Value::Numeric { value: to_field(value % modulus), typ })
+}
+
+/// Compares two values for a constraint. Numeric types are ignored as constants may be given
+/// a different type to the values they are compared against.
+fn values_equal(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        (Value::Numeric { value: lhs, .. }, Value::Numeric { value: rhs, .. }) => lhs == rhs,
+        (Value::Array { elements: lhs, .. }, Value::Array { elements: rhs, .. }) => {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| values_equal(lhs, rhs))
+        }
+        (lhs, rhs) => lhs == rhs,
+    }
+}
+
+/// Converts a number to the given numeric type, wrapping it to the type's bit size if it is an integer.
+pub(super) fn cast(value: FieldElement, typ: NumericType) -> FieldElement {
+    match typ {
+        NumericType::NativeField => value,
+        NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => {
+            to_field(truncate(value, bit_size))
+        }
+    }
+}
+
+fn numeric_type(typ: &Type) -> Result<NumericType, InterpreterError> {
+    match typ {
+        Type::Numeric(typ) => Ok(*typ),
+        other => {
+            Err(InterpreterError::InvalidSsa(format!("expected a numeric type, found {other}")))
+        }
+    }
+}
+
+fn integer_bit_size(typ: NumericType, operation: &str) -> Result<u32, InterpreterError> {
+    match typ {
+        NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => Ok(bit_size),
+        NumericType::NativeField => {
+            Err(InterpreterError::InvalidSsa(format!("{operation} is not defined on fields")))
+        }
+    }
+}
+
+fn truncate(value: FieldElement, bit_size: u32) -> BigUint {
+    to_biguint(value) % (BigUint::from(1u32) << bit_size)
+}
+
+fn to_biguint(value: FieldElement) -> BigUint {
+    BigUint::from_bytes_be(&value.to_be_bytes())
+}
+
+fn to_field(value: BigUint) -> FieldElement {
+    FieldElement::from_be_bytes_reduce(&value.to_bytes_be())
+}
+
+/// Interprets the `bit_size` bits of `value` as a two's complement integer.
+fn to_signed(value: &BigUint, bit_size: u32) -> BigInt {
+    let value = BigInt::from(value.clone());
+    if value.bit(u64::from(bit_size - 1)) {
+        value - (BigInt::from(1u32) << bit_size)
+    } else {
+        value
+    }
+}
+
+fn from_signed(value: BigInt, bit_size: u32) -> BigUint {
+    let modulus = BigInt::from(1u32) << bit_size;
+    let value = ((value % &modulus) + &modulus) % modulus;
+    value.to_biguint().expect("value is non-negative")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use acvm::FieldElement;
+
+    use super::{InterpreterError, Value};
+    use crate::ssa::{ir::types::NumericType, ssa_gen::Ssa};
+
+    fn interpret(src: &str, inputs: Vec<Value>) -> Result<Vec<Value>, InterpreterError> {
+        let ssa = Ssa::from_str(src).unwrap_or_else(|err| panic!("{err}"));
+        ssa.interpret(inputs)
+    }
+
+    fn u32(value: u128) -> Value {
+        Value::Numeric { value: value.into(), typ: NumericType::Unsigned { bit_size: 32 } }
+    }
+
+    #[test]
+    fn wraps_integer_arithmetic() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u8, v1: u8):
+    v2 = sub v0, v1
+    v3 = mul v2, u8 3
+    v4 = lt v0, v1
+    return v2, v3, v4
+}";
+        let u8 = |value: u128| Value::Numeric {
+            value: value.into(),
+            typ: NumericType::Unsigned { bit_size: 8 },
+        };
+        let results = interpret(src, vec![u8(1), u8(2)]).unwrap();
+        assert_eq!(results, vec![u8(255), u8(253), Value::bool(true)]);
+    }
+
+    #[test]
+    fn follows_control_flow_and_memory() {
+        // Sums the elements of the array in a loop, storing the running total in a reference.
+        let src = "
+acir fn main f0 {
+  b0(v0: [u32; 3]):
+    v1 = allocate
+    store u32 0 at v1
+    jmp b1(u32 0)
+  b1(v2: u32):
+    v3 = lt v2, u32 3
+    jmpif v3 then: b2, else: b3
+  b2():
+    v4 = array_get v0, index v2 -> u32
+    v5 = load v1 -> u32
+    v6 = add v5, v4
+    store v6 at v1
+    v7 = add v2, u32 1
+    jmp b1(v7)
+  b3():
+    v8 = load v1 -> u32
+    v9 = call f1(v8) -> u32
+    return v9
+}
+brillig fn double f1 {
+  b0(v0: u32):
+    v1 = mul v0, u32 2
+    return v1
+}";
+        let array = Value::array(
+            vec![3u128.into(), 4u128.into(), 5u128.into()],
+            NumericType::Unsigned { bit_size: 32 },
+        );
+        assert_eq!(interpret(src, vec![array]).unwrap(), vec![u32(24)]);
+    }
+
+    #[test]
+    fn skips_failing_operations_when_side_effects_are_disabled() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u1, v1: [Field; 2], v2: u32):
+    enable_side_effects v0
+    v3 = div u32 1, v2
+    v4 = array_get v1, index v2 -> Field
+    v5 = array_set v1, index u32 0, value Field 7
+    enable_side_effects u1 1
+    return v3, v4, v5
+}";
+        let array = Value::array(vec![1u128.into(), 2u128.into()], NumericType::NativeField);
+        let results = interpret(src, vec![Value::bool(false), array.clone(), u32(0)]).unwrap();
+        assert_eq!(results, vec![u32(0), Value::field(1u128.into()), array.clone()]);
+
+        let error = interpret(src, vec![Value::bool(true), array, u32(0)]).unwrap_err();
+        assert!(matches!(error, InterpreterError::DivisionByZero));
+    }
+
+    #[test]
+    fn reports_failed_constraints() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    constrain v0 == Field 1 'v0 must be one'
+    return
+}";
+        assert_eq!(interpret(src, vec![Value::field(FieldElement::one())]).unwrap(), vec![]);
+        let error = interpret(src, vec![Value::field(2u128.into())]).unwrap_err();
+        assert_eq!(error.to_string(), "constraint Field 2 == Field 1 failed: 'v0 must be one'");
+    }
+
+    #[test]
+    fn evaluates_intrinsics() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v3, v4 = call to_le_bits(v0, u32 4) -> Field, [u1; 4]
+    v5, v6 = call slice_push_back(Field 0, [u8] [], u8 9) -> Field, [u8]
+    v7 = call array_len(v5, v6) -> Field
+    return v4, v7
+}";
+        let results = interpret(src, vec![Value::field(6u128.into())]).unwrap();
+        let bits = Value::array(
+            vec![0u128.into(), 1u128.into(), 1u128.into(), 0u128.into()],
+            NumericType::Unsigned { bit_size: 1 },
+        );
+        assert_eq!(results, vec![bits, Value::field(FieldElement::one())]);
+    }
+
+    #[test]
+    fn uses_spare_slice_capacity() {
+        // A slice of length 1 with a capacity of 2, such as those produced by merging slices
+        let src = "
+acir fn main f0 {
+  b0():
+    v1, v2 = call slice_push_back(Field 1, [Field] [Field 7, Field 0], Field 9) -> Field, [Field]
+    v3 = array_get v2, index Field 1 -> Field
+    v4, v5, v6 = call slice_pop_back(v1, v2) -> Field, [Field], Field
+    return v3, v4, v6
+}";
+        let results = interpret(src, Vec::new()).unwrap();
+        let nine = Value::field(9u128.into());
+        assert_eq!(results, vec![nine.clone(), Value::field(FieldElement::one()), nine]);
+    }
+}
@@ -0,0 +1,89 @@
+use std::{cell::RefCell, rc::Rc};
+
+use acvm::FieldElement;
+use iter_extended::vecmap;
+
+use crate::ssa::ir::{
+    function::FunctionId,
+    instruction::Intrinsic,
+    types::{NumericType, Type},
+};
+
+/// A value computed while interpreting the SSA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value {
+    Numeric {
+        value: FieldElement,
+        typ: NumericType,
+    },
+
+    /// A reference to memory created by an `allocate` instruction.
+    /// The reference holds `None` until a value is first stored in it.
+    Reference(Rc<RefCell<Option<Value>>>),
+
+    /// An array or slice. As in the SSA, arrays of composite types hold their flattened elements.
+    Array {
+        elements: im::Vector<Value>,
+        typ: Type,
+    },
+
+    Function(FunctionId),
+    Intrinsic(Intrinsic),
+    ForeignFunction(String),
+}
+
+impl Value {
+    pub(crate) fn field(value: FieldElement) -> Value {
+        Value::Numeric { value, typ: NumericType::NativeField }
+    }
+
+    pub(crate) fn bool(value: bool) -> Value {
+        Value::Numeric { value: value.into(), typ: NumericType::Unsigned { bit_size: 1 } }
+    }
+
+    /// Creates an array of numeric values with the given element type.
+    pub(crate) fn array(elements: Vec<FieldElement>, element_type: NumericType) -> Value {
+        let typ = Type::Array(Rc::new(vec![Type::Numeric(element_type)]), elements.len());
+        let elements =
+            elements.into_iter().map(|value| Value::Numeric { value, typ: element_type });
+        Value::Array { elements: elements.collect(), typ }
+    }
+
+    /// Returns a default value of the given type: zero for numeric types and for each element of
+    /// an array, an empty slice or an unassigned reference.
+    ///
+    /// This is used for the results of operations which are skipped while side effects are disabled.
+    pub(crate) fn zero_of_type(typ: &Type) -> Value {
+        match typ {
+            Type::Numeric(typ) => Value::Numeric { value: FieldElement::zero(), typ: *typ },
+            Type::Array(element_types, length) => {
+                let elements = (0..*length)
+                    .flat_map(|_| vecmap(element_types.iter(), Value::zero_of_type))
+                    .collect();
+                Value::Array { elements, typ: typ.clone() }
+            }
+            Type::Slice(_) => Value::Array { elements: im::Vector::new(), typ: typ.clone() },
+            Type::Reference => Value::Reference(Rc::new(RefCell::new(None))),
+            Type::Function => unreachable!("ICE: functions have no default value"),
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Numeric { value, typ } => write!(f, "{typ} {value}"),
+            Value::Reference(reference) => match reference.borrow().as_ref() {
+                Some(value) => write!(f, "&{value}"),
+                None => write!(f, "&<unassigned>"),
+            },
+            Value::Array { elements, typ } => {
+                let elements = vecmap(elements, ToString::to_string);
+                write!(f, "{typ} [{}]", elements.join(", "))
+            }
+            Value::Function(id) => write!(f, "{id}"),
+            Value::Intrinsic(intrinsic) => write!(f, "{intrinsic}"),
+            Value::ForeignFunction(name) => write!(f, "{name}"),
+        }
+    }
+}
@@ -17,6 +17,20 @@ pub(crate) enum RuntimeType {
     Brillig,
 }
 
+/// How calls to a function are handled by the inliner.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub(crate) enum InlineType {
+    /// Calls are inlined into the caller.
+    #[default]
+    Inline,
+    /// The function is compiled into a separate circuit, which its callers call through a call opcode.
+    /// This is set by the `#[fold]` attribute.
+    Fold,
+    /// Calls are evaluated at compile time by the SSA interpreter and replaced with the values they return.
+    /// This is set by the `#[comptime]` attribute.
+    Comptime,
+}
+
 /// A function holds a list of instructions.
 /// These instructions are further grouped into Basic blocks
 ///
@@ -35,6 +49,8 @@ pub(crate) struct Function {
 
     runtime: RuntimeType,
 
+    inline_type: InlineType,
+
     /// The DataFlowGraph holds the majority of data pertaining to the function
     /// including its blocks, instructions, and values.
     pub(crate) dfg: DataFlowGraph,
@@ -47,7 +63,14 @@ impl Function {
     pub(crate) fn new(name: String, id: FunctionId) -> Self {
         let mut dfg = DataFlowGraph::default();
         let entry_block = dfg.make_block();
-        Self { name, id, entry_block, dfg, runtime: RuntimeType::Acir }
+        Self {
+            name,
+            id,
+            entry_block,
+            dfg,
+            runtime: RuntimeType::Acir,
+            inline_type: InlineType::default(),
+        }
     }
 
     /// The name of the function.
@@ -71,6 +94,28 @@ impl Function {
         self.runtime = runtime;
     }
 
+    /// Whether calls to the function are inlined into the caller.
+    pub(crate) fn inline_type(&self) -> InlineType {
+        self.inline_type
+    }
+
+    /// Set whether calls to the function are inlined into the caller.
+    pub(crate) fn set_inline_type(&mut self, inline_type: InlineType) {
+        self.inline_type = inline_type;
+    }
+
+    /// Returns true if this is an ACIR function which is compiled into its own circuit rather
+    /// than being inlined into its callers.
+    pub(crate) fn is_folded_acir(&self) -> bool {
+        self.runtime == RuntimeType::Acir && self.inline_type == InlineType::Fold
+    }
+
+    /// Returns true if calls to this function are evaluated at compile time rather than executed
+    /// by the program.
+    pub(crate) fn is_comptime(&self) -> bool {
+        self.inline_type == InlineType::Comptime
+    }
+
     /// Retrieves the entry block of a function.
     ///
     /// A function's entry block contains the instructions
@@ -438,9 +438,11 @@ impl Instruction {
             }
             Instruction::Truncate { value, bit_size, .. } => {
                 if let Some((numeric_constant, typ)) = dfg.get_numeric_constant_with_type(*value) {
-                    let integer_modulus = 2_u128.pow(*bit_size);
-                    let truncated = numeric_constant.to_u128() % integer_modulus;
-                    SimplifiedTo(dfg.make_constant(truncated.into(), typ))
+                    let integer_modulus = BigUint::from(2u128).pow(*bit_size);
+                    let constant = BigUint::from_bytes_be(&numeric_constant.to_be_bytes());
+                    let truncated = constant % integer_modulus;
+                    let truncated = FieldElement::from_be_bytes_reduce(&truncated.to_bytes_be());
+                    SimplifiedTo(dfg.make_constant(truncated, typ))
                 } else {
                     None
                 }
@@ -833,6 +835,9 @@ fn eval_constant_binary_op(
 }
 
 fn truncate(int: u128, bit_size: u32) -> u128 {
+    if bit_size >= 128 {
+        return int;
+    }
     let max = 2u128.pow(bit_size);
     int % max
 }
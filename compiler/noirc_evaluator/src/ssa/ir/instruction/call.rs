@@ -377,6 +377,7 @@ fn simplify_black_box_func(
     match bb_func {
         BlackBoxFunc::SHA256 => simplify_hash(dfg, arguments, acvm::blackbox_solver::sha256),
         BlackBoxFunc::Blake2s => simplify_hash(dfg, arguments, acvm::blackbox_solver::blake2s),
+        BlackBoxFunc::Blake3 => simplify_hash(dfg, arguments, acvm::blackbox_solver::blake3),
         BlackBoxFunc::Keccak256 => {
             match (dfg.get_array_constant(arguments[0]), dfg.get_numeric_constant(arguments[1])) {
                 (Some((input, _)), Some(num_bytes)) if array_is_constant(dfg, &input) => {
@@ -419,13 +420,109 @@ fn simplify_black_box_func(
         BlackBoxFunc::FixedBaseScalarMul
         | BlackBoxFunc::SchnorrVerify
         | BlackBoxFunc::PedersenCommitment
-        | BlackBoxFunc::PedersenHash => {
+        | BlackBoxFunc::PedersenHash
+        | BlackBoxFunc::EmbeddedCurveAdd
+        | BlackBoxFunc::EmbeddedCurveDouble => {
             // Currently unsolvable here as we rely on an implementation in the backend.
             SimplifyResult::None
         }
 
         BlackBoxFunc::RecursiveAggregation => SimplifyResult::None,
 
+        // Recovery can fail on invalid signatures, which must be reported when the Brillig code is executed.
+        BlackBoxFunc::EcdsaSecp256k1Recover => SimplifyResult::None,
+
+        BlackBoxFunc::BigIntAdd
+        | BlackBoxFunc::BigIntSub
+        | BlackBoxFunc::BigIntMul
+        | BlackBoxFunc::BigIntDiv
+        | BlackBoxFunc::BigIntFromLeBytes
+        | BlackBoxFunc::BigIntToLeBytes => {
+            // Big integers are only known to the solver, by their identifier.
+            SimplifyResult::None
+        }
+
+        BlackBoxFunc::Keccakf1600 => match dfg.get_array_constant(arguments[0]) {
+            Some((state, _)) if array_is_constant(dfg, &state) => {
+                let lanes = vecmap(state, |id| {
+                    dfg.get_numeric_constant(id)
+                        .expect("value id from array should point at constant")
+                        .try_to_u64()
+                });
+                // Lanes which don't fit in 64 bits are left to fail when the program is executed.
+                let Some(Ok(state)) =
+                    lanes.into_iter().collect::<Option<Vec<u64>>>().map(<[u64; 25]>::try_from)
+                else {
+                    return SimplifyResult::None;
+                };
+                let state = acvm::blackbox_solver::keccakf1600(state)
+                    .expect("Rust solvable black box function should not fail");
+                let state = vecmap(state, |lane| FieldElement::from(lane as u128));
+                SimplifyResult::SimplifiedTo(make_constant_array(dfg, state, Type::unsigned(64)))
+            }
+            _ => SimplifyResult::None,
+        },
+
+        BlackBoxFunc::Sha256Compression => {
+            match (dfg.get_array_constant(arguments[0]), dfg.get_array_constant(arguments[1])) {
+                (Some((block, _)), Some((state, _)))
+                    if array_is_constant(dfg, &block) && array_is_constant(dfg, &state) =>
+                {
+                    let to_words = |array: im::Vector<ValueId>| {
+                        array
+                            .into_iter()
+                            .map(|id| {
+                                dfg.get_numeric_constant(id)
+                                    .expect("value id from array should point at constant")
+                                    .try_to_u64()
+                                    .and_then(|word| u32::try_from(word).ok())
+                            })
+                            .collect::<Option<Vec<u32>>>()
+                    };
+                    // Words which don't fit in 32 bits are left to fail when the program is executed.
+                    let (Some(Ok(block)), Some(Ok(state))) = (
+                        to_words(block).map(<[u32; 16]>::try_from),
+                        to_words(state).map(<[u32; 8]>::try_from),
+                    ) else {
+                        return SimplifyResult::None;
+                    };
+                    let state = acvm::blackbox_solver::sha256_compression(state, block)
+                        .expect("Rust solvable black box function should not fail");
+                    let state = vecmap(state, |word| FieldElement::from(word as u128));
+                    SimplifyResult::SimplifiedTo(make_constant_array(
+                        dfg,
+                        state,
+                        Type::unsigned(32),
+                    ))
+                }
+                _ => SimplifyResult::None,
+            }
+        }
+
+        BlackBoxFunc::Poseidon2Permutation => {
+            match (dfg.get_array_constant(arguments[0]), dfg.get_numeric_constant(arguments[1])) {
+                (Some((state, _)), Some(state_length)) if array_is_constant(dfg, &state) => {
+                    let state = vecmap(state, |id| {
+                        dfg.get_numeric_constant(id)
+                            .expect("value id from array should point at constant")
+                    });
+                    // An invalid state length is left to fail when the program is executed.
+                    match acvm::blackbox_solver::poseidon2_permutation(
+                        &state,
+                        state_length.to_u128() as u32,
+                    ) {
+                        Ok(state) => SimplifyResult::SimplifiedTo(make_constant_array(
+                            dfg,
+                            state,
+                            Type::field(),
+                        )),
+                        Err(_) => SimplifyResult::None,
+                    }
+                }
+                _ => SimplifyResult::None,
+            }
+        }
+
         BlackBoxFunc::AND => {
             unreachable!("ICE: `BlackBoxFunc::AND` calls should be transformed into a `BinaryOp`")
         }
@@ -8,14 +8,20 @@ use iter_extended::vecmap;
 
 use super::{
     basic_block::BasicBlockId,
-    function::Function,
+    function::{Function, InlineType},
     instruction::{Instruction, InstructionId, TerminatorInstruction},
     value::ValueId,
 };
 
 /// Helper function for Function's Display impl to pretty-print the function with the given formatter.
 pub(crate) fn display_function(function: &Function, f: &mut Formatter) -> Result {
-    writeln!(f, "{} fn {} {} {{", function.runtime(), function.name(), function.id())?;
+    write!(f, "{}", function.runtime())?;
+    match function.inline_type() {
+        InlineType::Inline => (),
+        InlineType::Fold => write!(f, "(fold)")?,
+        InlineType::Comptime => write!(f, "(comptime)")?,
+    }
+    writeln!(f, " fn {} {} {{", function.name(), function.id())?;
     display_block_with_successors(function, function.entry_block(), &mut HashSet::new(), f)?;
     write!(f, "}}")
 }
@@ -68,13 +74,12 @@ fn value(function: &Function, id: ValueId) -> String {
         }
         Value::Function(id) => id.to_string(),
         Value::Intrinsic(intrinsic) => intrinsic.to_string(),
-        Value::Array { array, .. } => {
+        Value::Array { array, typ } => {
             let elements = vecmap(array, |element| value(function, *element));
-            format!("[{}]", elements.join(", "))
-        }
-        Value::Param { .. } | Value::Instruction { .. } | Value::ForeignFunction(_) => {
-            id.to_string()
+            format!("{typ} [{}]", elements.join(", "))
         }
+        Value::ForeignFunction(name) => name.clone(),
+        Value::Param { .. } | Value::Instruction { .. } => id.to_string(),
     }
 }
 
@@ -93,6 +98,16 @@ fn value_list(function: &Function, values: &[ValueId]) -> String {
     vecmap(values, |id| value(function, *id)).join(", ")
 }
 
+/// Display the types of the given instruction results, for instructions whose
+/// result types cannot be determined from their operands. E.g. ` -> Field, u32`
+fn result_types(function: &Function, results: &[ValueId]) -> String {
+    if results.is_empty() {
+        return String::new();
+    }
+    let types = vecmap(results, |id| function.dfg.type_of_value(*id).to_string());
+    format!(" -> {}", types.join(", "))
+}
+
 /// Display a terminator instruction
 pub(crate) fn display_terminator(
     function: &Function,
@@ -150,10 +165,13 @@ pub(crate) fn display_instruction(
             None => writeln!(f, "constrain {} == {}", show(*lhs), show(*rhs)),
         },
         Instruction::Call { func, arguments } => {
-            writeln!(f, "call {}({})", show(*func), value_list(function, arguments))
+            let types = result_types(function, results);
+            writeln!(f, "call {}({}){types}", show(*func), value_list(function, arguments))
         }
         Instruction::Allocate => writeln!(f, "allocate"),
-        Instruction::Load { address } => writeln!(f, "load {}", show(*address)),
+        Instruction::Load { address } => {
+            writeln!(f, "load {}{}", show(*address), result_types(function, results))
+        }
         Instruction::Store { address, value } => {
             writeln!(f, "store {} at {}", show(*value), show(*address))
         }
@@ -161,7 +179,8 @@ pub(crate) fn display_instruction(
             writeln!(f, "enable_side_effects {}", show(*condition))
         }
         Instruction::ArrayGet { array, index } => {
-            writeln!(f, "array_get {}, index {}", show(*array), show(*index))
+            let types = result_types(function, results);
+            writeln!(f, "array_get {}, index {}{types}", show(*array), show(*index))
         }
         Instruction::ArraySet { array, index, value } => {
             writeln!(
@@ -101,13 +101,13 @@ impl NumericType {
     pub(crate) fn value_is_within_limits(self, field: FieldElement) -> bool {
         match self {
             NumericType::Signed { bit_size } => {
-                let min = -(2i128.pow(bit_size - 1));
-                let max = 2u128.pow(bit_size - 1) - 1;
+                let min = i128::MIN >> (128 - bit_size);
+                let max = (i128::MAX >> (128 - bit_size)) as u128;
                 // Signed integers are odd since they will overflow the field value
                 field <= max.into() || field >= min.into()
             }
             NumericType::Unsigned { bit_size } => {
-                let max = 2u128.pow(bit_size) - 1;
+                let max = u128::MAX >> (128 - bit_size);
                 field <= max.into()
             }
             NumericType::NativeField => true,
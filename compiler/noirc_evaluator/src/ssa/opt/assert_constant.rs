@@ -23,6 +23,12 @@ impl Ssa {
     /// while loop unrolling only touches blocks with loops in them.
     pub(crate) fn evaluate_assert_constant(mut self) -> Result<Ssa, RuntimeError> {
         for function in self.functions.values_mut() {
+            // The arguments of compile-time functions are only known once their calls are evaluated,
+            // at which point the interpreter treats `assert_constant` as a no-op.
+            if function.is_comptime() {
+                continue;
+            }
+
             for block in function.reachable_blocks() {
                 // Unfortunately we can't just use instructions.retain(...) here since
                 // check_instruction can also return an error
@@ -0,0 +1,369 @@
+//! This module defines the compile-time evaluation pass, which replaces each call to a function
+//! marked `#[comptime]` with the values it returns, computed by the SSA interpreter.
+//!
+//! This lets programs compute lookup tables or round constants with ordinary Noir code without
+//! any of that computation ending up in the compiled program. The arguments of each call must be
+//! known at compile-time, which is why this pass runs after loop unrolling: calls made from
+//! the body of a loop only have constant arguments once the loop has been unrolled.
+//!
+//! Calls made from a branch depending on a runtime condition are evaluated as well, although the
+//! branch may never be taken. Their evaluation failing is reported as an error as the call can't
+//! be folded, even though the program would only fail if the branch is taken. Calls made from
+//! a loop of a Brillig function are reported as unsupported.
+//!
+//! Comptime functions are kept as entry points by the inliner, so once all of their calls have
+//! been evaluated they are removed from the program.
+use std::collections::HashSet;
+
+use iter_extended::vecmap;
+
+use crate::{
+    errors::RuntimeError,
+    ssa::{
+        interpreter::Value,
+        ir::{
+            dfg::DataFlowGraph,
+            dom::DominatorTree,
+            function::{Function, FunctionId},
+            instruction::{Instruction, InstructionId, TerminatorInstruction},
+            types::Type,
+            value::{Value as IrValue, ValueId},
+        },
+        ssa_gen::Ssa,
+    },
+};
+
+use super::unrolling::find_all_loops;
+
+impl Ssa {
+    /// Evaluates each call to a comptime function made from a function which is not itself
+    /// evaluated at compile-time, replacing the results of the call with constants.
+    ///
+    /// Returns an error if a call is made from a loop, if its arguments are not constants or if
+    /// evaluating it fails, for example because of a failing constraint.
+    pub(crate) fn evaluate_comptime_calls(mut self) -> Result<Ssa, RuntimeError> {
+        let comptime_functions: Vec<FunctionId> = self
+            .functions
+            .values()
+            .filter(|function| function.is_comptime() && function.id() != self.main_id)
+            .map(Function::id)
+            .collect();
+        if comptime_functions.is_empty() {
+            return Ok(self);
+        }
+
+        // The whole program is needed to evaluate a call, so all calls are evaluated before any
+        // function is modified.
+        let mut evaluated_calls = Vec::new();
+        for function in self.functions.values() {
+            if !function.is_comptime() {
+                for (instruction, results) in evaluate_calls_in_function(&self, function)? {
+                    evaluated_calls.push((function.id(), instruction, results));
+                }
+            }
+        }
+
+        for (function_id, instruction, results) in evaluated_calls {
+            let function = self.functions.get_mut(&function_id).expect("Caller should exist");
+            replace_call_with_constants(function, instruction, results)?;
+        }
+
+        for function_id in comptime_functions {
+            self.functions.remove(&function_id);
+        }
+        Ok(self)
+    }
+}
+
+/// Evaluates the calls to comptime functions in the reachable blocks of the given function,
+/// returning the values each call returns.
+///
+/// Calls must not be made from a loop. Calls made from blocks which don't dominate each returning
+/// block depend on a runtime condition, so their evaluation failing is reported separately.
+fn evaluate_calls_in_function(
+    ssa: &Ssa,
+    function: &Function,
+) -> Result<Vec<(InstructionId, Vec<Value>)>, RuntimeError> {
+    let dfg = &function.dfg;
+    let mut evaluated_calls = Vec::new();
+
+    let blocks_in_loops: HashSet<_> =
+        find_all_loops(function).yet_to_unroll.into_iter().flat_map(|loop_| loop_.blocks).collect();
+    let mut dom_tree = DominatorTree::with_function(function);
+    let reachable_blocks = function.reachable_blocks();
+    let returning_blocks: Vec<_> = reachable_blocks
+        .iter()
+        .copied()
+        .filter(|block| {
+            matches!(dfg[*block].terminator(), Some(TerminatorInstruction::Return { .. }))
+        })
+        .collect();
+
+    for block in reachable_blocks {
+        for instruction in dfg[block].instructions() {
+            let Instruction::Call { func, arguments } = &dfg[*instruction] else {
+                continue;
+            };
+            let IrValue::Function(callee) = &dfg[dfg.resolve(*func)] else {
+                continue;
+            };
+            if !ssa.functions.get(callee).map_or(false, Function::is_comptime) {
+                continue;
+            }
+
+            let call_stack = dfg.get_call_stack(*instruction);
+            if blocks_in_loops.contains(&block) {
+                return Err(RuntimeError::ComptimeCallInLoop { call_stack });
+            }
+
+            let arguments = arguments
+                .iter()
+                .map(|argument| constant_to_value(dfg, *argument))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| RuntimeError::ComptimeArgumentNotConstant {
+                    call_stack: call_stack.clone(),
+                })?;
+
+            let always_executed =
+                returning_blocks.iter().all(|returning| dom_tree.dominates(block, *returning));
+            let results = ssa.interpret_function(*callee, arguments).map_err(|error| {
+                let reason = error.to_string();
+                if always_executed {
+                    RuntimeError::ComptimeEvaluationFailed { reason, call_stack }
+                } else {
+                    RuntimeError::ComptimeCallInBranch { reason, call_stack }
+                }
+            })?;
+            evaluated_calls.push((*instruction, results));
+        }
+    }
+
+    Ok(evaluated_calls)
+}
+
+/// Replaces the results of the given call with the values it returned and removes the call.
+fn replace_call_with_constants(
+    function: &mut Function,
+    instruction: InstructionId,
+    values: Vec<Value>,
+) -> Result<(), RuntimeError> {
+    let dfg = &mut function.dfg;
+    let results = dfg.instruction_results(instruction).to_vec();
+
+    for (result, value) in results.into_iter().zip(values) {
+        let Some(constant) = value_to_constant(dfg, &value) else {
+            return Err(RuntimeError::ComptimeEvaluationFailed {
+                reason: format!("{value} cannot be used as a constant"),
+                call_stack: dfg.get_call_stack(instruction),
+            });
+        };
+        dfg.set_value_from_id(result, constant);
+    }
+
+    for block in function.reachable_blocks() {
+        function.dfg[block].instructions_mut().retain(|id| *id != instruction);
+    }
+    Ok(())
+}
+
+/// Converts a constant SSA value into the equivalent interpreter value, returning `None` if the
+/// value isn't known at compile-time.
+fn constant_to_value(dfg: &DataFlowGraph, value: ValueId) -> Option<Value> {
+    match &dfg[dfg.resolve(value)] {
+        IrValue::NumericConstant { constant, typ: Type::Numeric(typ) } => {
+            Some(Value::Numeric { value: *constant, typ: *typ })
+        }
+        IrValue::Array { array, typ } => {
+            let elements = array
+                .iter()
+                .map(|element| constant_to_value(dfg, *element))
+                .collect::<Option<_>>()?;
+            Some(Value::Array { elements, typ: typ.clone() })
+        }
+        IrValue::Function(id) => Some(Value::Function(*id)),
+        IrValue::Intrinsic(intrinsic) => Some(Value::Intrinsic(*intrinsic)),
+        IrValue::ForeignFunction(name) => Some(Value::ForeignFunction(name.clone())),
+        IrValue::NumericConstant { .. } | IrValue::Instruction { .. } | IrValue::Param { .. } => {
+            None
+        }
+    }
+}
+
+/// Converts a value returned by the interpreter into a constant in the given function, returning
+/// `None` for references, which have no constant representation.
+fn value_to_constant(dfg: &mut DataFlowGraph, value: &Value) -> Option<ValueId> {
+    match value {
+        Value::Numeric { value, typ } => Some(dfg.make_constant(*value, Type::Numeric(*typ))),
+        Value::Array { elements, typ } => {
+            let elements = vecmap(elements, |element| value_to_constant(dfg, element));
+            let elements = elements.into_iter().collect::<Option<_>>()?;
+            Some(dfg.make_array(elements, typ.clone()))
+        }
+        Value::Function(id) => Some(dfg.import_function(*id)),
+        Value::Intrinsic(intrinsic) => Some(dfg.import_intrinsic(*intrinsic)),
+        Value::ForeignFunction(name) => Some(dfg.import_foreign_function(name)),
+        Value::Reference(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::RuntimeError,
+        ssa::{parser::assert_normalized_ssa_equals, ssa_gen::Ssa},
+    };
+
+    #[test]
+    fn replaces_calls_with_their_results() {
+        // f1 builds a table of the first powers of its argument.
+        let src = "
+acir fn main f0 {
+  b0(v0: u32):
+    v1 = call f1(Field 3) -> [Field; 3]
+    v2 = array_get v1, index v0 -> Field
+    return v2
+}
+acir(comptime) fn powers f1 {
+  b0(v0: Field):
+    v1 = mul v0, v0
+    v2 = mul v1, v0
+    return [Field; 3] [v0, v1, v2]
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let ssa = ssa.evaluate_comptime_calls().unwrap();
+
+        let expected = "
+acir fn main f0 {
+  b0(v0: u32):
+    v2 = array_get [Field; 3] [Field 3, Field 9, Field 27], index v0 -> Field
+    return v2
+}";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn requires_constant_arguments() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v1 = call f1(v0) -> Field
+    return v1
+}
+acir(comptime) fn double f1 {
+  b0(v0: Field):
+    v1 = add v0, v0
+    return v1
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let error = ssa.evaluate_comptime_calls().unwrap_err();
+        assert!(matches!(error, RuntimeError::ComptimeArgumentNotConstant { .. }));
+    }
+
+    #[test]
+    fn replaces_calls_in_branches_with_their_results() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u1):
+    jmpif v0 then: b1, else: b2
+  b1():
+    v2 = call f1(Field 2) -> Field
+    jmp b3(v2)
+  b2():
+    jmp b3(Field 1)
+  b3(v1: Field):
+    return v1
+}
+acir(comptime) fn double f1 {
+  b0(v0: Field):
+    v1 = add v0, v0
+    return v1
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let ssa = ssa.evaluate_comptime_calls().unwrap();
+
+        let expected = "
+acir fn main f0 {
+  b0(v0: u1):
+    jmpif v0 then: b1, else: b2
+  b1():
+    jmp b3(Field 4)
+  b2():
+    jmp b3(Field 1)
+  b3(v1: Field):
+    return v1
+}";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn reports_failing_evaluations_in_branches() {
+        // The call would fail, but only if the branch is taken.
+        let src = "
+acir fn main f0 {
+  b0(v0: u1):
+    jmpif v0 then: b1, else: b2
+  b1():
+    v2 = call f1(Field 2) -> Field
+    jmp b3(v2)
+  b2():
+    jmp b3(Field 1)
+  b3(v1: Field):
+    return v1
+}
+acir(comptime) fn check f1 {
+  b0(v0: Field):
+    constrain v0 == Field 1 'expected one'
+    return v0
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let error = ssa.evaluate_comptime_calls().unwrap_err();
+        assert!(matches!(error, RuntimeError::ComptimeCallInBranch { .. }));
+    }
+
+    #[test]
+    fn rejects_calls_in_loops() {
+        let src = "
+brillig fn main f0 {
+  b0(v0: u32):
+    jmp b1(u32 0)
+  b1(v1: u32):
+    v2 = lt v1, v0
+    jmpif v2 then: b2, else: b3
+  b2():
+    v3 = call f1(Field 2) -> Field
+    v4 = add v1, u32 1
+    jmp b1(v4)
+  b3():
+    return
+}
+acir(comptime) fn double f1 {
+  b0(v0: Field):
+    v1 = add v0, v0
+    return v1
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let error = ssa.evaluate_comptime_calls().unwrap_err();
+        assert!(matches!(error, RuntimeError::ComptimeCallInLoop { .. }));
+    }
+
+    #[test]
+    fn reports_failing_evaluations() {
+        let src = "
+acir fn main f0 {
+  b0():
+    v1 = call f1(Field 2) -> Field
+    return v1
+}
+acir(comptime) fn check f1 {
+  b0(v0: Field):
+    constrain v0 == Field 1 'expected one'
+    return v0
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let error = ssa.evaluate_comptime_calls().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Could not evaluate call to a comptime function: constraint Field 2 == Field 1 failed: 'expected one'"
+        );
+    }
+}
@@ -188,6 +188,8 @@ mod test {
             types::Type,
             value::{Value, ValueId},
         },
+        parser::assert_normalized_ssa_equals,
+        ssa_gen::Ssa,
     };
 
     #[test]
@@ -333,4 +335,26 @@ mod test {
 
         assert_eq!(instruction, &Instruction::Cast(ValueId::test_new(0), Type::unsigned(32)));
     }
+
+    #[test]
+    fn folds_constant_operands() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v3 = add Field 2, Field 3
+    v4 = mul v0, v3
+    v5 = eq v4, v4
+    constrain v5 == u1 1
+    return v4
+}";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+acir fn main f0 {
+  b0(v0: Field):
+    v2 = mul v0, Field 5
+    return v2
+}";
+        assert_normalized_ssa_equals(ssa.fold_constants(), expected);
+    }
 }
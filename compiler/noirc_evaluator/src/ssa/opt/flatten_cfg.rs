@@ -157,14 +157,18 @@ pub(crate) mod value_merger;
 use value_merger::ValueMerger;
 
 impl Ssa {
-    /// Flattens the control flow graph of main such that the function is left with a
-    /// single block containing all instructions and no more control-flow.
+    /// Flattens the control flow graph of main and of any folded ACIR functions such that each
+    /// function is left with a single block containing all instructions and no more control-flow.
     ///
     /// This pass will modify any instructions with side effects in particular, often multiplying
     /// them by jump conditions to maintain correctness even when all branches of a jmpif are inlined.
     /// For more information, see the module-level comment at the top of this file.
     pub(crate) fn flatten_cfg(mut self) -> Ssa {
-        flatten_function_cfg(self.main_mut());
+        for function in self.functions.values_mut() {
+            if function.id() == self.main_id || function.is_folded_acir() {
+                flatten_function_cfg(function);
+            }
+        }
         self
     }
 }
@@ -94,11 +94,16 @@ struct PerFunctionContext<'function> {
 
 /// The entry point functions are each function we should inline into - and each function that
 /// should be left in the final program. This is usually just `main` but also includes any
-/// brillig functions used.
+/// brillig functions used, any ACIR functions marked to be folded and any functions evaluated
+/// at compile time, which are only removed once their calls have been evaluated.
 fn get_entry_point_functions(ssa: &Ssa) -> BTreeSet<FunctionId> {
     let functions = ssa.functions.iter();
     let mut entry_points = functions
-        .filter(|(_, function)| function.runtime() == RuntimeType::Brillig)
+        .filter(|(_, function)| {
+            function.runtime() == RuntimeType::Brillig
+                || function.is_folded_acir()
+                || function.is_comptime()
+        })
         .map(|(id, _)| *id)
         .collect::<BTreeSet<_>>();
 
@@ -114,7 +119,9 @@ impl InlineContext {
     /// that could not be inlined calling it.
     fn new(ssa: &Ssa, entry_point: FunctionId) -> InlineContext {
         let source = &ssa.functions[&entry_point];
-        let builder = FunctionBuilder::new(source.name().to_owned(), entry_point, source.runtime());
+        let mut builder =
+            FunctionBuilder::new(source.name().to_owned(), entry_point, source.runtime());
+        builder.current_function.set_inline_type(source.inline_type());
         Self { builder, recursion_level: 0, entry_point, call_stack: CallStack::new() }
     }
 
@@ -344,10 +351,17 @@ impl<'function> PerFunctionContext<'function> {
         for id in block.instructions() {
             match &self.source_function.dfg[*id] {
                 Instruction::Call { func, arguments } => match self.get_function(*func) {
-                    Some(function) => match ssa.functions[&function].runtime() {
-                        RuntimeType::Acir => self.inline_function(ssa, *id, function, arguments),
-                        RuntimeType::Brillig => self.push_instruction(*id),
-                    },
+                    Some(function) => {
+                        let callee = &ssa.functions[&function];
+                        match callee.runtime() {
+                            RuntimeType::Acir
+                                if !callee.is_folded_acir() && !callee.is_comptime() =>
+                            {
+                                self.inline_function(ssa, *id, function, arguments);
+                            }
+                            RuntimeType::Acir | RuntimeType::Brillig => self.push_instruction(*id),
+                        }
+                    }
                     None => self.push_instruction(*id),
                 },
                 _ => self.push_instruction(*id),
@@ -509,11 +523,12 @@ mod test {
         function_builder::FunctionBuilder,
         ir::{
             basic_block::BasicBlockId,
-            function::RuntimeType,
-            instruction::{BinaryOp, Intrinsic, TerminatorInstruction},
+            function::{InlineType, RuntimeType},
+            instruction::{BinaryOp, Instruction, Intrinsic, TerminatorInstruction},
             map::Id,
             types::Type,
         },
+        ssa_gen::Ssa,
     };
 
     #[test]
@@ -782,4 +797,34 @@ mod test {
         let main = ssa.main();
         assert_eq!(main.reachable_blocks().len(), 4);
     }
+
+    #[test]
+    fn does_not_inline_folded_functions() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v2 = call f1(v0) -> Field
+    v3 = call f1(v2) -> Field
+    return v3
+}
+acir(fold) fn double f1 {
+  b0(v0: Field):
+    v2 = mul v0, Field 2
+    return v2
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let ssa = ssa.inline_functions();
+
+        assert_eq!(ssa.functions.len(), 2);
+        let folded = ssa.functions.values().find(|function| function.id() != ssa.main_id).unwrap();
+        assert_eq!(folded.inline_type(), InlineType::Fold);
+
+        let main = ssa.main();
+        let calls = main.dfg[main.entry_block()]
+            .instructions()
+            .iter()
+            .filter(|instruction| matches!(main.dfg[**instruction], Instruction::Call { .. }))
+            .count();
+        assert_eq!(calls, 2);
+    }
 }
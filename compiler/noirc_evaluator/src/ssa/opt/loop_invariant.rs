@@ -0,0 +1,470 @@
+//! This file contains the loop invariant code motion and strength reduction pass for Brillig
+//! functions. ACIR functions have all their loops unrolled so this pass does not apply to them.
+//!
+//! For each loop, from the innermost outwards:
+//! 1. Any instruction within the loop which is pure, cannot fail, and whose operands are all
+//!    defined outside of the loop is moved into the loop's pre-header so that it is executed once
+//!    rather than on every iteration. Hoisting out of an inner loop's pre-header, which is itself
+//!    within the outer loop, lets an instruction move out of several loops at once.
+//! 2. Each multiplication of an induction variable by a constant, such as the `i * 2` used to
+//!    index an array of pairs, is replaced by a new induction variable which is incremented by
+//!    a constant alongside the original one.
+//!
+//! An induction variable is a parameter of the loop header which is incremented by a constant
+//! on each iteration, as is the case for the index of a `for` loop. Loops are only optimized if
+//! their header has a single predecessor outside of the loop, the pre-header.
+use std::collections::{HashMap, HashSet};
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::CallStack,
+        function::{Function, RuntimeType},
+        instruction::{Binary, BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        post_order::PostOrder,
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+use super::unrolling::{find_all_loops, Loop};
+
+impl Ssa {
+    /// Hoists loop invariant instructions out of loops and reduces multiplications of induction
+    /// variables to additions in each Brillig function.
+    pub(crate) fn loop_invariant_code_motion(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            if function.runtime() == RuntimeType::Brillig {
+                optimize_loops(function);
+            }
+        }
+        self
+    }
+}
+
+fn optimize_loops(function: &mut Function) {
+    let loops = find_all_loops(function);
+
+    // Neither hoisting nor strength reduction change the CFG, so the loops and this block order
+    // remain valid throughout. Visiting blocks in reverse post-order means each value is visited
+    // before its uses, so chains of invariant instructions are all hoisted in a single pass.
+    let mut reverse_post_order = PostOrder::with_function(function).into_vec();
+    reverse_post_order.reverse();
+
+    // Loops are sorted from smallest to largest so inner loops are optimized before outer loops.
+    for loop_ in &loops.yet_to_unroll {
+        let mut outside_predecessors =
+            loops.cfg.predecessors(loop_.header).filter(|block| !loop_.blocks.contains(block));
+        let (Some(pre_header), None) = (outside_predecessors.next(), outside_predecessors.next())
+        else {
+            continue;
+        };
+
+        let blocks: Vec<_> = reverse_post_order
+            .iter()
+            .copied()
+            .filter(|block| loop_.blocks.contains(block))
+            .collect();
+        hoist_loop_invariants(function, loop_, &blocks, pre_header);
+
+        let header_predecessors = loops.cfg.predecessors(loop_.header).len();
+        if header_predecessors == 2 {
+            reduce_strength(function, loop_, &blocks, pre_header);
+        }
+    }
+}
+
+/// Moves each loop invariant instruction in the given loop blocks to the end of the pre-header.
+fn hoist_loop_invariants(
+    function: &mut Function,
+    loop_: &Loop,
+    blocks: &[BasicBlockId],
+    pre_header: BasicBlockId,
+) {
+    let mut defined_in_loop = HashSet::new();
+    for block in &loop_.blocks {
+        defined_in_loop.extend(function.dfg.block_parameters(*block).iter().copied());
+        for instruction in function.dfg[*block].instructions() {
+            defined_in_loop.extend(function.dfg.instruction_results(*instruction).iter().copied());
+        }
+    }
+
+    for block in blocks {
+        let instructions = function.dfg[*block].take_instructions();
+        let mut remaining = Vec::with_capacity(instructions.len());
+
+        for instruction in instructions {
+            if can_hoist(function, instruction, &defined_in_loop) {
+                for result in function.dfg.instruction_results(instruction) {
+                    defined_in_loop.remove(result);
+                }
+                function.dfg[pre_header].insert_instruction(instruction);
+            } else {
+                remaining.push(instruction);
+            }
+        }
+
+        *function.dfg[*block].instructions_mut() = remaining;
+    }
+}
+
+/// An instruction can be hoisted if it is pure, it cannot fail even in iterations which would not
+/// have executed it, and each of its operands is defined outside of the loop.
+fn can_hoist(
+    function: &Function,
+    instruction: InstructionId,
+    defined_in_loop: &HashSet<ValueId>,
+) -> bool {
+    let dfg = &function.dfg;
+    let instruction = &dfg[instruction];
+
+    let cannot_fail = match instruction {
+        // Only divisions by non-zero constants are free of side effects.
+        Instruction::Binary(_) | Instruction::Cast(..) | Instruction::Not(_) => {
+            !instruction.has_side_effects(dfg)
+        }
+        // Brillig does not check array accesses, so only those known to be in bounds are hoisted.
+        Instruction::ArrayGet { array, index } | Instruction::ArraySet { array, index, .. } => {
+            match (dfg.try_get_array_length(*array), dfg.get_numeric_constant(*index)) {
+                (Some(length), Some(index)) => index.to_u128() < length as u128,
+                _ => false,
+            }
+        }
+        _ => false,
+    };
+
+    let mut is_invariant = true;
+    instruction.for_each_value(|value| {
+        is_invariant &= !defined_in_loop.contains(&dfg.resolve(value));
+    });
+
+    cannot_fail && instruction.is_pure(dfg) && is_invariant
+}
+
+/// Replaces each multiplication of an induction variable by a constant within the loop with a new
+/// induction variable holding the product, which is incremented by the product of the constant
+/// and the original induction variable's step.
+fn reduce_strength(
+    function: &mut Function,
+    loop_: &Loop,
+    blocks: &[BasicBlockId],
+    pre_header: BasicBlockId,
+) {
+    let Some(initial_values) = jmp_arguments(function, pre_header, loop_.header) else {
+        return;
+    };
+    let Some(next_values) = jmp_arguments(function, loop_.back_edge_start, loop_.header) else {
+        return;
+    };
+
+    // Maps each induction variable to its initial value and its constant step.
+    let mut induction_variables = HashMap::new();
+    let parameters = function.dfg.block_parameters(loop_.header);
+    for (position, parameter) in parameters.iter().enumerate() {
+        if let Some(step) = constant_increment(function, *parameter, next_values[position]) {
+            induction_variables.insert(*parameter, (initial_values[position], step));
+        }
+    }
+    if induction_variables.is_empty() {
+        return;
+    }
+
+    // Multiplications of the same induction variable by the same constant share a new induction variable.
+    let mut products: HashMap<(ValueId, ValueId), ValueId> = HashMap::new();
+    // The new induction variables along with their steps, which are incremented once all the loop's
+    // blocks have been rewritten as the back edge block may be one of them.
+    let mut increments = Vec::new();
+    for block in blocks {
+        let instructions = function.dfg[*block].take_instructions();
+        let mut remaining = Vec::with_capacity(instructions.len());
+
+        for instruction in instructions {
+            let Some((variable, constant, variable_is_lhs)) =
+                multiplication_by_constant(function, instruction, &induction_variables)
+            else {
+                remaining.push(instruction);
+                continue;
+            };
+
+            let product = *products.entry((variable, constant)).or_insert_with(|| {
+                let (initial_value, step) = induction_variables[&variable];
+                let result = function.dfg.instruction_results(instruction)[0];
+                let typ = function.dfg.type_of_value(result);
+
+                // Keep the operands in their original order so the products have the original type.
+                let multiply = |value| {
+                    if variable_is_lhs {
+                        Instruction::binary(BinaryOp::Mul, value, constant)
+                    } else {
+                        Instruction::binary(BinaryOp::Mul, constant, value)
+                    }
+                };
+                let dfg = &mut function.dfg;
+                let initial_product = dfg
+                    .insert_instruction_and_results(
+                        multiply(initial_value),
+                        pre_header,
+                        None,
+                        CallStack::new(),
+                    )
+                    .first();
+                let step_product = dfg
+                    .insert_instruction_and_results(
+                        multiply(step),
+                        pre_header,
+                        None,
+                        CallStack::new(),
+                    )
+                    .first();
+
+                let product = dfg.add_block_parameter(loop_.header, typ);
+                push_jmp_argument(function, pre_header, initial_product);
+                increments.push((product, step_product));
+                product
+            });
+
+            let result = function.dfg.instruction_results(instruction)[0];
+            function.dfg.set_value_from_id(result, product);
+        }
+
+        *function.dfg[*block].instructions_mut() = remaining;
+    }
+
+    for (product, step_product) in increments {
+        let increment = Instruction::binary(BinaryOp::Add, product, step_product);
+        let next_product = function
+            .dfg
+            .insert_instruction_and_results(
+                increment,
+                loop_.back_edge_start,
+                None,
+                CallStack::new(),
+            )
+            .first();
+        push_jmp_argument(function, loop_.back_edge_start, next_product);
+    }
+}
+
+/// Returns the arguments of the jmp from `block` to `destination`, if `block` terminates with one.
+fn jmp_arguments(
+    function: &Function,
+    block: BasicBlockId,
+    destination: BasicBlockId,
+) -> Option<Vec<ValueId>> {
+    match function.dfg[block].terminator() {
+        Some(TerminatorInstruction::Jmp { destination: target, arguments, .. })
+            if *target == destination =>
+        {
+            Some(arguments.clone())
+        }
+        _ => None,
+    }
+}
+
+fn push_jmp_argument(function: &mut Function, block: BasicBlockId, argument: ValueId) {
+    if let TerminatorInstruction::Jmp { arguments, .. } =
+        function.dfg[block].unwrap_terminator_mut()
+    {
+        arguments.push(argument);
+    }
+}
+
+/// Returns the constant step if `next_value` is `parameter` plus a constant.
+fn constant_increment(
+    function: &Function,
+    parameter: ValueId,
+    next_value: ValueId,
+) -> Option<ValueId> {
+    let dfg = &function.dfg;
+    let Value::Instruction { instruction, .. } = &dfg[dfg.resolve(next_value)] else {
+        return None;
+    };
+    let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Add }) = &dfg[*instruction]
+    else {
+        return None;
+    };
+    let (lhs, rhs) = (dfg.resolve(*lhs), dfg.resolve(*rhs));
+    if lhs == parameter && dfg.get_numeric_constant(rhs).is_some() {
+        Some(rhs)
+    } else if rhs == parameter && dfg.get_numeric_constant(lhs).is_some() {
+        Some(lhs)
+    } else {
+        None
+    }
+}
+
+/// Returns the induction variable and constant multiplied by the given instruction, if it is such
+/// a multiplication, along with whether the induction variable is its left hand side.
+fn multiplication_by_constant(
+    function: &Function,
+    instruction: InstructionId,
+    induction_variables: &HashMap<ValueId, (ValueId, ValueId)>,
+) -> Option<(ValueId, ValueId, bool)> {
+    let dfg = &function.dfg;
+    let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Mul }) = &dfg[instruction] else {
+        return None;
+    };
+    let (lhs, rhs) = (dfg.resolve(*lhs), dfg.resolve(*rhs));
+    if induction_variables.contains_key(&lhs) && dfg.get_numeric_constant(rhs).is_some() {
+        Some((lhs, rhs, true))
+    } else if induction_variables.contains_key(&rhs) && dfg.get_numeric_constant(lhs).is_some() {
+        Some((rhs, lhs, false))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::brillig_vm::brillig::Value;
+
+    use crate::{
+        brillig::brillig_gen::tests::execute_main,
+        ssa::{parser::assert_normalized_ssa_equals, ssa_gen::Ssa},
+    };
+
+    #[test]
+    fn hoists_invariant_instructions_out_of_loops() {
+        let src = "
+brillig fn main f0 {
+  b0(v0: u32, v1: u32, v2: [Field; 2]):
+    jmp b1(u32 0)
+  b1(v3: u32):
+    v4 = lt v3, u32 4
+    jmpif v4 then: b2, else: b3
+  b2():
+    v5 = mul v0, v1
+    v6 = add v5, u32 1
+    v7 = div v6, v1
+    v8 = array_get v2, index u32 1 -> Field
+    v9 = add v3, v6
+    v10 = add v3, u32 1
+    jmp b1(v10)
+  b3():
+    return
+}";
+        let ssa: Ssa = src.parse().unwrap();
+
+        // The division may fail if v1 is zero so it is left in the loop.
+        let expected = "
+brillig fn main f0 {
+  b0(v0: u32, v1: u32, v2: [Field; 2]):
+    v5 = mul v0, v1
+    v6 = add v5, u32 1
+    v8 = array_get v2, index u32 1 -> Field
+    jmp b1(u32 0)
+  b1(v3: u32):
+    v4 = lt v3, u32 4
+    jmpif v4 then: b2, else: b3
+  b2():
+    v7 = div v6, v1
+    v9 = add v3, v6
+    v10 = add v3, u32 1
+    jmp b1(v10)
+  b3():
+    return
+}";
+        assert_normalized_ssa_equals(ssa.loop_invariant_code_motion(), expected);
+    }
+
+    #[test]
+    fn reduces_executed_brillig_opcodes() {
+        // Sums `v0 * v1 + 1` over ten iterations.
+        let src = "
+brillig fn main f0 {
+  b0(v0: u32, v1: u32):
+    jmp b1(u32 0, u32 0)
+  b1(v2: u32, v3: u32):
+    v4 = lt v2, u32 10
+    jmpif v4 then: b2, else: b3
+  b2():
+    v5 = mul v0, v1
+    v6 = add v5, u32 1
+    v7 = add v3, v6
+    v8 = add v2, u32 1
+    jmp b1(v8, v7)
+  b3():
+    return v3
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        let inputs = vec![Value::from(3_usize), Value::from(5_usize)];
+
+        let before = execute_main(&ssa, inputs.clone());
+        let after = execute_main(&ssa.loop_invariant_code_motion(), inputs);
+        assert_eq!(before.return_values, vec![Value::from(160_usize)]);
+        assert_eq!(after.return_values, before.return_values);
+
+        // The opcodes of the two hoisted instructions run once rather than once per iteration.
+        assert!(
+            after.steps + 2 * 9 <= before.steps,
+            "expected at least 18 fewer opcodes to be executed than {}, but {} were",
+            before.steps,
+            after.steps
+        );
+    }
+
+    #[test]
+    fn reduces_multiplied_induction_variables_to_additions() {
+        let src = "
+brillig fn main f0 {
+  b0(v0: [Field; 8]):
+    jmp b1(u32 0, Field 0)
+  b1(v1: u32, v2: Field):
+    v3 = lt v1, u32 4
+    jmpif v3 then: b2, else: b3
+  b2():
+    v4 = mul v1, u32 2
+    v5 = array_get v0, index v4 -> Field
+    v6 = add v4, u32 1
+    v7 = array_get v0, index v6 -> Field
+    v8 = add v2, v5
+    v9 = add v8, v7
+    v10 = add v1, u32 1
+    jmp b1(v10, v9)
+  b3():
+    return v2
+}";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+brillig fn main f0 {
+  b0(v0: [Field; 8]):
+    jmp b1(u32 0, Field 0, u32 0)
+  b1(v1: u32, v2: Field, v11: u32):
+    v3 = lt v1, u32 4
+    jmpif v3 then: b2, else: b3
+  b2():
+    v5 = array_get v0, index v11 -> Field
+    v6 = add v11, u32 1
+    v7 = array_get v0, index v6 -> Field
+    v8 = add v2, v5
+    v9 = add v8, v7
+    v10 = add v1, u32 1
+    v12 = add v11, u32 2
+    jmp b1(v10, v9, v12)
+  b3():
+    return v2
+}";
+        assert_normalized_ssa_equals(ssa.loop_invariant_code_motion(), expected);
+    }
+
+    #[test]
+    fn leaves_acir_functions_unchanged() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u32, v1: u32):
+    jmp b1(u32 0)
+  b1(v2: u32):
+    v3 = lt v2, u32 4
+    jmpif v3 then: b2, else: b3
+  b2():
+    v4 = mul v0, v1
+    v5 = add v2, u32 1
+    jmp b1(v5)
+  b3():
+    return
+}";
+        let ssa: Ssa = src.parse().unwrap();
+        assert_normalized_ssa_equals(ssa.loop_invariant_code_motion(), src);
+    }
+}
@@ -5,11 +5,14 @@
 //! Generally, these passes are also expected to minimize the final amount of instructions.
 mod array_use;
 mod assert_constant;
+mod comptime;
 mod constant_folding;
 mod defunctionalize;
 mod die;
 pub(crate) mod flatten_cfg;
 mod inlining;
+mod loop_invariant;
 mod mem2reg;
 mod simplify_cfg;
 mod unrolling;
+mod value_numbering;
@@ -45,6 +45,12 @@ impl Ssa {
                 continue;
             }
 
+            // Compile-time functions are executed by the SSA interpreter, which follows their loops
+            // with the concrete arguments of each call, so their loop bounds need not be constant.
+            if function.is_comptime() {
+                continue;
+            }
+
             // This check is always true with the addition of the above guard, but I'm
             // keeping it in case the guard on brillig functions is ever removed.
             let abort_on_error = function.runtime() == RuntimeType::Acir;
@@ -54,32 +60,33 @@ impl Ssa {
     }
 }
 
-struct Loop {
+pub(super) struct Loop {
     /// The header block of a loop is the block which dominates all the
     /// other blocks in the loop.
-    header: BasicBlockId,
+    pub(super) header: BasicBlockId,
 
     /// The start of the back_edge n -> d is the block n at the end of
     /// the loop that jumps back to the header block d which restarts the loop.
-    back_edge_start: BasicBlockId,
+    pub(super) back_edge_start: BasicBlockId,
 
     /// All the blocks contained within the loop, including `header` and `back_edge_start`.
     pub(crate) blocks: HashSet<BasicBlockId>,
 }
 
-struct Loops {
+pub(super) struct Loops {
     /// The loops that failed to be unrolled so that we do not try to unroll them again.
     /// Each loop is identified by its header block id.
     failed_to_unroll: HashSet<BasicBlockId>,
 
-    yet_to_unroll: Vec<Loop>,
+    /// The loops found in the function, sorted from smallest to largest.
+    pub(super) yet_to_unroll: Vec<Loop>,
     modified_blocks: HashSet<BasicBlockId>,
-    cfg: ControlFlowGraph,
+    pub(super) cfg: ControlFlowGraph,
 }
 
 /// Find a loop in the program by finding a node that dominates any predecessor node.
 /// The edge where this happens will be the back-edge of the loop.
-fn find_all_loops(function: &Function) -> Loops {
+pub(super) fn find_all_loops(function: &Function) -> Loops {
     let cfg = ControlFlowGraph::with_function(function);
     let post_order = PostOrder::with_function(function);
     let mut dom_tree = DominatorTree::with_cfg_and_post_order(&cfg, &post_order);
@@ -0,0 +1,233 @@
+//! The global value numbering pass removes [pure][Instruction::is_pure()] instructions which
+//! duplicate an instruction in a dominating block, replacing their results with those of the
+//! earlier instruction. This extends the deduplication performed by the
+//! [`constant_folding`][super::constant_folding] pass, which only considers a single block.
+//!
+//! The pass works as follows:
+//! - Walk the dominator tree of each function from its entry block, keeping a table of the
+//!   instructions available in the current block along with their results.
+//! - On entering a block, each pure instruction is looked up in the table after resolving its
+//!   inputs. If a copy is found then the instruction is removed and its results replaced with the
+//!   results of the copy, otherwise the instruction is added to the table.
+//! - On leaving a block, the instructions it added to the table are removed again as they are
+//!   not available in blocks which the block does not dominate.
+//!
+//! The results of some instructions in a flattened ACIR function depend on the condition set by
+//! the most recent [`EnableSideEffects`][Instruction::EnableSideEffects] instruction, such as array
+//! accesses which are not checked while side effects are disabled. These are only deduplicated
+//! if they were executed under the same condition.
+use iter_extended::vecmap;
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId, dfg::DataFlowGraph, dom::DominatorTree, function::Function,
+        instruction::Instruction, types::Type, value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+use fxhash::FxHashMap as HashMap;
+
+impl Ssa {
+    /// Removes pure instructions which are duplicated in a dominating block.
+    ///
+    /// See [`value_numbering`][self] module for more information.
+    pub(crate) fn global_value_numbering(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            value_number(function);
+        }
+        self
+    }
+}
+
+/// A pure instruction with resolved inputs, which is equivalent to any other instruction with the
+/// same key and so may reuse its results.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct InstructionKey {
+    instruction: Instruction,
+
+    /// Instructions such as `from_field` calls may only differ in the types of their results.
+    result_types: Vec<Type>,
+
+    /// The current side effects condition, if the instruction's results depend on it and side
+    /// effects are not known to be enabled.
+    side_effects_condition: Option<ValueId>,
+}
+
+enum Visit {
+    /// Number the instructions in a block, given the side effects condition at its start.
+    Enter(BasicBlockId, Option<ValueId>),
+
+    /// Remove the instructions added to the table by a block once all the blocks it dominates have been visited.
+    Exit(Vec<InstructionKey>),
+}
+
+fn value_number(function: &mut Function) {
+    let dom_tree = DominatorTree::with_function(function);
+    let mut dominated_blocks: HashMap<BasicBlockId, Vec<BasicBlockId>> = HashMap::default();
+    for block in function.reachable_blocks() {
+        if let Some(dominator) = dom_tree.immediate_dominator(block) {
+            dominated_blocks.entry(dominator).or_default().push(block);
+        }
+    }
+
+    let mut available_instructions = HashMap::default();
+
+    // The dominator tree is walked with an explicit stack as it may be very deep before the
+    // CFG has been flattened.
+    let mut stack = vec![Visit::Enter(function.entry_block(), None)];
+    while let Some(visit) = stack.pop() {
+        match visit {
+            Visit::Enter(block, side_effects_condition) => {
+                let (added_keys, side_effects_condition) = value_number_block(
+                    &mut function.dfg,
+                    block,
+                    side_effects_condition,
+                    &mut available_instructions,
+                );
+                stack.push(Visit::Exit(added_keys));
+
+                let dominated = dominated_blocks.get(&block).into_iter().flatten();
+                stack.extend(dominated.map(|block| Visit::Enter(*block, side_effects_condition)));
+            }
+            Visit::Exit(added_keys) => {
+                for key in added_keys {
+                    available_instructions.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+/// Removes each pure instruction in the block which is already available, returning the keys of
+/// the instructions it adds to the table and the side effects condition at the end of the block.
+fn value_number_block(
+    dfg: &mut DataFlowGraph,
+    block: BasicBlockId,
+    mut side_effects_condition: Option<ValueId>,
+    available_instructions: &mut HashMap<InstructionKey, Vec<ValueId>>,
+) -> (Vec<InstructionKey>, Option<ValueId>) {
+    let instructions = dfg[block].take_instructions();
+    let mut remaining_instructions = Vec::with_capacity(instructions.len());
+    let mut added_keys = Vec::new();
+
+    for id in instructions {
+        let instruction = dfg[id].map_values(|value| dfg.resolve(value));
+
+        if let Instruction::EnableSideEffects { condition } = &instruction {
+            let enabled =
+                dfg.get_numeric_constant(*condition).map_or(false, |condition| condition.is_one());
+            side_effects_condition = (!enabled).then_some(*condition);
+        }
+
+        if instruction.is_pure(dfg) {
+            let results = dfg.instruction_results(id).to_vec();
+            let key = InstructionKey {
+                result_types: vecmap(&results, |result| dfg.type_of_value(*result)),
+                side_effects_condition: side_effects_condition
+                    .filter(|_| depends_on_side_effects(&instruction, dfg)),
+                instruction,
+            };
+
+            if let Some(available_results) = available_instructions.get(&key) {
+                for (result, available_result) in results.iter().zip(available_results) {
+                    dfg.set_value_from_id(*result, *available_result);
+                }
+                continue;
+            }
+
+            available_instructions.insert(key.clone(), results);
+            added_keys.push(key);
+        }
+        remaining_instructions.push(id);
+    }
+
+    *dfg[block].instructions_mut() = remaining_instructions;
+    (added_keys, side_effects_condition)
+}
+
+/// Returns true if the ACIR generated for this instruction uses the side effects condition, such that
+/// its results may differ while side effects are disabled.
+fn depends_on_side_effects(instruction: &Instruction, dfg: &DataFlowGraph) -> bool {
+    match instruction {
+        Instruction::ArrayGet { .. } | Instruction::ArraySet { .. } | Instruction::Call { .. } => {
+            true
+        }
+        // Divisions are only checked for a zero divisor while side effects are enabled.
+        _ => instruction.has_side_effects(dfg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{parser::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn removes_instructions_duplicated_in_dominating_blocks() {
+        let src = "
+brillig fn main f0 {
+  b0(v0: u32, v1: u1):
+    v2 = add v0, u32 1
+    jmpif v1 then: b1, else: b2
+  b1():
+    v3 = add v0, u32 1
+    v4 = mul v3, u32 2
+    jmp b3(v4)
+  b2():
+    v5 = mul v2, u32 2
+    jmp b3(v5)
+  b3(v6: u32):
+    v7 = mul v2, u32 2
+    v8 = add v6, v7
+    return v8
+}";
+        let ssa: Ssa = src.parse().unwrap();
+
+        // The multiplications in b1 and b2 are not deduplicated as neither block dominates the other.
+        let expected = "
+brillig fn main f0 {
+  b0(v0: u32, v1: u1):
+    v2 = add v0, u32 1
+    jmpif v1 then: b1, else: b2
+  b1():
+    v4 = mul v2, u32 2
+    jmp b3(v4)
+  b2():
+    v5 = mul v2, u32 2
+    jmp b3(v5)
+  b3(v6: u32):
+    v7 = mul v2, u32 2
+    v8 = add v6, v7
+    return v8
+}";
+        assert_normalized_ssa_equals(ssa.global_value_numbering(), expected);
+    }
+
+    #[test]
+    fn respects_side_effects_conditions() {
+        let src = "
+acir fn main f0 {
+  b0(v0: [Field; 2], v1: u32, v2: u1):
+    enable_side_effects v2
+    v3 = array_get v0, index v1 -> Field
+    v4 = add v1, u32 1
+    enable_side_effects u1 1
+    v5 = array_get v0, index v1 -> Field
+    v6 = add v1, u32 1
+    v7 = array_get v0, index v1 -> Field
+    return v3, v4, v5, v6, v7
+}";
+        let ssa: Ssa = src.parse().unwrap();
+
+        let expected = "
+acir fn main f0 {
+  b0(v0: [Field; 2], v1: u32, v2: u1):
+    enable_side_effects v2
+    v3 = array_get v0, index v1 -> Field
+    v4 = add v1, u32 1
+    enable_side_effects u1 1
+    v5 = array_get v0, index v1 -> Field
+    return v3, v4, v5, v4, v5
+}";
+        assert_normalized_ssa_equals(ssa.global_value_numbering(), expected);
+    }
+}
@@ -0,0 +1,161 @@
+use acvm::FieldElement;
+
+use super::SsaParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    Ident(String),
+    Int(FieldElement),
+    Str(String),
+    LeftParen,
+    RightParen,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    Comma,
+    Colon,
+    Semicolon,
+    Assign,
+    Equal,
+    Arrow,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(ident) => write!(f, "{ident}"),
+            Token::Int(int) => write!(f, "{int}"),
+            Token::Str(string) => write!(f, "'{string}'"),
+            Token::LeftParen => write!(f, "("),
+            Token::RightParen => write!(f, ")"),
+            Token::LeftBracket => write!(f, "["),
+            Token::RightBracket => write!(f, "]"),
+            Token::LeftBrace => write!(f, "{{"),
+            Token::RightBrace => write!(f, "}}"),
+            Token::Comma => write!(f, ","),
+            Token::Colon => write!(f, ":"),
+            Token::Semicolon => write!(f, ";"),
+            Token::Assign => write!(f, "="),
+            Token::Equal => write!(f, "=="),
+            Token::Arrow => write!(f, "->"),
+        }
+    }
+}
+
+/// A token along with the (1-based) line it was found on.
+#[derive(Debug, Clone)]
+pub(super) struct SpannedToken {
+    pub(super) token: Token,
+    pub(super) line: usize,
+}
+
+/// Splits the printed form of the SSA into tokens.
+pub(super) fn lex(src: &str) -> Result<Vec<SpannedToken>, SsaParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = src.chars().peekable();
+    let mut line = 1;
+
+    while let Some(char) = chars.next() {
+        let token = match char {
+            '\n' => {
+                line += 1;
+                continue;
+            }
+            char if char.is_whitespace() => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semicolon,
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Equal,
+            '=' => Token::Assign,
+            '-' if chars.next_if_eq(&'>').is_some() => Token::Arrow,
+            '-' => match chars.next() {
+                Some(digit @ '0'..='9') => Token::Int(-lex_int(digit, &mut chars, line)?),
+                _ => return Err(SsaParseError::new(line, "expected a number after '-'")),
+            },
+            '\'' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(char) => string.push(char),
+                        None => return Err(SsaParseError::new(line, "unterminated string")),
+                    }
+                }
+                Token::Str(string)
+            }
+            '0'..='9' => Token::Int(lex_int(char, &mut chars, line)?),
+            char if char.is_ascii_alphabetic() || char == '_' => {
+                let mut ident = char.to_string();
+                while let Some(char) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                    ident.push(char);
+                }
+                Token::Ident(ident)
+            }
+            other => {
+                return Err(SsaParseError::new(line, format!("unexpected character '{other}'")))
+            }
+        };
+        tokens.push(SpannedToken { token, line });
+    }
+    Ok(tokens)
+}
+
+/// Lexes an integer in any of the forms used when displaying a [`FieldElement`]:
+/// decimal or hexadecimal numbers, powers of two such as `2⁶⁴` and multiples of these such as `2⁶⁴×3`.
+fn lex_int(
+    first_digit: char,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    line: usize,
+) -> Result<FieldElement, SsaParseError> {
+    let parse = |digits: &str| {
+        FieldElement::try_from_str(digits)
+            .ok_or_else(|| SsaParseError::new(line, format!("invalid number '{digits}'")))
+    };
+
+    let mut digits = first_digit.to_string();
+    while let Some(char) = chars.next_if(|c| c.is_ascii_alphanumeric()) {
+        digits.push(char);
+    }
+
+    let mut exponent = String::new();
+    while let Some(char) = chars.next_if(|c| superscript_digit(*c).is_some()) {
+        exponent.push(superscript_digit(char).unwrap());
+    }
+    if exponent.is_empty() {
+        return parse(&digits);
+    }
+
+    let mut value = parse(&digits)?.pow(&parse(&exponent)?);
+    if chars.next_if_eq(&'×').is_some() {
+        let mut multiplier = String::new();
+        while let Some(char) = chars.next_if(|c| c.is_ascii_digit()) {
+            multiplier.push(char);
+        }
+        value = value * parse(&multiplier)?;
+    }
+    Ok(value)
+}
+
+fn superscript_digit(char: char) -> Option<char> {
+    let digit = match char {
+        '⁰' => '0',
+        '¹' => '1',
+        '²' => '2',
+        '³' => '3',
+        '⁴' => '4',
+        '⁵' => '5',
+        '⁶' => '6',
+        '⁷' => '7',
+        '⁸' => '8',
+        '⁹' => '9',
+        _ => return None,
+    };
+    Some(digit)
+}
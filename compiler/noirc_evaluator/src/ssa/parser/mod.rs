@@ -0,0 +1,728 @@
+//! This module parses the textual form of the SSA IR produced by the [printer][super::ir::printer]
+//! back into an [`Ssa`], allowing optimization passes to be tested against snapshots of their
+//! input and expected output rather than building the IR by hand.
+//!
+//! Instructions are inserted exactly as written: no simplification is applied while parsing.
+//! Value ids in the source are only used to link uses to their definitions, so the parsed SSA
+//! may number its values differently. Use [`assert_normalized_ssa_equals`] to compare SSA against
+//! an expected snapshot regardless of how its values are numbered.
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+
+use acvm::FieldElement;
+use thiserror::Error;
+
+use super::{
+    ir::{
+        basic_block::BasicBlockId,
+        function::{Function, FunctionId, InlineType, RuntimeType},
+        instruction::{BinaryOp, Instruction, Intrinsic, TerminatorInstruction},
+        map::Id,
+        types::Type,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+use self::lexer::{lex, SpannedToken, Token};
+
+mod lexer;
+
+#[derive(Debug, Error)]
+#[error("line {line}: {message}")]
+pub(crate) struct SsaParseError {
+    line: usize,
+    message: String,
+}
+
+impl SsaParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self { line, message: message.into() }
+    }
+}
+
+impl FromStr for Ssa {
+    type Err = SsaParseError;
+
+    /// Parses SSA in the form displayed by [`Ssa`]'s `Display` implementation.
+    /// The first function is taken to be the program's `main` function.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { tokens: lex(src)?, position: 0 };
+        let mut functions = Vec::new();
+        while parser.peek().is_some() {
+            functions.push(parser.parse_function()?);
+        }
+        if functions.is_empty() {
+            return Err(SsaParseError::new(1, "expected at least one function"));
+        }
+        Ok(Ssa::new(functions))
+    }
+}
+
+/// Asserts that the given SSA matches the expected SSA source.
+///
+/// Both are printed after being parsed so that their values are numbered in the same way,
+/// meaning the expected source only needs to be consistent in its own value ids.
+pub(crate) fn assert_normalized_ssa_equals(ssa: Ssa, expected: &str) {
+    let normalize = |src: &str| {
+        let ssa = Ssa::from_str(src).unwrap_or_else(|err| panic!("{err} in:\n{src}"));
+        ssa.to_string()
+    };
+    let expected = normalize(expected);
+    let actual = normalize(&ssa.to_string());
+    assert_eq!(actual, expected, "SSA does not match the expected snapshot");
+}
+
+struct Parser {
+    tokens: Vec<SpannedToken>,
+    position: usize,
+}
+
+/// The names given to values and blocks in the function currently being parsed.
+struct FunctionContext {
+    function: Function,
+    values: HashMap<String, ValueId>,
+    blocks: HashMap<String, BasicBlockId>,
+    /// The line on which each block was first referenced, used to report blocks which are never defined.
+    undefined_blocks: HashMap<String, usize>,
+}
+
+impl FunctionContext {
+    /// Returns the block with the given label, creating it on first use.
+    fn block(&mut self, label: &str, line: usize) -> BasicBlockId {
+        if let Some(block) = self.blocks.get(label) {
+            return *block;
+        }
+        let block = self.function.dfg.make_block();
+        self.blocks.insert(label.to_string(), block);
+        self.undefined_blocks.insert(label.to_string(), line);
+        block
+    }
+
+    fn define_value(
+        &mut self,
+        name: String,
+        value: ValueId,
+        line: usize,
+    ) -> Result<(), SsaParseError> {
+        if self.values.insert(name.clone(), value).is_some() {
+            return Err(SsaParseError::new(
+                line,
+                format!("value '{name}' is defined more than once"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|token| &token.token)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.position + n).map(|token| &token.token)
+    }
+
+    /// The line of the next token, or of the last token once all have been consumed.
+    fn line(&self) -> usize {
+        let index = self.position.min(self.tokens.len().saturating_sub(1));
+        self.tokens.get(index).map_or(1, |token| token.line)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, SsaParseError> {
+        Err(SsaParseError::new(self.line(), message))
+    }
+
+    fn next(&mut self) -> Result<Token, SsaParseError> {
+        match self.tokens.get(self.position) {
+            Some(token) => {
+                self.position += 1;
+                Ok(token.token.clone())
+            }
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), SsaParseError> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => {
+                self.position -= 1;
+                self.error(format!("expected '{expected}', found '{token}'"))
+            }
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String, SsaParseError> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => {
+                self.position -= 1;
+                self.error(format!("expected an identifier, found '{token}'"))
+            }
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), SsaParseError> {
+        match self.next()? {
+            Token::Ident(ident) if ident == keyword => Ok(()),
+            token => {
+                self.position -= 1;
+                self.error(format!("expected '{keyword}', found '{token}'"))
+            }
+        }
+    }
+
+    fn expect_int(&mut self) -> Result<FieldElement, SsaParseError> {
+        match self.next()? {
+            Token::Int(int) => Ok(int),
+            token => {
+                self.position -= 1;
+                self.error(format!("expected a number, found '{token}'"))
+            }
+        }
+    }
+
+    fn expect_u32(&mut self) -> Result<u32, SsaParseError> {
+        let int = self.expect_int()?;
+        match int.try_to_u64().and_then(|int| u32::try_from(int).ok()) {
+            Some(int) => Ok(int),
+            None => self.error(format!("expected a 32-bit integer, found {int}")),
+        }
+    }
+
+    /// Parses `<runtime>[(fold|comptime)] fn <name> f<id> { <blocks> }`
+    fn parse_function(&mut self) -> Result<Function, SsaParseError> {
+        let runtime = match self.expect_ident()?.as_str() {
+            "acir" => RuntimeType::Acir,
+            "brillig" => RuntimeType::Brillig,
+            other => return self.error(format!("expected 'acir' or 'brillig', found '{other}'")),
+        };
+        let inline_type = if self.eat(&Token::LeftParen) {
+            let inline_type = match self.expect_ident()?.as_str() {
+                "fold" => InlineType::Fold,
+                "comptime" => InlineType::Comptime,
+                other => {
+                    return self.error(format!("expected 'fold' or 'comptime', found '{other}'"))
+                }
+            };
+            self.expect(Token::RightParen)?;
+            inline_type
+        } else {
+            InlineType::Inline
+        };
+        self.expect_keyword("fn")?;
+        let name = self.expect_ident()?;
+        let id = self.parse_function_id()?;
+        self.expect(Token::LeftBrace)?;
+
+        let mut function = Function::new(name, id);
+        function.set_runtime(runtime);
+        function.set_inline_type(inline_type);
+        let mut context = FunctionContext {
+            function,
+            values: HashMap::new(),
+            blocks: HashMap::new(),
+            undefined_blocks: HashMap::new(),
+        };
+
+        // The entry block is always displayed first.
+        let entry_label = self.expect_ident()?;
+        let entry_block = context.function.entry_block();
+        context.blocks.insert(entry_label.clone(), entry_block);
+        self.parse_block(&mut context, entry_block, &entry_label)?;
+
+        while !self.eat(&Token::RightBrace) {
+            let line = self.line();
+            let label = self.expect_ident()?;
+            let block = context.block(&label, line);
+            self.parse_block(&mut context, block, &label)?;
+        }
+
+        if let Some((label, line)) =
+            context.undefined_blocks.into_iter().min_by_key(|(_, line)| *line)
+        {
+            return Err(SsaParseError::new(line, format!("block '{label}' is never defined")));
+        }
+        Ok(context.function)
+    }
+
+    fn parse_function_id(&mut self) -> Result<FunctionId, SsaParseError> {
+        let ident = self.expect_ident()?;
+        match parse_id(&ident, 'f') {
+            Some(index) => Ok(Id::test_new(index)),
+            None => self.error(format!("expected a function id, found '{ident}'")),
+        }
+    }
+
+    /// Parses a block, after its label: `(<parameters>): <instructions> <terminator>`
+    fn parse_block(
+        &mut self,
+        context: &mut FunctionContext,
+        block: BasicBlockId,
+        label: &str,
+    ) -> Result<(), SsaParseError> {
+        if context.undefined_blocks.remove(label).is_none()
+            && block != context.function.entry_block()
+        {
+            return self.error(format!("block '{label}' is defined more than once"));
+        }
+
+        self.expect(Token::LeftParen)?;
+        if !self.eat(&Token::RightParen) {
+            loop {
+                let line = self.line();
+                let name = self.expect_ident()?;
+                self.expect(Token::Colon)?;
+                let typ = self.parse_type()?;
+                let parameter = context.function.dfg.add_block_parameter(block, typ);
+                context.define_value(name, parameter, line)?;
+                if self.eat(&Token::RightParen) {
+                    break;
+                }
+                self.expect(Token::Comma)?;
+            }
+        }
+        self.expect(Token::Colon)?;
+
+        loop {
+            if let Some(terminator) = self.parse_terminator(context)? {
+                context.function.dfg.set_block_terminator(block, terminator);
+                return Ok(());
+            }
+            self.parse_instruction(context, block)?;
+        }
+    }
+
+    fn parse_terminator(
+        &mut self,
+        context: &mut FunctionContext,
+    ) -> Result<Option<TerminatorInstruction>, SsaParseError> {
+        let Some(Token::Ident(keyword)) = self.peek() else { return Ok(None) };
+        let line = self.line();
+        let terminator = match keyword.as_str() {
+            "jmp" => {
+                self.position += 1;
+                let destination = self.expect_ident()?;
+                let destination = context.block(&destination, line);
+                self.expect(Token::LeftParen)?;
+                let arguments = self.parse_values_until(context, &Token::RightParen)?;
+                TerminatorInstruction::Jmp {
+                    destination,
+                    arguments,
+                    call_stack: Default::default(),
+                }
+            }
+            "jmpif" => {
+                self.position += 1;
+                let condition = self.parse_value(context)?;
+                self.expect_keyword("then")?;
+                self.expect(Token::Colon)?;
+                let then_destination = self.expect_ident()?;
+                let then_destination = context.block(&then_destination, line);
+                self.expect(Token::Comma)?;
+                self.expect_keyword("else")?;
+                self.expect(Token::Colon)?;
+                let else_destination = self.expect_ident()?;
+                let else_destination = context.block(&else_destination, line);
+                TerminatorInstruction::JmpIf { condition, then_destination, else_destination }
+            }
+            "return" => {
+                self.position += 1;
+                // The return values are on the same line as the `return` itself.
+                let mut return_values = Vec::new();
+                if self.tokens.get(self.position).map(|token| token.line) == Some(line) {
+                    return_values.push(self.parse_value(context)?);
+                    while self.eat(&Token::Comma) {
+                        return_values.push(self.parse_value(context)?);
+                    }
+                }
+                TerminatorInstruction::Return { return_values, call_stack: Default::default() }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(terminator))
+    }
+
+    fn parse_instruction(
+        &mut self,
+        context: &mut FunctionContext,
+        block: BasicBlockId,
+    ) -> Result<(), SsaParseError> {
+        let line = self.line();
+
+        // Instructions with results are written `v2, v3 = <instruction>`
+        let mut result_names = Vec::new();
+        if matches!(self.peek_nth(1), Some(Token::Comma | Token::Assign)) {
+            loop {
+                result_names.push(self.expect_ident()?);
+                if self.eat(&Token::Assign) {
+                    break;
+                }
+                self.expect(Token::Comma)?;
+            }
+        }
+
+        let keyword = self.expect_ident()?;
+        let mut result_types = None;
+        let instruction = match keyword.as_str() {
+            "cast" => {
+                let value = self.parse_value(context)?;
+                self.expect_keyword("as")?;
+                Instruction::Cast(value, self.parse_type()?)
+            }
+            "not" => Instruction::Not(self.parse_value(context)?),
+            "truncate" => {
+                let value = self.parse_value(context)?;
+                self.expect_keyword("to")?;
+                let bit_size = self.expect_u32()?;
+                self.expect_keyword("bits")?;
+                self.expect(Token::Comma)?;
+                self.expect_keyword("max_bit_size")?;
+                self.expect(Token::Colon)?;
+                let max_bit_size = self.expect_u32()?;
+                Instruction::Truncate { value, bit_size, max_bit_size }
+            }
+            "constrain" => {
+                let lhs = self.parse_value(context)?;
+                self.expect(Token::Equal)?;
+                let rhs = self.parse_value(context)?;
+                let message = match self.peek() {
+                    Some(Token::Str(_)) => match self.next()? {
+                        Token::Str(message) => Some(message),
+                        _ => unreachable!(),
+                    },
+                    _ => None,
+                };
+                Instruction::Constrain(lhs, rhs, message)
+            }
+            "call" => {
+                let func = self.parse_value(context)?;
+                self.expect(Token::LeftParen)?;
+                let arguments = self.parse_values_until(context, &Token::RightParen)?;
+                result_types = self.parse_result_types()?;
+                Instruction::Call { func, arguments }
+            }
+            "allocate" => Instruction::Allocate,
+            "load" => {
+                let address = self.parse_value(context)?;
+                result_types = self.parse_result_types()?;
+                Instruction::Load { address }
+            }
+            "store" => {
+                let value = self.parse_value(context)?;
+                self.expect_keyword("at")?;
+                let address = self.parse_value(context)?;
+                Instruction::Store { address, value }
+            }
+            "enable_side_effects" => {
+                Instruction::EnableSideEffects { condition: self.parse_value(context)? }
+            }
+            "array_get" => {
+                let array = self.parse_value(context)?;
+                self.expect(Token::Comma)?;
+                self.expect_keyword("index")?;
+                let index = self.parse_value(context)?;
+                result_types = self.parse_result_types()?;
+                Instruction::ArrayGet { array, index }
+            }
+            "array_set" => {
+                let array = self.parse_value(context)?;
+                self.expect(Token::Comma)?;
+                self.expect_keyword("index")?;
+                let index = self.parse_value(context)?;
+                self.expect(Token::Comma)?;
+                self.expect_keyword("value")?;
+                let value = self.parse_value(context)?;
+                Instruction::ArraySet { array, index, value }
+            }
+            operator => match parse_binary_op(operator) {
+                Some(operator) => {
+                    let lhs = self.parse_value(context)?;
+                    self.expect(Token::Comma)?;
+                    let rhs = self.parse_value(context)?;
+                    Instruction::binary(operator, lhs, rhs)
+                }
+                None => {
+                    return Err(SsaParseError::new(
+                        line,
+                        format!("unknown instruction '{operator}'"),
+                    ))
+                }
+            },
+        };
+
+        if instruction.requires_ctrl_typevars() && result_types.is_none() {
+            if !result_names.is_empty() {
+                return Err(SsaParseError::new(line, "expected the result types after '->'"));
+            }
+            result_types = Some(Vec::new());
+        }
+
+        let dfg = &mut context.function.dfg;
+        let instruction_id = dfg.make_instruction(instruction, result_types);
+        dfg[block].insert_instruction(instruction_id);
+
+        let results = dfg.instruction_results(instruction_id).to_vec();
+        if results.len() != result_names.len() {
+            let message =
+                format!("expected {} results, found {}", results.len(), result_names.len());
+            return Err(SsaParseError::new(line, message));
+        }
+        for (name, result) in result_names.into_iter().zip(results) {
+            context.define_value(name, result, line)?;
+        }
+        Ok(())
+    }
+
+    /// Parses the optional `-> <type>, <type>` following instructions whose result types are not
+    /// determined by their operands.
+    fn parse_result_types(&mut self) -> Result<Option<Vec<Type>>, SsaParseError> {
+        if !self.eat(&Token::Arrow) {
+            return Ok(None);
+        }
+        let mut types = vec![self.parse_type()?];
+        while self.eat(&Token::Comma) {
+            types.push(self.parse_type()?);
+        }
+        Ok(Some(types))
+    }
+
+    /// Parses a comma separated list of values, consuming the given closing token.
+    fn parse_values_until(
+        &mut self,
+        context: &mut FunctionContext,
+        closing: &Token,
+    ) -> Result<Vec<ValueId>, SsaParseError> {
+        let mut values = Vec::new();
+        if self.eat(closing) {
+            return Ok(values);
+        }
+        loop {
+            values.push(self.parse_value(context)?);
+            if self.eat(closing) {
+                return Ok(values);
+            }
+            self.expect(Token::Comma)?;
+        }
+    }
+
+    fn parse_value(&mut self, context: &mut FunctionContext) -> Result<ValueId, SsaParseError> {
+        let dfg = &mut context.function.dfg;
+        if self.peek() == Some(&Token::LeftBracket) {
+            let typ = self.parse_type()?;
+            self.expect(Token::LeftBracket)?;
+            let elements = self.parse_values_until(context, &Token::RightBracket)?;
+            return Ok(context.function.dfg.make_array(elements.into(), typ));
+        }
+
+        let ident = self.expect_ident()?;
+        if let Some(typ) = parse_numeric_type(&ident) {
+            let constant = self.expect_int()?;
+            return Ok(dfg.make_constant(constant, typ));
+        }
+        if parse_id(&ident, 'v').is_some() {
+            return match context.values.get(&ident) {
+                Some(value) => Ok(*value),
+                None => {
+                    self.position -= 1;
+                    self.error(format!("value '{ident}' is used before being defined"))
+                }
+            };
+        }
+        if let Some(index) = parse_id(&ident, 'f') {
+            return Ok(dfg.import_function(Id::test_new(index)));
+        }
+        match Intrinsic::lookup(&ident) {
+            Some(intrinsic) => Ok(dfg.import_intrinsic(intrinsic)),
+            None => Ok(dfg.import_foreign_function(&ident)),
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<Type, SsaParseError> {
+        if self.eat(&Token::LeftBracket) {
+            let mut element_types = vec![self.parse_type()?];
+            while self.eat(&Token::Comma) {
+                element_types.push(self.parse_type()?);
+            }
+            if self.eat(&Token::RightBracket) {
+                return Ok(Type::Slice(Rc::new(element_types)));
+            }
+            self.expect(Token::Semicolon)?;
+            let length = self.expect_u32()?;
+            self.expect(Token::RightBracket)?;
+            return Ok(Type::Array(Rc::new(element_types), length as usize));
+        }
+
+        let ident = self.expect_ident()?;
+        match ident.as_str() {
+            "reference" => Ok(Type::Reference),
+            "function" => Ok(Type::Function),
+            _ => match parse_numeric_type(&ident) {
+                Some(typ) => Ok(typ),
+                None => {
+                    self.position -= 1;
+                    self.error(format!("expected a type, found '{ident}'"))
+                }
+            },
+        }
+    }
+}
+
+/// Parses an id such as `v3` or `f1`, returning its index.
+fn parse_id(ident: &str, prefix: char) -> Option<usize> {
+    let digits = ident.strip_prefix(prefix)?;
+    if digits.is_empty() || !digits.chars().all(|char| char.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn parse_numeric_type(ident: &str) -> Option<Type> {
+    if ident == "Field" {
+        return Some(Type::field());
+    }
+    let bit_size = |prefix| ident.strip_prefix(prefix).and_then(|bits: &str| bits.parse().ok());
+    if let Some(bit_size) = bit_size('u') {
+        Some(Type::unsigned(bit_size))
+    } else {
+        bit_size('i').map(Type::signed)
+    }
+}
+
+fn parse_binary_op(operator: &str) -> Option<BinaryOp> {
+    let operator = match operator {
+        "add" => BinaryOp::Add,
+        "sub" => BinaryOp::Sub,
+        "mul" => BinaryOp::Mul,
+        "div" => BinaryOp::Div,
+        "mod" => BinaryOp::Mod,
+        "eq" => BinaryOp::Eq,
+        "lt" => BinaryOp::Lt,
+        "and" => BinaryOp::And,
+        "or" => BinaryOp::Or,
+        "xor" => BinaryOp::Xor,
+        _ => return None,
+    };
+    Some(operator)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::ssa::ssa_gen::Ssa;
+
+    fn assert_round_trips(src: &str) {
+        let ssa = Ssa::from_str(src).unwrap_or_else(|err| panic!("{err}"));
+        assert_eq!(ssa.to_string().trim(), src.trim());
+    }
+
+    #[test]
+    fn parses_arithmetic_and_calls() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field, v1: u32):
+    v3 = add v0, Field 2
+    v5 = mul v1, u32 2⁴×3
+    v6 = cast v5 as Field
+    v7 = not v1
+    v8 = truncate v7 to 8 bits, max_bit_size: 32
+    v9 = lt v8, v1
+    constrain v9 == u1 1 'v8 should be smaller'
+    v12 = call f1(v3, v6) -> Field
+    v16, v17 = call to_le_radix(v12, u32 2⁸, u32 2) -> [u8], u32
+    call print(v12)
+    v20 = sub v12, Field -1
+    return v20
+}
+brillig fn helper f1 {
+  b0(v0: Field, v1: Field):
+    v2 = div v0, v1
+    return v2
+}";
+        assert_round_trips(src);
+    }
+
+    #[test]
+    fn parses_memory_and_arrays() {
+        let src = "
+acir fn main f0 {
+  b0(v0: [Field; 2], v1: u32):
+    v2 = allocate
+    store v0 at v2
+    v3 = load v2 -> [Field; 2]
+    enable_side_effects u1 1
+    v5 = array_get v3, index v1 -> Field
+    v6 = array_set v3, index v1, value v5
+    v13 = array_set [Field, u8; 2] [Field 1, u8 2, Field 3, u8 4], index u32 0, value Field 1
+    return v6, v13
+}";
+        assert_round_trips(src);
+    }
+
+    #[test]
+    fn parses_control_flow() {
+        let src = "
+brillig fn main f0 {
+  b0(v0: u1):
+    jmpif v0 then: b1, else: b2
+  b1():
+    jmp b3(Field 1)
+  b3(v2: Field):
+    return v2
+  b2():
+    jmp b3(Field 2)
+}";
+        assert_round_trips(src);
+    }
+
+    #[test]
+    fn parses_folded_functions() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v2 = call f1(v0) -> Field
+    return v2
+}
+acir(fold) fn double f1 {
+  b0(v0: Field):
+    v2 = mul v0, Field 2
+    return v2
+}";
+        assert_round_trips(src);
+    }
+
+    #[test]
+    fn reports_undefined_values() {
+        let src = "
+acir fn main f0 {
+  b0():
+    v1 = add v0, Field 1
+    return v1
+}";
+        let Err(error) = Ssa::from_str(src) else { panic!("expected a parse error") };
+        assert_eq!(error.to_string(), "line 4: value 'v0' is used before being defined");
+    }
+
+    #[test]
+    fn reports_missing_result_types() {
+        let src = "
+acir fn main f0 {
+  b0(v0: reference):
+    v1 = load v0
+    return v1
+}";
+        let Err(error) = Ssa::from_str(src) else { panic!("expected a parse error") };
+        assert_eq!(error.to_string(), "line 4: expected the result types after '->'");
+    }
+}
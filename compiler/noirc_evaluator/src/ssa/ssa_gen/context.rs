@@ -12,7 +12,7 @@ use crate::errors::RuntimeError;
 use crate::ssa::function_builder::FunctionBuilder;
 use crate::ssa::ir::dfg::DataFlowGraph;
 use crate::ssa::ir::function::FunctionId as IrFunctionId;
-use crate::ssa::ir::function::{Function, RuntimeType};
+use crate::ssa::ir::function::{Function, InlineType, RuntimeType};
 use crate::ssa::ir::instruction::BinaryOp;
 use crate::ssa::ir::map::AtomicCounter;
 use crate::ssa::ir::types::{NumericType, Type};
@@ -111,6 +111,12 @@ impl<'a> FunctionContext<'a> {
             self.builder.new_brillig_function(func.name.clone(), id);
         } else {
             self.builder.new_function(func.name.clone(), id);
+            if func.should_fold {
+                self.builder.current_function.set_inline_type(InlineType::Fold);
+            }
+        }
+        if func.comptime {
+            self.builder.current_function.set_inline_type(InlineType::Comptime);
         }
         self.add_parameters_to_scope(&func.parameters);
     }
@@ -0,0 +1,549 @@
+//! This module contains a verifier which checks that an [`Ssa`] program is well-formed.
+//!
+//! It is run between optimization passes in debug builds so that a pass which produces invalid
+//! SSA is reported immediately, rather than causing a confusing failure in a later pass or
+//! during ACIR generation. The verifier checks that:
+//! - Each value is defined in a block which dominates its uses, and before them if in the same block.
+//! - Each `jmp` passes as many arguments as its destination block has parameters,
+//!   and each `jmpif` only jumps to blocks without parameters.
+//! - The operands of each binary instruction are numeric, integer operands have the same type,
+//!   and the result has the type of its operands, or is a boolean for comparisons.
+//! - The arguments and results of each call to an SSA function match that function's signature.
+//! - Once references are expected to have been removed, an ACIR `main` function contains no
+//!   `allocate`, `load` or `store` instructions.
+use std::collections::HashMap;
+
+use iter_extended::vecmap;
+use thiserror::Error;
+
+use super::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::DataFlowGraph,
+        dom::DominatorTree,
+        function::{Function, RuntimeType},
+        instruction::{BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        types::Type,
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+#[derive(Debug, Error)]
+pub(crate) enum SsaVerificationError {
+    #[error("{value} is used in {block} of {function} but is not defined in a reachable block")]
+    UndefinedValue { function: String, block: BasicBlockId, value: ValueId },
+    #[error("{value} is used in {block} of {function} before its definition in {definition}")]
+    UseBeforeDefinition {
+        function: String,
+        block: BasicBlockId,
+        value: ValueId,
+        definition: BasicBlockId,
+    },
+    #[error("jmp from {block} of {function} passes {arguments} arguments to {destination}, which has {parameters} parameters")]
+    JmpArity {
+        function: String,
+        block: BasicBlockId,
+        destination: BasicBlockId,
+        arguments: usize,
+        parameters: usize,
+    },
+    #[error(
+        "jmpif from {block} of {function} targets {destination}, which has {parameters} parameters"
+    )]
+    JmpIfArity {
+        function: String,
+        block: BasicBlockId,
+        destination: BasicBlockId,
+        parameters: usize,
+    },
+    #[error("the operands of `{operator}` in {block} of {function} have incompatible types {lhs} and {rhs}")]
+    BinaryTypeMismatch {
+        function: String,
+        block: BasicBlockId,
+        operator: BinaryOp,
+        lhs: Type,
+        rhs: Type,
+    },
+    #[error("the operand of `{operator}` in {block} of {function} has non-numeric type {typ}")]
+    NonNumericBinaryOperand { function: String, block: BasicBlockId, operator: BinaryOp, typ: Type },
+    #[error("the result of `{operator}` in {block} of {function} has type {result}, which does not match its operands of types {lhs} and {rhs}")]
+    BinaryResultTypeMismatch {
+        function: String,
+        block: BasicBlockId,
+        operator: BinaryOp,
+        lhs: Type,
+        rhs: Type,
+        result: Type,
+    },
+    #[error("call to {callee} in {block} of {function} has {kind} ({found}) which do not match its signature ({expected})")]
+    CallSignatureMismatch {
+        function: String,
+        block: BasicBlockId,
+        callee: String,
+        kind: &'static str,
+        expected: String,
+        found: String,
+    },
+    #[error("{block} of {function} calls {callee} which is not defined")]
+    UndefinedFunction { function: String, block: BasicBlockId, callee: String },
+    #[error("{block} of {function} still contains the `{instruction}` {id} after references should have been removed")]
+    RemainingReference {
+        function: String,
+        block: BasicBlockId,
+        instruction: &'static str,
+        id: InstructionId,
+    },
+}
+
+impl Ssa {
+    /// Checks that each function in the program is well-formed.
+    ///
+    /// If `references_removed` is true, this also checks that no memory instructions remain in
+    /// `main` if it is an ACIR function or in any folded ACIR function, as ACIR generation cannot
+    /// handle them.
+    pub(crate) fn verify(&self, references_removed: bool) -> Result<(), SsaVerificationError> {
+        for function in self.functions.values() {
+            FunctionVerifier::new(self, function).verify()?;
+        }
+
+        if !references_removed {
+            return Ok(());
+        }
+        let acir_functions = self.functions.values().filter(|function| {
+            (function.id() == self.main_id && function.runtime() == RuntimeType::Acir)
+                || function.is_folded_acir()
+        });
+        for function in acir_functions {
+            for block in function.reachable_blocks() {
+                for instruction in function.dfg[block].instructions() {
+                    let name = match &function.dfg[*instruction] {
+                        Instruction::Allocate => "allocate",
+                        Instruction::Load { .. } => "load",
+                        Instruction::Store { .. } => "store",
+                        _ => continue,
+                    };
+                    return Err(SsaVerificationError::RemainingReference {
+                        function: function.name().to_string(),
+                        block,
+                        instruction: name,
+                        id: *instruction,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct FunctionVerifier<'ssa> {
+    ssa: &'ssa Ssa,
+    function: &'ssa Function,
+    dom_tree: DominatorTree,
+
+    /// The block defining each block parameter and instruction result, along with the position of
+    /// its definition within the block. Parameters are defined at position 0 and the results of the
+    /// nth instruction at position n + 1, so a value may be used by any later instruction.
+    definitions: HashMap<ValueId, (BasicBlockId, usize)>,
+}
+
+impl<'ssa> FunctionVerifier<'ssa> {
+    fn new(ssa: &'ssa Ssa, function: &'ssa Function) -> Self {
+        let dfg = &function.dfg;
+        let mut definitions = HashMap::new();
+        for block in function.reachable_blocks() {
+            for parameter in dfg.block_parameters(block) {
+                definitions.insert(*parameter, (block, 0));
+            }
+            for (index, instruction) in dfg[block].instructions().iter().enumerate() {
+                for result in dfg.instruction_results(*instruction) {
+                    definitions.insert(*result, (block, index + 1));
+                }
+            }
+        }
+        let dom_tree = DominatorTree::with_function(function);
+        Self { ssa, function, dom_tree, definitions }
+    }
+
+    fn verify(mut self) -> Result<(), SsaVerificationError> {
+        let function = self.function;
+        for block in function.reachable_blocks() {
+            let instructions = function.dfg[block].instructions();
+            for (index, instruction) in instructions.iter().enumerate() {
+                let mut values = Vec::new();
+                function.dfg[*instruction].for_each_value(|value| values.push(value));
+                for value in values {
+                    self.check_dominates(block, index + 1, value)?;
+                }
+                self.check_instruction(block, *instruction)?;
+            }
+
+            // The terminator is positioned after the last instruction.
+            let terminator = function.dfg[block].unwrap_terminator();
+            let mut values = Vec::new();
+            terminator.for_each_value(|value| values.push(value));
+            for value in values {
+                self.check_dominates(block, instructions.len() + 1, value)?;
+            }
+            self.check_terminator(block, terminator)?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        self.function.name().to_string()
+    }
+
+    /// Checks that `value` is defined before a use at `position` in `block`.
+    fn check_dominates(
+        &mut self,
+        block: BasicBlockId,
+        position: usize,
+        value: ValueId,
+    ) -> Result<(), SsaVerificationError> {
+        let value = self.function.dfg.resolve(value);
+        if self.function.dfg.is_constant(value) {
+            return Ok(());
+        }
+
+        let Some((definition, definition_position)) = self.definitions.get(&value).copied() else {
+            return Err(SsaVerificationError::UndefinedValue { function: self.name(), block, value });
+        };
+        let defined_before = if definition == block {
+            definition_position < position
+        } else {
+            self.dom_tree.dominates(definition, block)
+        };
+        if defined_before {
+            Ok(())
+        } else {
+            Err(SsaVerificationError::UseBeforeDefinition {
+                function: self.name(),
+                block,
+                value,
+                definition,
+            })
+        }
+    }
+
+    fn check_instruction(
+        &self,
+        block: BasicBlockId,
+        instruction: InstructionId,
+    ) -> Result<(), SsaVerificationError> {
+        let dfg = &self.function.dfg;
+        match &dfg[instruction] {
+            Instruction::Binary(binary) => {
+                let lhs = dfg.type_of_value(binary.lhs);
+                let rhs = dfg.type_of_value(binary.rhs);
+                for typ in [&lhs, &rhs] {
+                    if !matches!(typ, Type::Numeric(_)) {
+                        return Err(SsaVerificationError::NonNumericBinaryOperand {
+                            function: self.name(),
+                            block,
+                            operator: binary.operator,
+                            typ: typ.clone(),
+                        });
+                    }
+                }
+
+                // Numeric constants are not always given the type of the value they are used with,
+                // and fields are mixed with integers when computing powers of two for shifts.
+                let has_field = lhs == Type::field() || rhs == Type::field();
+                let lhs_is_constant = dfg.is_constant(binary.lhs);
+                let rhs_is_constant = dfg.is_constant(binary.rhs);
+                if !has_field && !lhs_is_constant && !rhs_is_constant && lhs != rhs {
+                    return Err(SsaVerificationError::BinaryTypeMismatch {
+                        function: self.name(),
+                        block,
+                        operator: binary.operator,
+                        lhs,
+                        rhs,
+                    });
+                }
+
+                let result = dfg.type_of_value(dfg.instruction_results(instruction)[0]);
+                let result_matches = match binary.operator {
+                    BinaryOp::Eq | BinaryOp::Lt => result == Type::bool(),
+                    // The result takes the type of the operands, which constants need not share.
+                    _ if lhs_is_constant && rhs_is_constant => result == lhs || result == rhs,
+                    _ => {
+                        has_field
+                            || (!lhs_is_constant && result == lhs)
+                            || (!rhs_is_constant && result == rhs)
+                    }
+                };
+                if !result_matches {
+                    return Err(SsaVerificationError::BinaryResultTypeMismatch {
+                        function: self.name(),
+                        block,
+                        operator: binary.operator,
+                        lhs,
+                        rhs,
+                        result,
+                    });
+                }
+            }
+            Instruction::Call { func, arguments } => {
+                let Value::Function(callee_id) = &dfg[dfg.resolve(*func)] else {
+                    return Ok(());
+                };
+                let Some(callee) = self.ssa.functions.get(callee_id) else {
+                    let callee = callee_id.to_string();
+                    return Err(SsaVerificationError::UndefinedFunction {
+                        function: self.name(),
+                        block,
+                        callee,
+                    });
+                };
+
+                let parameters =
+                    vecmap(callee.parameters(), |value| callee.dfg.type_of_value(*value));
+                self.check_signature(block, callee, "arguments", &parameters, arguments)?;
+
+                // Functions which never return have no return values to compare against.
+                if let Some(returns) = return_values(callee) {
+                    let returns = vecmap(returns, |value| callee.dfg.type_of_value(*value));
+                    let results = dfg.instruction_results(instruction);
+                    self.check_signature(block, callee, "results", &returns, results)?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn check_signature(
+        &self,
+        block: BasicBlockId,
+        callee: &Function,
+        kind: &'static str,
+        expected: &[Type],
+        values: &[ValueId],
+    ) -> Result<(), SsaVerificationError> {
+        let dfg = &self.function.dfg;
+        let matches = expected.len() == values.len()
+            && expected.iter().zip(values).all(|(typ, value)| type_matches(dfg, typ, *value));
+        if matches {
+            return Ok(());
+        }
+
+        let join = |types: Vec<String>| types.join(", ");
+        Err(SsaVerificationError::CallSignatureMismatch {
+            function: self.name(),
+            block,
+            callee: format!("{} {}", callee.name(), callee.id()),
+            kind,
+            expected: join(vecmap(expected, ToString::to_string)),
+            found: join(vecmap(values, |value| dfg.type_of_value(*value).to_string())),
+        })
+    }
+
+    fn check_terminator(
+        &self,
+        block: BasicBlockId,
+        terminator: &TerminatorInstruction,
+    ) -> Result<(), SsaVerificationError> {
+        let dfg = &self.function.dfg;
+        match terminator {
+            TerminatorInstruction::Jmp { destination, arguments, .. } => {
+                let parameters = dfg.block_parameters(*destination).len();
+                if arguments.len() != parameters {
+                    return Err(SsaVerificationError::JmpArity {
+                        function: self.name(),
+                        block,
+                        destination: *destination,
+                        arguments: arguments.len(),
+                        parameters,
+                    });
+                }
+            }
+            TerminatorInstruction::JmpIf { then_destination, else_destination, .. } => {
+                for destination in [*then_destination, *else_destination] {
+                    let parameters = dfg.block_parameters(destination).len();
+                    if parameters != 0 {
+                        return Err(SsaVerificationError::JmpIfArity {
+                            function: self.name(),
+                            block,
+                            destination,
+                            parameters,
+                        });
+                    }
+                }
+            }
+            TerminatorInstruction::Return { .. } => (),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the values returned by the function, or `None` if it has no reachable `return`.
+///
+/// Unlike [`Function::returns`], this does not panic on functions which never return.
+fn return_values(function: &Function) -> Option<&[ValueId]> {
+    function.reachable_blocks().into_iter().find_map(|block| {
+        match function.dfg[block].terminator() {
+            Some(TerminatorInstruction::Return { return_values, .. }) => {
+                Some(return_values.as_slice())
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Numeric constants may be given any numeric type where another is expected.
+fn type_matches(dfg: &DataFlowGraph, expected: &Type, value: ValueId) -> bool {
+    let typ = dfg.type_of_value(value);
+    match (expected, &typ) {
+        (Type::Numeric(_), Type::Numeric(_)) if dfg.is_constant(value) => true,
+        _ => *expected == typ,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::SsaVerificationError;
+    use crate::ssa::ssa_gen::Ssa;
+
+    fn verify(src: &str, references_removed: bool) -> Result<(), SsaVerificationError> {
+        let ssa = Ssa::from_str(src).unwrap_or_else(|err| panic!("{err}"));
+        ssa.verify(references_removed)
+    }
+
+    #[test]
+    fn accepts_well_formed_ssa() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u32, v1: u1):
+    v2 = allocate
+    store v0 at v2
+    jmpif v1 then: b1, else: b2
+  b1():
+    v3 = load v2 -> u32
+    v4 = add v3, u32 1
+    jmp b3(v4)
+  b2():
+    jmp b3(v0)
+  b3(v5: u32):
+    v6 = call f1(v5, Field 2) -> u32
+    return v6
+}
+brillig fn double f1 {
+  b0(v0: u32, v1: Field):
+    v2 = mul v0, u32 2
+    return v2
+}";
+        verify(src, false).unwrap();
+    }
+
+    #[test]
+    fn rejects_uses_which_are_not_dominated_by_their_definition() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u32, v1: u1):
+    jmpif v1 then: b1, else: b2
+  b1():
+    v2 = add v0, u32 1
+    jmp b2()
+  b2():
+    return v2
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::UseBeforeDefinition { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_jumps_with_the_wrong_number_of_arguments() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u32):
+    jmp b1(v0)
+  b1(v1: u32, v2: u32):
+    return v1
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::JmpArity { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_binary_operands_of_different_types() {
+        let src = "
+acir fn main f0 {
+  b0(v0: u32, v1: u64):
+    v2 = add v0, v1
+    return v2
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::BinaryTypeMismatch { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_binary_results_which_do_not_match_their_operands() {
+        // The type of the constant determines the type of the result.
+        let src = "
+acir fn main f0 {
+  b0(v0: u32):
+    v1 = add u64 1, v0
+    return v1
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::BinaryResultTypeMismatch { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_non_numeric_binary_operands() {
+        let src = "
+acir fn main f0 {
+  b0(v0: [Field; 2]):
+    v1 = add v0, v0
+    return v1
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::NonNumericBinaryOperand { .. }), "{error}");
+
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v1 = allocate
+    store v0 at v1
+    v2 = eq v1, v1
+    return v2
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::NonNumericBinaryOperand { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_calls_which_do_not_match_the_callee_signature() {
+        let src = "
+acir fn main f0 {
+  b0(v0: [Field; 2]):
+    v1 = call f1(v0) -> Field
+    return v1
+}
+brillig fn first f1 {
+  b0(v0: [Field; 3]):
+    v1 = array_get v0, index u32 0 -> Field
+    return v1
+}";
+        let error = verify(src, false).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::CallSignatureMismatch { .. }), "{error}");
+    }
+
+    #[test]
+    fn rejects_references_once_they_should_have_been_removed() {
+        let src = "
+acir fn main f0 {
+  b0(v0: Field):
+    v1 = allocate
+    store v0 at v1
+    v2 = load v1 -> Field
+    return v2
+}";
+        verify(src, false).unwrap();
+        let error = verify(src, true).unwrap_err();
+        assert!(matches!(error, SsaVerificationError::RemainingReference { .. }), "{error}");
+    }
+}
@@ -225,10 +225,6 @@ impl BinaryOpKind {
         )
     }
 
-    pub fn is_valid_for_field_type(self) -> bool {
-        matches!(self, BinaryOpKind::Equal | BinaryOpKind::NotEqual)
-    }
-
     pub fn as_string(self) -> &'static str {
         match self {
             BinaryOpKind::Add => "+",
@@ -180,6 +180,23 @@ impl Context {
             .get_all_contracts(&self.def_interner)
     }
 
+    /// Returns the location of the body of each function of any crate, including methods and trait impls,
+    /// which lets a location within a function's body be mapped back to that function.
+    pub fn function_body_locations(&self) -> Vec<(Location, FuncId)> {
+        let mut locations: Vec<_> = self
+            .def_interner
+            .function_metas()
+            .filter(|(_, meta)| meta.has_body)
+            .map(|(func_id, _)| {
+                let body = self.def_interner.function(func_id);
+                (self.def_interner.expr_location(body.as_expr()), *func_id)
+            })
+            .collect();
+        // Functions are interned in a hash map, so sort them to keep the debug artifacts deterministic.
+        locations.sort_by_key(|(location, _)| (location.file, location.span.start()));
+        locations
+    }
+
     fn module(&self, module_id: def_map::ModuleId) -> &def_map::ModuleData {
         module_id.module(&self.def_maps)
     }
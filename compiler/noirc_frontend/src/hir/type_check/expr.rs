@@ -711,18 +711,6 @@ impl<'interner> TypeChecker<'interner> {
                     return self.comparator_operand_type_rules(other, binding, op, span);
                 }
 
-                if !op.kind.is_valid_for_field_type() && (other.is_bindable() || other.is_field()) {
-                    let other = other.follow_bindings();
-
-                    self.push_delayed_type_check(Box::new(move || {
-                        if other.is_field() || other.is_bindable() {
-                            Err(TypeCheckError::InvalidComparisonOnField { span })
-                        } else {
-                            Ok(())
-                        }
-                    }));
-                }
-
                 if other.try_bind_to_polymorphic_int(int).is_ok() || other == &Type::Error {
                     Ok(Bool)
                 } else {
@@ -757,13 +745,8 @@ impl<'interner> TypeChecker<'interner> {
             (Integer(..), typ) | (typ, Integer(..)) => {
                 Err(TypeCheckError::IntegerTypeMismatch { typ: typ.clone(), span })
             }
-            (FieldElement, FieldElement) => {
-                if op.kind.is_valid_for_field_type() {
-                    Ok(Bool)
-                } else {
-                    Err(TypeCheckError::FieldComparison { span })
-                }
-            }
+            // Field elements are ordered as integers in the range [0, p)
+            (FieldElement, FieldElement) => Ok(Bool),
 
             // <= and friends are technically valid for booleans, just not very useful
             (Bool, Bool) => Ok(Bool),
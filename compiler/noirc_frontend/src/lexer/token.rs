@@ -312,7 +312,8 @@ impl IntType {
             Err(_) => return Ok(None),
         };
 
-        let max_bits = FieldElement::max_num_bits() / 2;
+        // Integers wider than 128 bits cannot be multiplied in the field, even when split into limbs.
+        let max_bits = 128;
 
         if str_as_u32 > max_bits {
             return Err(LexerErrorKind::TooManyBits { span, max: max_bits, got: str_as_u32 });
@@ -483,6 +484,8 @@ impl Attribute {
                 Attribute::Function(FunctionAttribute::Oracle(name.to_string()))
             }
             ["test"] => Attribute::Function(FunctionAttribute::Test(TestScope::None)),
+            ["fold"] => Attribute::Function(FunctionAttribute::Fold),
+            ["comptime"] => Attribute::Function(FunctionAttribute::Comptime),
             ["test", name] => {
                 validate(name)?;
                 let malformed_scope =
@@ -532,6 +535,12 @@ pub enum FunctionAttribute {
     Builtin(String),
     Oracle(String),
     Test(TestScope),
+    /// The function is compiled into a separate ACIR circuit which its callers call,
+    /// rather than being inlined into them.
+    Fold,
+    /// Calls to the function are evaluated at compile time and replaced with the values they return,
+    /// so its arguments must be known at compile time.
+    Comptime,
 }
 
 impl FunctionAttribute {
@@ -553,6 +562,14 @@ impl FunctionAttribute {
         matches!(self, FunctionAttribute::Foreign(_))
     }
 
+    pub fn is_fold(&self) -> bool {
+        matches!(self, FunctionAttribute::Fold)
+    }
+
+    pub fn is_comptime(&self) -> bool {
+        matches!(self, FunctionAttribute::Comptime)
+    }
+
     pub fn is_low_level(&self) -> bool {
         matches!(self, FunctionAttribute::Foreign(_) | FunctionAttribute::Builtin(_))
     }
@@ -565,6 +582,8 @@ impl fmt::Display for FunctionAttribute {
             FunctionAttribute::Foreign(ref k) => write!(f, "#[foreign({k})]"),
             FunctionAttribute::Builtin(ref k) => write!(f, "#[builtin({k})]"),
             FunctionAttribute::Oracle(ref k) => write!(f, "#[oracle({k})]"),
+            FunctionAttribute::Fold => write!(f, "#[fold]"),
+            FunctionAttribute::Comptime => write!(f, "#[comptime]"),
         }
     }
 }
@@ -606,6 +625,8 @@ impl AsRef<str> for FunctionAttribute {
             FunctionAttribute::Builtin(string) => string,
             FunctionAttribute::Oracle(string) => string,
             FunctionAttribute::Test { .. } => "",
+            FunctionAttribute::Fold => "",
+            FunctionAttribute::Comptime => "",
         }
     }
 }
@@ -202,6 +202,10 @@ pub struct Function {
 
     pub return_type: Type,
     pub unconstrained: bool,
+    /// True if the function was marked `#[fold]`, in which case it is not inlined into its callers in ACIR.
+    pub should_fold: bool,
+    /// True if the function was marked `#[comptime]`, in which case calls to it are evaluated at compile time.
+    pub comptime: bool,
 }
 
 /// Compared to hir_def::types::Type, this monomorphized Type has:
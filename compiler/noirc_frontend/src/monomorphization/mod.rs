@@ -233,8 +233,20 @@ impl<'interner> Monomorphizer<'interner> {
         let body = self.expr(body_expr_id);
         let unconstrained = modifiers.is_unconstrained
             || matches!(modifiers.contract_function_type, Some(ContractFunctionType::Open));
+        let function_attribute = self.interner.function_attributes(&f).function.as_ref();
+        let should_fold = function_attribute.map_or(false, |attribute| attribute.is_fold());
+        let comptime = function_attribute.map_or(false, |attribute| attribute.is_comptime());
 
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            should_fold,
+            comptime,
+        };
         self.push_function(id, function);
     }
 
@@ -1132,7 +1144,16 @@ impl<'interner> Monomorphizer<'interner> {
         let name = lambda_name.to_owned();
         let unconstrained = false;
 
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            should_fold: false,
+            comptime: false,
+        };
         self.push_function(id, function);
 
         let typ =
@@ -1246,7 +1267,16 @@ impl<'interner> Monomorphizer<'interner> {
         parameters.append(&mut converted_parameters);
 
         let unconstrained = false;
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            should_fold: false,
+            comptime: false,
+        };
         self.push_function(id, function);
 
         let lambda_value =
@@ -1364,7 +1394,16 @@ impl<'interner> Monomorphizer<'interner> {
         let name = lambda_name.to_owned();
 
         let unconstrained = false;
-        let function = ast::Function { id, name, parameters, body, return_type, unconstrained };
+        let function = ast::Function {
+            id,
+            name,
+            parameters,
+            body,
+            return_type,
+            unconstrained,
+            should_fold: false,
+            comptime: false,
+        };
         self.push_function(id, function);
 
         ast::Expression::Ident(ast::Ident {
@@ -719,6 +719,12 @@ impl NodeInterner {
         self.func_meta.get(func_id).cloned()
     }
 
+    /// Returns the id and meta data of every function which has been resolved, including methods
+    /// and the methods of trait impls.
+    pub fn function_metas(&self) -> impl Iterator<Item = (&FuncId, &FuncMeta)> {
+        self.func_meta.iter()
+    }
+
     pub fn function_ident(&self, func_id: &FuncId) -> crate::Ident {
         let name = self.function_name(func_id).to_owned();
         let span = self.function_meta(func_id).name.location.span;
@@ -1091,6 +1091,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn function_body_locations_include_methods() {
+        let src = r#"
+        struct Foo { x: Field }
+
+        impl Foo {
+            fn double(self) -> Field {
+                self.x * 2
+            }
+        }
+
+        trait Tripled {
+            fn triple(self) -> Field;
+        }
+
+        impl Tripled for Foo {
+            fn triple(self) -> Field {
+                self.x * 3
+            }
+        }
+
+        fn main(x: Field) -> pub Field {
+            let foo = Foo { x };
+            foo.double() + foo.triple()
+        }
+        "#;
+        let (_program, context, _errors) = get_program(src);
+
+        let functions: Vec<_> = context
+            .function_body_locations()
+            .into_iter()
+            .map(|(location, func_id)| {
+                let body = &src[location.span.start() as usize..location.span.end() as usize];
+                (context.def_interner.function_name(&func_id), body)
+            })
+            .collect();
+        let names: Vec<_> = functions.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["double", "triple", "main"]);
+        assert!(functions[0].1.contains("self.x * 2"));
+        assert!(functions[1].1.contains("self.x * 3"));
+        assert!(functions[2].1.contains("foo.double() + foo.triple()"));
+    }
+
     fn check_rewrite(src: &str, expected: &str) {
         let (_program, context, _errors) = get_program(src);
         let main_func_id = context.def_interner.find_function("main").unwrap();
@@ -152,6 +152,8 @@ fn preprocess_program(program: CompiledProgram) -> PreprocessedProgram {
         abi: program.abi,
         noir_version: NOIR_ARTIFACT_VERSION_STRING.to_string(),
         bytecode: program.circuit,
+        functions: program.functions,
+        unconstrained_functions: program.unconstrained_functions,
     }
 }
 
@@ -165,6 +167,8 @@ fn preprocess_contract(contract: CompiledContract) -> PreprocessedContract {
             is_internal: func.is_internal,
             abi: func.abi,
             bytecode: func.bytecode,
+            functions: func.functions,
+            unconstrained_functions: func.unconstrained_functions,
         })
         .collect();
 
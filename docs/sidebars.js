@@ -89,6 +89,7 @@ const sidebars = {
         'standard_library/zeroed',
         'standard_library/black_box_fns',
         'standard_library/options',
+        'standard_library/bigint',
       ],
     },
     {
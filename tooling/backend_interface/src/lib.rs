@@ -146,8 +146,10 @@ impl BackendOpcodeSupport {
             Opcode::Arithmetic(_) => self.opcodes.contains("arithmetic"),
             Opcode::Directive(_) => self.opcodes.contains("directive"),
             Opcode::Brillig(_) => self.opcodes.contains("brillig"),
+            Opcode::BrilligCall { .. } => self.opcodes.contains("brillig_call"),
             Opcode::MemoryInit { .. } => self.opcodes.contains("memory_init"),
             Opcode::MemoryOp { .. } => self.opcodes.contains("memory_op"),
+            Opcode::Call { .. } => self.opcodes.contains("call"),
             Opcode::BlackBoxFuncCall(func) => {
                 self.black_box_functions.contains(func.get_black_box_func().name())
             }
@@ -1,12 +1,16 @@
 use acvm::acir::circuit::{Opcode, OpcodeLocation};
+use acvm::acir::{
+    circuit::{brillig::BrilligBytecode, Circuit},
+    native_types::WitnessMap,
+};
 use acvm::pwg::{
-    ACVMStatus, BrilligSolver, BrilligSolverStatus, ForeignCallWaitInfo, StepResult, ACVM,
+    ACVMStatus, AcirCallWaitInfo, BrilligSolver, BrilligSolverStatus, ExecutionLimits,
+    ForeignCallWaitInfo, StepResult, ACVM,
 };
 use acvm::BlackBoxFunctionSolver;
-use acvm::{acir::circuit::Circuit, acir::native_types::WitnessMap};
 
 use nargo::errors::ExecutionError;
-use nargo::ops::ForeignCallExecutor;
+use nargo::ops::{execute_acir_call, ForeignCallExecutor};
 use nargo::NargoError;
 
 #[derive(Debug)]
@@ -17,6 +21,10 @@ pub(super) enum DebugCommandResult {
 }
 
 pub(super) struct DebugContext<'a, B: BlackBoxFunctionSolver> {
+    blackbox_solver: &'a B,
+    /// The ACIR functions which may be called by the circuit, whose execution is stepped over.
+    functions: &'a [Circuit],
+    unconstrained_functions: &'a [BrilligBytecode],
     acvm: ACVM<'a, B>,
     brillig_solver: Option<BrilligSolver<'a, B>>,
     foreign_call_executor: ForeignCallExecutor,
@@ -27,10 +35,17 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
     pub(super) fn new(
         blackbox_solver: &'a B,
         circuit: &'a Circuit,
+        functions: &'a [Circuit],
+        unconstrained_functions: &'a [BrilligBytecode],
         initial_witness: WitnessMap,
     ) -> Self {
+        let mut acvm = ACVM::new(blackbox_solver, &circuit.opcodes, initial_witness);
+        acvm.set_unconstrained_functions(unconstrained_functions);
         Self {
-            acvm: ACVM::new(blackbox_solver, &circuit.opcodes, initial_witness),
+            blackbox_solver,
+            functions,
+            unconstrained_functions,
+            acvm,
             brillig_solver: None,
             foreign_call_executor: ForeignCallExecutor::default(),
             show_output: true,
@@ -93,9 +108,36 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
         }
     }
 
+    fn handle_acir_call(&mut self, acir_call: AcirCallWaitInfo) -> DebugCommandResult {
+        let id = acir_call.id;
+        let call_result = execute_acir_call(
+            self.blackbox_solver,
+            self.functions,
+            self.unconstrained_functions,
+            acir_call,
+            self.show_output,
+            ExecutionLimits::default(),
+        );
+        match call_result {
+            Ok(call_result) => {
+                self.acvm.resolve_pending_acir_call(call_result);
+                DebugCommandResult::Ok
+            }
+            Err(error) => DebugCommandResult::Error(NargoError::ExecutionError(
+                ExecutionError::AcirCallFailed {
+                    id,
+                    error: Box::new(error),
+                    call_stack: vec![OpcodeLocation::Acir(self.acvm.instruction_pointer())],
+                },
+            )),
+        }
+    }
+
     fn handle_acvm_status(&mut self, status: ACVMStatus) -> DebugCommandResult {
         if let ACVMStatus::RequiresForeignCall(foreign_call) = status {
             self.handle_foreign_call(foreign_call)
+        } else if let ACVMStatus::RequiresAcirCall(acir_call) = status {
+            self.handle_acir_call(acir_call)
         } else {
             match status {
                 ACVMStatus::Solved => DebugCommandResult::Done,
@@ -106,6 +148,9 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
                 ACVMStatus::RequiresForeignCall(_) => {
                     unreachable!("Unexpected pending foreign call resolution");
                 }
+                ACVMStatus::RequiresAcirCall(_) => {
+                    unreachable!("Unexpected pending ACIR call resolution");
+                }
             }
         }
     }
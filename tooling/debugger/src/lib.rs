@@ -1,8 +1,11 @@
 mod context;
 mod repl;
 
+use acvm::acir::{
+    circuit::{brillig::BrilligBytecode, Circuit},
+    native_types::WitnessMap,
+};
 use acvm::BlackBoxFunctionSolver;
-use acvm::{acir::circuit::Circuit, acir::native_types::WitnessMap};
 
 use nargo::artifacts::debug::DebugArtifact;
 use nargo::NargoError;
@@ -10,8 +13,17 @@ use nargo::NargoError;
 pub fn debug_circuit<B: BlackBoxFunctionSolver>(
     blackbox_solver: &B,
     circuit: &Circuit,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
     debug_artifact: DebugArtifact,
     initial_witness: WitnessMap,
 ) -> Result<Option<WitnessMap>, NargoError> {
-    repl::run(blackbox_solver, circuit, &debug_artifact, initial_witness)
+    repl::run(
+        blackbox_solver,
+        circuit,
+        functions,
+        unconstrained_functions,
+        &debug_artifact,
+        initial_witness,
+    )
 }
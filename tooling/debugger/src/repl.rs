@@ -1,8 +1,11 @@
 use crate::context::{DebugCommandResult, DebugContext};
 
 use acvm::acir::circuit::OpcodeLocation;
+use acvm::acir::{
+    circuit::{brillig::BrilligBytecode, Circuit},
+    native_types::WitnessMap,
+};
 use acvm::BlackBoxFunctionSolver;
-use acvm::{acir::circuit::Circuit, acir::native_types::WitnessMap};
 
 use nargo::artifacts::debug::DebugArtifact;
 use nargo::NargoError;
@@ -27,10 +30,18 @@ impl<'a, B: BlackBoxFunctionSolver> ReplDebugger<'a, B> {
     pub fn new(
         blackbox_solver: &'a B,
         circuit: &'a Circuit,
+        functions: &'a [Circuit],
+        unconstrained_functions: &'a [BrilligBytecode],
         debug_artifact: &'a DebugArtifact,
         initial_witness: WitnessMap,
     ) -> Self {
-        let context = DebugContext::new(blackbox_solver, circuit, initial_witness);
+        let context = DebugContext::new(
+            blackbox_solver,
+            circuit,
+            functions,
+            unconstrained_functions,
+            initial_witness,
+        );
         Self { context, debug_artifact, last_result: DebugCommandResult::Ok }
     }
 
@@ -183,11 +194,19 @@ fn print_dimmed_line(line_number: usize, line: &str) {
 pub fn run<B: BlackBoxFunctionSolver>(
     blackbox_solver: &B,
     circuit: &Circuit,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
     debug_artifact: &DebugArtifact,
     initial_witness: WitnessMap,
 ) -> Result<Option<WitnessMap>, NargoError> {
-    let context =
-        RefCell::new(ReplDebugger::new(blackbox_solver, circuit, debug_artifact, initial_witness));
+    let context = RefCell::new(ReplDebugger::new(
+        blackbox_solver,
+        circuit,
+        functions,
+        unconstrained_functions,
+        debug_artifact,
+        initial_witness,
+    ));
     let ref_context = &context;
 
     ref_context.borrow().show_current_vm_status();
@@ -1,5 +1,6 @@
 use std::future::{self, Future};
 
+use acvm::pwg::ExecutionLimits;
 use async_lsp::{ErrorCode, ResponseError};
 use nargo::{
     ops::{run_test, TestStatus},
@@ -70,8 +71,14 @@ fn on_test_run_request_inner(
                 )
             })?;
 
-            let test_result =
-                run_test(&state.solver, &context, test_function, false, &CompileOptions::default());
+            let test_result = run_test(
+                &state.solver,
+                &context,
+                test_function,
+                false,
+                ExecutionLimits::default(),
+                &CompileOptions::default(),
+            );
             let result = match test_result {
                 TestStatus::Pass => NargoTestRunResult {
                     id: params.id.clone(),
@@ -39,6 +39,24 @@ impl BlackBoxFunctionSolver for WrapperSolver {
     ) -> Result<acvm::FieldElement, acvm::BlackBoxResolutionError> {
         self.0.pedersen_hash(inputs, domain_separator)
     }
+
+    fn ec_add(
+        &self,
+        input1_x: &acvm::FieldElement,
+        input1_y: &acvm::FieldElement,
+        input2_x: &acvm::FieldElement,
+        input2_y: &acvm::FieldElement,
+    ) -> Result<(acvm::FieldElement, acvm::FieldElement), acvm::BlackBoxResolutionError> {
+        self.0.ec_add(input1_x, input1_y, input2_x, input2_y)
+    }
+
+    fn ec_double(
+        &self,
+        input_x: &acvm::FieldElement,
+        input_y: &acvm::FieldElement,
+    ) -> Result<(acvm::FieldElement, acvm::FieldElement), acvm::BlackBoxResolutionError> {
+        self.0.ec_double(input_x, input_y)
+    }
 }
 
 // We also have a mocked implementation of the `BlackBoxFunctionSolver` trait for use in tests
@@ -81,4 +99,22 @@ impl BlackBoxFunctionSolver for MockBackend {
     ) -> Result<acvm::FieldElement, acvm::BlackBoxResolutionError> {
         unimplemented!()
     }
+
+    fn ec_add(
+        &self,
+        _input1_x: &acvm::FieldElement,
+        _input1_y: &acvm::FieldElement,
+        _input2_x: &acvm::FieldElement,
+        _input2_y: &acvm::FieldElement,
+    ) -> Result<(acvm::FieldElement, acvm::FieldElement), acvm::BlackBoxResolutionError> {
+        unimplemented!()
+    }
+
+    fn ec_double(
+        &self,
+        _input_x: &acvm::FieldElement,
+        _input_y: &acvm::FieldElement,
+    ) -> Result<(acvm::FieldElement, acvm::FieldElement), acvm::BlackBoxResolutionError> {
+        unimplemented!()
+    }
 }
@@ -1,4 +1,4 @@
-use acvm::acir::circuit::Circuit;
+use acvm::acir::circuit::{brillig::BrilligBytecode, Circuit};
 use noirc_abi::{Abi, ContractEvent};
 use noirc_driver::ContractFunctionType;
 use serde::{Deserialize, Serialize};
@@ -41,4 +41,20 @@ pub struct PreprocessedContractFunction {
         deserialize_with = "Circuit::deserialize_circuit_base64"
     )]
     pub bytecode: Circuit,
+
+    /// The ACIR functions which are called from `bytecode` rather than being inlined into it.
+    #[serde(
+        default,
+        serialize_with = "Circuit::serialize_circuits_base64",
+        deserialize_with = "Circuit::deserialize_circuits_base64"
+    )]
+    pub functions: Vec<Circuit>,
+
+    /// The unconstrained functions which are called from ACIR.
+    #[serde(
+        default,
+        serialize_with = "BrilligBytecode::serialize_functions_base64",
+        deserialize_with = "BrilligBytecode::deserialize_functions_base64"
+    )]
+    pub unconstrained_functions: Vec<BrilligBytecode>,
 }
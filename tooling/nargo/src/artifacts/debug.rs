@@ -41,6 +41,7 @@ impl DebugArtifact {
                 DebugFile {
                     source: file_source.to_string(),
                     path: file_manager.path(file_id).to_path_buf(),
+                    functions: Vec::new(),
                 },
             );
         }
@@ -44,11 +44,14 @@ impl NargoError {
 
         match execution_error {
             ExecutionError::AssertionFailed(message, _) => Some(message),
+            ExecutionError::AcirCallFailed { error, .. } => error.user_defined_failure_message(),
             ExecutionError::SolvingError(error) => match error {
                 OpcodeResolutionError::IndexOutOfBounds { .. }
                 | OpcodeResolutionError::UnsupportedBlackBoxFunc(_)
                 | OpcodeResolutionError::OpcodeNotSolvable(_)
-                | OpcodeResolutionError::UnsatisfiedConstrain { .. } => None,
+                | OpcodeResolutionError::UnsatisfiedConstrain { .. }
+                | OpcodeResolutionError::ExecutionLimitExceeded { .. }
+                | OpcodeResolutionError::UnknownUnconstrainedFunction(_) => None,
                 OpcodeResolutionError::BrilligFunctionFailed { message, .. } => Some(message),
                 OpcodeResolutionError::BlackBoxFunctionFailed(_, reason) => Some(reason),
             },
@@ -63,6 +66,10 @@ pub enum ExecutionError {
 
     #[error(transparent)]
     SolvingError(#[from] OpcodeResolutionError),
+
+    /// Execution of a call to another ACIR function of the program failed.
+    #[error("Failed to solve call to ACIR function {id}: {error}")]
+    AcirCallFailed { id: u32, error: Box<NargoError>, call_stack: Vec<OpcodeLocation> },
 }
 
 /// Extracts the opcode locations from a nargo error.
@@ -75,6 +82,11 @@ fn extract_locations_from_error(
             call_stack,
             ..
         })
+        | ExecutionError::SolvingError(OpcodeResolutionError::ExecutionLimitExceeded {
+            call_stack,
+            ..
+        })
+        | ExecutionError::AcirCallFailed { call_stack, .. }
         | ExecutionError::AssertionFailed(_, call_stack) => Some(call_stack.clone()),
         ExecutionError::SolvingError(OpcodeResolutionError::IndexOutOfBounds {
             opcode_location: error_location,
@@ -1,27 +1,170 @@
-use acvm::pwg::{ACVMStatus, ErrorLocation, OpcodeResolutionError, ACVM};
-use acvm::BlackBoxFunctionSolver;
-use acvm::{acir::circuit::Circuit, acir::native_types::WitnessMap};
+use std::collections::BTreeMap;
+
+use acvm::acir::circuit::OpcodeLocation;
+use acvm::acir::{
+    circuit::{brillig::BrilligBytecode, Circuit},
+    native_types::WitnessMap,
+};
+use acvm::pwg::{
+    ACVMStatus, AcirCallWaitInfo, ErrorLocation, ExecutionLimits, OpcodeResolutionError,
+    ProfilingSamples, ACVM,
+};
+use acvm::{BlackBoxFunctionSolver, FieldElement};
 
 use crate::errors::ExecutionError;
 use crate::NargoError;
 
 use super::foreign_calls::ForeignCallExecutor;
 
+/// Options controlling the execution of a circuit.
+#[derive(Default)]
+pub struct ExecutionOptions<'a> {
+    /// Whether to print the output of calls to `println`.
+    pub show_output: bool,
+    /// The step and memory limits enforced while solving the circuit.
+    pub execution_limits: ExecutionLimits,
+    /// If provided, the number of times each Brillig opcode is executed is recorded in it.
+    /// Only the Brillig opcodes executed by the circuit itself are recorded, not those of the ACIR functions it calls.
+    pub profiling_samples: Option<&'a mut ProfilingSamples>,
+}
+
+/// Executes `circuit`, solving any calls it makes to the ACIR functions in `functions`
+/// and to the unconstrained functions in `unconstrained_functions`.
+///
+/// This uses the default execution limits and doesn't profile the execution.
+/// See [`execute_circuit_with_options`] to configure these.
 pub fn execute_circuit<B: BlackBoxFunctionSolver>(
     blackbox_solver: &B,
     circuit: &Circuit,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
     initial_witness: WitnessMap,
     show_output: bool,
 ) -> Result<WitnessMap, NargoError> {
-    let mut acvm = ACVM::new(blackbox_solver, &circuit.opcodes, initial_witness);
+    execute_circuit_with_options(
+        blackbox_solver,
+        circuit,
+        functions,
+        unconstrained_functions,
+        initial_witness,
+        ExecutionOptions { show_output, ..ExecutionOptions::default() },
+    )
+}
+
+/// Executes `circuit` like [`execute_circuit`] with the given [`ExecutionOptions`].
+pub fn execute_circuit_with_options<B: BlackBoxFunctionSolver>(
+    blackbox_solver: &B,
+    circuit: &Circuit,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
+    initial_witness: WitnessMap,
+    options: ExecutionOptions,
+) -> Result<WitnessMap, NargoError> {
+    let context = ExecutionContext::new(
+        blackbox_solver,
+        functions,
+        unconstrained_functions,
+        options.show_output,
+        options.execution_limits,
+        solve_sequentially,
+    );
+    execute_circuit_in_context(circuit, initial_witness, context, options.profiling_samples)
+}
+
+/// Executes `circuit` like [`execute_circuit_with_options`], solving independent opcodes concurrently.
+#[cfg(feature = "parallel")]
+pub fn execute_circuit_in_parallel<B: BlackBoxFunctionSolver + Sync>(
+    blackbox_solver: &B,
+    circuit: &Circuit,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
+    initial_witness: WitnessMap,
+    options: ExecutionOptions,
+) -> Result<WitnessMap, NargoError> {
+    let context = ExecutionContext::new(
+        blackbox_solver,
+        functions,
+        unconstrained_functions,
+        options.show_output,
+        options.execution_limits,
+        solve_in_parallel,
+    );
+    execute_circuit_in_context(circuit, initial_witness, context, options.profiling_samples)
+}
 
-    let mut foreign_call_executor = ForeignCallExecutor::default();
+fn execute_circuit_in_context<B: BlackBoxFunctionSolver>(
+    circuit: &Circuit,
+    initial_witness: WitnessMap,
+    mut context: ExecutionContext<B>,
+    profiling_samples: Option<&mut ProfilingSamples>,
+) -> Result<WitnessMap, NargoError> {
+    let mut acvm = ACVM::new(context.blackbox_solver, &circuit.opcodes, initial_witness);
+    acvm.set_unconstrained_functions(context.unconstrained_functions);
+    acvm.set_execution_limits(context.execution_limits);
+    if profiling_samples.is_some() {
+        acvm.enable_profiling();
+    }
+    solve(&mut acvm, circuit, &mut context)?;
 
+    if let Some(profiling_samples) = profiling_samples {
+        *profiling_samples = acvm.profiling_samples().clone();
+    }
+    let solved_witness = acvm.finalize();
+    Ok(solved_witness)
+}
+
+/// State shared between the execution of a circuit and of the ACIR functions which it calls.
+struct ExecutionContext<'a, B: BlackBoxFunctionSolver> {
+    blackbox_solver: &'a B,
+    functions: &'a [Circuit],
+    unconstrained_functions: &'a [BrilligBytecode],
+    show_output: bool,
+    execution_limits: ExecutionLimits,
+    foreign_call_executor: ForeignCallExecutor,
+    /// Runs the ACVM of the circuit and of each called ACIR function until it halts.
+    solve_acvm: fn(&mut ACVM<'_, B>) -> ACVMStatus,
+}
+
+impl<'a, B: BlackBoxFunctionSolver> ExecutionContext<'a, B> {
+    fn new(
+        blackbox_solver: &'a B,
+        functions: &'a [Circuit],
+        unconstrained_functions: &'a [BrilligBytecode],
+        show_output: bool,
+        execution_limits: ExecutionLimits,
+        solve_acvm: fn(&mut ACVM<'_, B>) -> ACVMStatus,
+    ) -> Self {
+        ExecutionContext {
+            blackbox_solver,
+            functions,
+            unconstrained_functions,
+            show_output,
+            execution_limits,
+            foreign_call_executor: ForeignCallExecutor::default(),
+            solve_acvm,
+        }
+    }
+}
+
+fn solve_sequentially<B: BlackBoxFunctionSolver>(acvm: &mut ACVM<'_, B>) -> ACVMStatus {
+    acvm.solve()
+}
+
+#[cfg(feature = "parallel")]
+fn solve_in_parallel<B: BlackBoxFunctionSolver + Sync>(acvm: &mut ACVM<'_, B>) -> ACVMStatus {
+    acvm.solve_parallel()
+}
+
+fn solve<B: BlackBoxFunctionSolver>(
+    acvm: &mut ACVM<B>,
+    circuit: &Circuit,
+    context: &mut ExecutionContext<B>,
+) -> Result<(), NargoError> {
     loop {
-        let solver_status = acvm.solve();
+        let solver_status = (context.solve_acvm)(acvm);
 
         match solver_status {
-            ACVMStatus::Solved => break,
+            ACVMStatus::Solved => return Ok(()),
             ACVMStatus::InProgress => {
                 unreachable!("Execution should not stop while in `InProgress` state.")
             }
@@ -30,7 +173,8 @@ pub fn execute_circuit<B: BlackBoxFunctionSolver>(
                     OpcodeResolutionError::UnsatisfiedConstrain {
                         opcode_location: ErrorLocation::Resolved(opcode_location),
                     } => Some(vec![*opcode_location]),
-                    OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. } => {
+                    OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. }
+                    | OpcodeResolutionError::ExecutionLimitExceeded { call_stack, .. } => {
                         Some(call_stack.clone())
                     }
                     _ => None,
@@ -51,12 +195,66 @@ pub fn execute_circuit<B: BlackBoxFunctionSolver>(
             }
             ACVMStatus::RequiresForeignCall(foreign_call) => {
                 let foreign_call_result =
-                    foreign_call_executor.execute(&foreign_call, show_output)?;
+                    context.foreign_call_executor.execute(&foreign_call, context.show_output)?;
                 acvm.resolve_pending_foreign_call(foreign_call_result);
             }
+            ACVMStatus::RequiresAcirCall(acir_call) => {
+                let id = acir_call.id;
+                // Failures within the called function are reported at the location of the call,
+                // as opcode locations within the function are not covered by the caller's debug info.
+                let call_result = solve_acir_call(acir_call, context).map_err(|error| {
+                    ExecutionError::AcirCallFailed {
+                        id,
+                        error: Box::new(error),
+                        call_stack: vec![OpcodeLocation::Acir(acvm.instruction_pointer())],
+                    }
+                })?;
+                acvm.resolve_pending_acir_call(call_result);
+            }
         }
     }
+}
+
+/// Executes a call to one of the ACIR functions in `functions`, returning the function's return values.
+pub fn execute_acir_call<B: BlackBoxFunctionSolver>(
+    blackbox_solver: &B,
+    functions: &[Circuit],
+    unconstrained_functions: &[BrilligBytecode],
+    acir_call: AcirCallWaitInfo,
+    show_output: bool,
+    execution_limits: ExecutionLimits,
+) -> Result<Vec<FieldElement>, NargoError> {
+    let mut context = ExecutionContext::new(
+        blackbox_solver,
+        functions,
+        unconstrained_functions,
+        show_output,
+        execution_limits,
+        solve_sequentially,
+    );
+    solve_acir_call(acir_call, &mut context)
+}
+
+/// Solves the circuit of the called ACIR function on the call's inputs, returning its return values.
+///
+/// The function's parameters and return values are taken in order of their witness indices.
+fn solve_acir_call<B: BlackBoxFunctionSolver>(
+    acir_call: AcirCallWaitInfo,
+    context: &mut ExecutionContext<B>,
+) -> Result<Vec<FieldElement>, NargoError> {
+    let functions = context.functions;
+    let function = functions
+        .get(acir_call.id as usize)
+        .unwrap_or_else(|| panic!("ICE: call to unknown ACIR function {}", acir_call.id));
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter(
+        function.private_parameters.iter().copied().zip(acir_call.inputs),
+    ));
+    let mut acvm = ACVM::new(context.blackbox_solver, &function.opcodes, initial_witness);
+    acvm.set_unconstrained_functions(context.unconstrained_functions);
+    acvm.set_execution_limits(context.execution_limits);
+    solve(&mut acvm, function, context)?;
 
     let solved_witness = acvm.finalize();
-    Ok(solved_witness)
+    Ok(function.return_values.0.iter().map(|witness| solved_witness[witness]).collect())
 }
@@ -1,9 +1,17 @@
-pub use self::execute::execute_circuit;
+#[cfg(feature = "parallel")]
+pub use self::execute::execute_circuit_in_parallel;
+pub use self::execute::{
+    execute_acir_call, execute_circuit, execute_circuit_with_options, ExecutionOptions,
+};
 pub use self::foreign_calls::ForeignCallExecutor;
 pub use self::optimize::{optimize_contract, optimize_program};
+pub use self::profile::{
+    profile_brillig_execution, profile_circuit, CircuitProfile, FunctionCost, LineCost,
+};
 pub use self::test::{run_test, TestStatus};
 
 mod execute;
 mod foreign_calls;
 mod optimize;
+mod profile;
 mod test;
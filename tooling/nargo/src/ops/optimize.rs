@@ -1,5 +1,8 @@
-use acvm::{acir::circuit::Opcode, Language};
-use iter_extended::try_vecmap;
+use acvm::{
+    acir::circuit::{brillig::BrilligBytecode, Circuit, Opcode},
+    Language,
+};
+use iter_extended::{try_vecmap, vecmap};
 use noirc_driver::{CompiledContract, CompiledProgram};
 
 use crate::NargoError;
@@ -9,11 +12,24 @@ pub fn optimize_program(
     np_language: Language,
     is_opcode_supported: &impl Fn(&Opcode) -> bool,
 ) -> Result<CompiledProgram, NargoError> {
+    if !supports_brillig_calls(is_opcode_supported) {
+        let unconstrained_functions = std::mem::take(&mut program.unconstrained_functions);
+        (program.circuit, program.functions) =
+            inline_brillig_calls(program.circuit, program.functions, &unconstrained_functions);
+    }
+
     let (optimized_circuit, location_map) =
         acvm::compiler::compile(program.circuit, np_language, is_opcode_supported)?;
 
     program.circuit = optimized_circuit;
     program.debug.update_acir(location_map);
+
+    // Opcode locations within the called functions are not covered by the debug info.
+    program.functions = try_vecmap(program.functions, |function| {
+        let (optimized_function, _) =
+            acvm::compiler::compile(function, np_language, is_opcode_supported)?;
+        Ok::<_, NargoError>(optimized_function)
+    })?;
     Ok(program)
 }
 
@@ -23,12 +39,45 @@ pub fn optimize_contract(
     is_opcode_supported: &impl Fn(&Opcode) -> bool,
 ) -> Result<CompiledContract, NargoError> {
     let functions = try_vecmap(contract.functions, |mut func| {
+        if !supports_brillig_calls(is_opcode_supported) {
+            let unconstrained_functions = std::mem::take(&mut func.unconstrained_functions);
+            (func.bytecode, func.functions) =
+                inline_brillig_calls(func.bytecode, func.functions, &unconstrained_functions);
+        }
+
         let (optimized_bytecode, location_map) =
             acvm::compiler::compile(func.bytecode, np_language, is_opcode_supported)?;
         func.bytecode = optimized_bytecode;
         func.debug.update_acir(location_map);
+        func.functions = try_vecmap(func.functions, |function| {
+            let (optimized_function, _) =
+                acvm::compiler::compile(function, np_language, is_opcode_supported)?;
+            Ok::<_, NargoError>(optimized_function)
+        })?;
         Ok::<_, NargoError>(func)
     })?;
 
     Ok(CompiledContract { functions, ..contract })
 }
+
+/// Returns whether the backend can read calls to unconstrained functions, rather than only Brillig
+/// opcodes holding their own bytecode.
+fn supports_brillig_calls(is_opcode_supported: &impl Fn(&Opcode) -> bool) -> bool {
+    let brillig_call =
+        Opcode::BrilligCall { id: 0, inputs: Vec::new(), outputs: Vec::new(), predicate: None };
+    is_opcode_supported(&brillig_call)
+}
+
+/// Replaces the calls to unconstrained functions made by a circuit and the ACIR functions it calls
+/// with Brillig opcodes holding the bytecode of the called function.
+fn inline_brillig_calls(
+    circuit: Circuit,
+    functions: Vec<Circuit>,
+    unconstrained_functions: &[BrilligBytecode],
+) -> (Circuit, Vec<Circuit>) {
+    let circuit = acvm::compiler::inline_brillig_calls(circuit, unconstrained_functions);
+    let functions = vecmap(functions, |function| {
+        acvm::compiler::inline_brillig_calls(function, unconstrained_functions)
+    });
+    (circuit, functions)
+}
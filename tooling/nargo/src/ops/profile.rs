@@ -0,0 +1,240 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use acvm::acir::brillig::Opcode as BrilligOpcode;
+use acvm::acir::circuit::{brillig::BrilligBytecode, Circuit, Opcode, OpcodeLocation};
+use acvm::pwg::ProfilingSamples;
+use codespan_reporting::files::Files;
+use noirc_errors::{debug_info::DebugInfo, Location};
+use serde::Serialize;
+
+use crate::artifacts::debug::DebugArtifact;
+
+/// Name used for opcodes which could not be attributed to any source code.
+const UNKNOWN_FRAME: &str = "<unknown>";
+
+/// A breakdown of the opcodes within a circuit, or of the Brillig opcodes executed while solving it,
+/// grouped by the kind of opcode and attributed to the source code which generated them.
+#[derive(Debug, Default, Serialize)]
+pub struct CircuitProfile {
+    /// Total number of opcodes of each kind.
+    pub opcodes: BTreeMap<String, usize>,
+    /// Opcodes generated directly by each function, excluding those generated by its callees.
+    pub functions: Vec<FunctionCost>,
+    /// Opcodes generated by each source line.
+    pub lines: Vec<LineCost>,
+    /// Opcode counts keyed by their semicolon-separated call stack, ending in the opcode kind.
+    ///
+    /// This is the "collapsed stack" format consumed by flamegraph tools.
+    pub stacks: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunctionCost {
+    pub function: String,
+    pub total: usize,
+    pub opcodes: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LineCost {
+    pub file: String,
+    pub line: usize,
+    pub function: String,
+    pub source: String,
+    pub total: usize,
+    pub opcodes: BTreeMap<String, usize>,
+}
+
+/// A single entry in an opcode's call stack.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Frame {
+    function: String,
+    file: String,
+    line: usize,
+    source: String,
+}
+
+impl std::fmt::Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}:{})", self.function, self.file, self.line)
+    }
+}
+
+impl CircuitProfile {
+    /// Writes the profile in the collapsed stack format, with every stack rooted at `root`.
+    pub fn collapsed_stacks(&self, root: &str) -> String {
+        self.stacks.iter().map(|(stack, count)| format!("{root};{stack} {count}\n")).collect()
+    }
+}
+
+/// Counts the opcodes in `circuit` by kind, using `debug_info` to attribute each opcode
+/// to the functions and source lines responsible for it.
+///
+/// File paths are displayed relative to `root_dir` where possible.
+pub fn profile_circuit(
+    circuit: &Circuit,
+    debug_info: &DebugInfo,
+    debug_artifact: &DebugArtifact,
+    root_dir: &Path,
+) -> CircuitProfile {
+    let samples = circuit
+        .opcodes
+        .iter()
+        .enumerate()
+        .map(|(index, opcode)| (OpcodeLocation::Acir(index), opcode.name().to_string(), 1));
+
+    build_profile(samples, debug_info, debug_artifact, root_dir)
+}
+
+/// Counts the Brillig opcodes executed while solving `circuit` by kind, using `debug_info` to attribute
+/// each execution to the unconstrained functions and source lines responsible for it.
+///
+/// Foreign calls and black box operations are broken down further by the function being called.
+pub fn profile_brillig_execution(
+    circuit: &Circuit,
+    unconstrained_functions: &[BrilligBytecode],
+    profiling_samples: &ProfilingSamples,
+    debug_info: &DebugInfo,
+    debug_artifact: &DebugArtifact,
+    root_dir: &Path,
+) -> CircuitProfile {
+    let samples = profiling_samples.iter().filter_map(|(location, count)| {
+        let OpcodeLocation::Brillig { acir_index, brillig_index } = location else {
+            return None;
+        };
+        let bytecode = match circuit.opcodes.get(*acir_index)? {
+            Opcode::Brillig(brillig) => &brillig.bytecode,
+            Opcode::BrilligCall { id, .. } => &unconstrained_functions.get(*id as usize)?.bytecode,
+            _ => return None,
+        };
+        let kind = brillig_opcode_kind(bytecode.get(*brillig_index)?);
+        Some((*location, kind, *count))
+    });
+
+    build_profile(samples, debug_info, debug_artifact, root_dir)
+}
+
+fn brillig_opcode_kind(opcode: &BrilligOpcode) -> String {
+    match opcode {
+        BrilligOpcode::ForeignCall { function, .. } => format!("{}({function})", opcode.name()),
+        BrilligOpcode::BlackBox(black_box_op) => {
+            format!("{}({})", opcode.name(), black_box_op.name())
+        }
+        _ => opcode.name().to_string(),
+    }
+}
+
+/// Aggregates `samples` of opcodes, each given by its location, kind and the number of times it was seen.
+fn build_profile(
+    samples: impl Iterator<Item = (OpcodeLocation, String, usize)>,
+    debug_info: &DebugInfo,
+    debug_artifact: &DebugArtifact,
+    root_dir: &Path,
+) -> CircuitProfile {
+    let mut profile = CircuitProfile::default();
+    let mut functions: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    let mut lines: BTreeMap<Frame, BTreeMap<String, usize>> = BTreeMap::new();
+
+    for (location, kind, count) in samples {
+        *profile.opcodes.entry(kind.clone()).or_default() += count;
+
+        let call_stack = debug_info.opcode_location(&location);
+        let frames: Vec<Frame> = call_stack
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|location| resolve_frame(location, debug_artifact, root_dir))
+            .collect();
+
+        let function = frames.last().map_or(UNKNOWN_FRAME, |frame| &frame.function);
+        *functions.entry(function.to_string()).or_default().entry(kind.clone()).or_default() +=
+            count;
+
+        if let Some(frame) = frames.last() {
+            *lines.entry(frame.clone()).or_default().entry(kind.clone()).or_default() += count;
+        }
+
+        let mut stack: Vec<String> = frames.iter().map(ToString::to_string).collect();
+        if stack.is_empty() {
+            stack.push(UNKNOWN_FRAME.to_string());
+        }
+        stack.push(kind);
+        *profile.stacks.entry(stack.join(";")).or_default() += count;
+    }
+
+    profile.functions = functions
+        .into_iter()
+        .map(|(function, opcodes)| FunctionCost {
+            function,
+            total: opcodes.values().sum(),
+            opcodes,
+        })
+        .collect();
+    profile.functions.sort_by_key(|function| Reverse(function.total));
+
+    profile.lines = lines
+        .into_iter()
+        .map(|(frame, opcodes)| LineCost {
+            file: frame.file,
+            line: frame.line,
+            function: frame.function,
+            source: frame.source,
+            total: opcodes.values().sum(),
+            opcodes,
+        })
+        .collect();
+    profile.lines.sort_by_key(|line| Reverse(line.total));
+
+    profile
+}
+
+fn resolve_frame(
+    location: Location,
+    debug_artifact: &DebugArtifact,
+    root_dir: &Path,
+) -> Option<Frame> {
+    let file = debug_artifact.file_map.get(&location.file)?;
+    let line_index = debug_artifact.location_line_index(location).ok()?;
+    let line = debug_artifact.location_line_number(location).ok()?;
+    let line_range = debug_artifact.line_range(location.file, line_index).ok()?;
+    let source = file.source.get(line_range).unwrap_or_default().trim().to_string();
+    let function = file.enclosing_function(location.span).unwrap_or(UNKNOWN_FRAME).to_string();
+    let path = file.path.strip_prefix(root_dir).unwrap_or(&file.path);
+
+    Some(Frame { function, file: path.display().to_string(), line, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_driver::{DebugFile, DebugFunction};
+    use noirc_errors::Span;
+
+    #[test]
+    fn attributes_locations_to_the_enclosing_function() {
+        let source =
+            "fn main(x: Field) {\n    foo(x);\n}\n\nfn foo(y: Field) {\n    assert(y != 0);\n}\n";
+        let body_span = |name: &str| {
+            let start = source.find(&format!("fn {name}")).unwrap();
+            let start = start + source[start..].find('{').unwrap();
+            let end = start + source[start..].find('}').unwrap() + 1;
+            Span::from(start as u32..end as u32)
+        };
+        let file = DebugFile {
+            source: source.to_string(),
+            path: "main.nr".into(),
+            functions: vec![
+                DebugFunction { name: "main".to_string(), span: body_span("main") },
+                DebugFunction { name: "foo".to_string(), span: body_span("foo") },
+            ],
+        };
+        let span_of = |text: &str| {
+            let start = source.find(text).unwrap();
+            Span::from(start as u32..(start + text.len()) as u32)
+        };
+
+        assert_eq!(file.enclosing_function(span_of("foo(x)")), Some("main"));
+        assert_eq!(file.enclosing_function(span_of("assert(y != 0)")), Some("foo"));
+        assert_eq!(file.enclosing_function(span_of("fn main")), None);
+    }
+}
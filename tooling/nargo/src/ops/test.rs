@@ -1,4 +1,4 @@
-use acvm::{acir::native_types::WitnessMap, BlackBoxFunctionSolver};
+use acvm::{acir::native_types::WitnessMap, pwg::ExecutionLimits, BlackBoxFunctionSolver};
 use noirc_driver::{compile_no_check, CompileOptions};
 use noirc_errors::{debug_info::DebugInfo, FileDiagnostic};
 use noirc_evaluator::errors::RuntimeError;
@@ -6,7 +6,7 @@ use noirc_frontend::hir::{def_map::TestFunction, Context};
 
 use crate::{errors::try_to_diagnose_runtime_error, NargoError};
 
-use super::execute_circuit;
+use super::{execute_circuit_with_options, ExecutionOptions};
 
 pub enum TestStatus {
     Pass,
@@ -19,6 +19,7 @@ pub fn run_test<B: BlackBoxFunctionSolver>(
     context: &Context,
     test_function: TestFunction,
     show_output: bool,
+    execution_limits: ExecutionLimits,
     config: &CompileOptions,
 ) -> TestStatus {
     let program = compile_no_check(context, config, test_function.get_id(), None, false);
@@ -26,8 +27,14 @@ pub fn run_test<B: BlackBoxFunctionSolver>(
         Ok(program) => {
             // Run the backend to ensure the PWG evaluates functions like std::hash::pedersen,
             // otherwise constraints involving these expressions will not error.
-            let circuit_execution =
-                execute_circuit(blackbox_solver, &program.circuit, WitnessMap::new(), show_output);
+            let circuit_execution = execute_circuit_with_options(
+                blackbox_solver,
+                &program.circuit,
+                &program.functions,
+                &program.unconstrained_functions,
+                WitnessMap::new(),
+                ExecutionOptions { show_output, execution_limits, profiling_samples: None },
+            );
             test_status_program_compile_pass(test_function, program.debug, circuit_execution)
         }
         Err(err) => test_status_program_compile_fail(err, test_function),
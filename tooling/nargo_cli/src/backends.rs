@@ -9,6 +9,23 @@ fn active_backend_file_path() -> PathBuf {
 
 pub(crate) const ACVM_BACKEND_BARRETENBERG: &str = "acvm-backend-barretenberg";
 
+/// Returns the solver for the black box functions which the ACVM doesn't solve by itself.
+///
+/// These are solved natively with the `native-solver` feature, and by barretenberg's WASM build otherwise.
+#[cfg(feature = "native-solver")]
+pub(crate) fn blackbox_solver() -> barretenberg_blackbox_solver::Bn254BlackBoxSolver {
+    barretenberg_blackbox_solver::Bn254BlackBoxSolver
+}
+
+/// Returns the solver for the black box functions which the ACVM doesn't solve by itself.
+///
+/// These are solved natively with the `native-solver` feature, and by barretenberg's WASM build otherwise.
+#[cfg(not(feature = "native-solver"))]
+#[allow(deprecated)]
+pub(crate) fn blackbox_solver() -> barretenberg_blackbox_solver::BarretenbergSolver {
+    barretenberg_blackbox_solver::BarretenbergSolver::new()
+}
+
 pub(crate) fn clear_active_backend() {
     let active_backend_file = active_backend_file_path();
     if active_backend_file.is_file() {
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use acvm::acir::circuit::opcodes::BlackBoxFuncCall;
@@ -71,7 +72,7 @@ pub(crate) fn run(
         .partition(|package| package.is_binary());
 
     let (np_language, opcode_support) = backend.get_backend_info()?;
-    let (_, compiled_contracts) = compile_workspace(
+    let (_, mut compiled_contracts) = compile_workspace(
         &workspace,
         &binary_packages,
         &contract_packages,
@@ -81,13 +82,18 @@ pub(crate) fn run(
     )?;
 
     // Save build artifacts to disk.
-    for (package, contract) in contract_packages.into_iter().zip(compiled_contracts) {
+    for package in contract_packages {
+        let contract = compiled_contracts
+            .remove(&package.name)
+            .expect("Each contract package should have been compiled");
         save_contract(contract, &package, &circuit_dir);
     }
 
     Ok(())
 }
 
+/// Compiles the given packages, returning the compiled programs in the order of `binary_packages`
+/// and the compiled contracts keyed by the name of their package.
 pub(super) fn compile_workspace(
     workspace: &Workspace,
     binary_packages: &[Package],
@@ -95,7 +101,7 @@ pub(super) fn compile_workspace(
     np_language: Language,
     opcode_support: &BackendOpcodeSupport,
     compile_options: &CompileOptions,
-) -> Result<(Vec<CompiledProgram>, Vec<CompiledContract>), CliError> {
+) -> Result<(Vec<CompiledProgram>, BTreeMap<CrateName, CompiledContract>), CliError> {
     let is_opcode_supported = |opcode: &_| opcode_support.is_opcode_supported(opcode);
 
     // Compile all of the packages in parallel.
@@ -105,11 +111,13 @@ pub(super) fn compile_workspace(
             compile_program(workspace, package, compile_options, np_language, &is_opcode_supported)
         })
         .collect();
-    let contract_results: Vec<(FileManager, CompilationResult<CompiledContract>)> =
+    let contract_results: Vec<(&CrateName, (FileManager, CompilationResult<CompiledContract>))> =
         contract_packages
             .par_iter()
             .map(|package| {
-                compile_contract(package, compile_options, np_language, &is_opcode_supported)
+                let result =
+                    compile_contract(package, compile_options, np_language, &is_opcode_supported);
+                (&package.name, result)
             })
             .collect();
 
@@ -125,17 +133,18 @@ pub(super) fn compile_workspace(
             )
         })
         .collect::<Result<_, _>>()?;
-    let compiled_contracts: Vec<CompiledContract> = contract_results
+    let compiled_contracts: BTreeMap<CrateName, CompiledContract> = contract_results
         .into_iter()
-        .map(|(file_manager, compilation_result)| {
-            report_errors(
+        .map(|(package_name, (file_manager, compilation_result))| {
+            let contract = report_errors(
                 compilation_result,
                 &file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
-            )
+            )?;
+            Ok((package_name.clone(), contract))
         })
-        .collect::<Result<_, _>>()?;
+        .collect::<Result<_, CompileError>>()?;
 
     Ok((compiled_programs, compiled_contracts))
 }
@@ -184,6 +193,8 @@ fn compile_program(
         Some(CompiledProgram {
             hash: preprocessed_program.hash,
             circuit: preprocessed_program.bytecode,
+            functions: preprocessed_program.functions,
+            unconstrained_functions: preprocessed_program.unconstrained_functions,
             abi: preprocessed_program.abi,
             noir_version: preprocessed_program.noir_version,
             debug: debug_artifact.debug_symbols.remove(0),
@@ -196,10 +207,11 @@ fn compile_program(
 
     let force_recompile =
         cached_program.as_ref().map_or(false, |p| p.noir_version != NOIR_ARTIFACT_VERSION_STRING);
+    let compile_options = backend_compile_options(compile_options, is_opcode_supported);
     let (program, warnings) = match noirc_driver::compile_main(
         &mut context,
         crate_id,
-        compile_options,
+        &compile_options,
         cached_program,
         force_recompile,
     ) {
@@ -236,8 +248,9 @@ fn compile_contract(
 ) -> (FileManager, CompilationResult<CompiledContract>) {
     let (mut context, crate_id) =
         prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+    let compile_options = backend_compile_options(compile_options, is_opcode_supported);
     let (contract, warnings) =
-        match noirc_driver::compile_contract(&mut context, crate_id, compile_options) {
+        match noirc_driver::compile_contract(&mut context, crate_id, &compile_options) {
             Ok(contracts_and_warnings) => contracts_and_warnings,
             Err(errors) => {
                 return (context.file_manager, Err(errors));
@@ -251,6 +264,20 @@ fn compile_contract(
     (context.file_manager, Ok((optimized_contract, warnings)))
 }
 
+/// Returns the options to compile a package with for the backend, which requires the `#[fold]`
+/// functions to be inlined if it can't prove calls between circuits.
+fn backend_compile_options(
+    compile_options: &CompileOptions,
+    is_opcode_supported: &impl Fn(&Opcode) -> bool,
+) -> CompileOptions {
+    let acir_call =
+        Opcode::Call { id: 0, inputs: Vec::new(), outputs: Vec::new(), predicate: None };
+    CompileOptions {
+        inline_folded_functions: !is_opcode_supported(&acir_call),
+        ..compile_options.clone()
+    }
+}
+
 fn save_program(program: CompiledProgram, package: &Package, circuit_dir: &Path) {
     let preprocessed_program = PreprocessedProgram {
         hash: program.hash,
@@ -258,6 +285,8 @@ fn save_program(program: CompiledProgram, package: &Package, circuit_dir: &Path)
         abi: program.abi,
         noir_version: program.noir_version,
         bytecode: program.circuit,
+        functions: program.functions,
+        unconstrained_functions: program.unconstrained_functions,
     };
 
     save_program_to_file(&preprocessed_program, &package.name, circuit_dir);
@@ -288,6 +317,8 @@ fn save_contract(contract: CompiledContract, package: &Package, circuit_dir: &Pa
         is_internal: func.is_internal,
         abi: func.abi,
         bytecode: func.bytecode,
+        functions: func.functions,
+        unconstrained_functions: func.unconstrained_functions,
     });
 
     let preprocessed_contract = PreprocessedContract {
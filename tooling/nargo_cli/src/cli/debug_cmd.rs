@@ -13,7 +13,7 @@ use noirc_frontend::graph::CrateName;
 use super::compile_cmd::compile_bin_package;
 use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
 use super::NargoConfig;
-use crate::backends::Backend;
+use crate::backends::{blackbox_solver, Backend};
 use crate::errors::CliError;
 
 /// Executes a circuit in debug mode
@@ -104,8 +104,7 @@ pub(crate) fn debug_program(
     compiled_program: &CompiledProgram,
     inputs_map: &InputMap,
 ) -> Result<Option<WitnessMap>, CliError> {
-    #[allow(deprecated)]
-    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let blackbox_solver = blackbox_solver();
 
     let initial_witness = compiled_program.abi.encode(inputs_map, None)?;
 
@@ -118,6 +117,8 @@ pub(crate) fn debug_program(
     noir_debugger::debug_circuit(
         &blackbox_solver,
         &compiled_program.circuit,
+        &compiled_program.functions,
+        &compiled_program.unconstrained_functions,
         debug_artifact,
         initial_witness,
     )
@@ -1,20 +1,30 @@
-use acvm::acir::native_types::WitnessMap;
+use std::collections::BTreeMap;
+
+use acvm::acir::circuit::Opcode;
+use acvm::acir::native_types::{Witness, WitnessMap};
+use acvm::pwg::{ExecutionLimits, ProfilingSamples};
 use clap::Args;
 
 use nargo::artifacts::debug::DebugArtifact;
 use nargo::constants::PROVER_INPUT_FILE;
 use nargo::errors::try_to_diagnose_runtime_error;
+use nargo::ops::profile_brillig_execution;
 use nargo::package::Package;
+use nargo::NargoError;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::{Format, InputValue};
-use noirc_abi::InputMap;
+use noirc_abi::{Abi, InputMap, MAIN_RETURN_NAME};
 use noirc_driver::{CompileOptions, CompiledProgram};
 use noirc_frontend::graph::CrateName;
 
 use super::compile_cmd::compile_bin_package;
-use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
+use super::fs::{
+    inputs::read_inputs_from_file,
+    witness::{save_witness_to_dir_in_format, WitnessFormat},
+};
+use super::info_cmd::print_profile;
 use super::NargoConfig;
-use crate::backends::Backend;
+use crate::backends::{blackbox_solver, Backend};
 use crate::errors::CliError;
 
 /// Executes a circuit to calculate its return value
@@ -23,6 +33,10 @@ pub(crate) struct ExecuteCommand {
     /// Write the execution witness to named file
     witness_name: Option<String>,
 
+    /// The format in which to write the execution witness
+    #[clap(long, value_enum, default_value_t)]
+    witness_format: WitnessFormat,
+
     /// The name of the toml file which contains the inputs for the prover
     #[clap(long, short, default_value = PROVER_INPUT_FILE)]
     prover_name: String,
@@ -35,10 +49,38 @@ pub(crate) struct ExecuteCommand {
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    /// Report how many times each Brillig opcode was executed, broken down by function and source line
+    #[clap(long)]
+    profile: bool,
+
+    #[clap(flatten)]
+    execution_options: ExecutionOptions,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
 
+/// Options which bound the resources consumed while executing a circuit
+#[derive(Debug, Clone, Copy, Args)]
+pub(crate) struct ExecutionOptions {
+    /// Fail execution once this many ACIR and Brillig opcodes have been executed
+    #[clap(long, default_value_t = ExecutionLimits::DEFAULT_MAX_STEPS)]
+    max_steps: usize,
+
+    /// Fail execution if an unconstrained function uses more than this many memory slots
+    #[clap(long, default_value_t = ExecutionLimits::DEFAULT_MAX_MEMORY_SIZE)]
+    max_memory_size: usize,
+}
+
+impl From<ExecutionOptions> for ExecutionLimits {
+    fn from(options: ExecutionOptions) -> Self {
+        ExecutionLimits {
+            max_steps: Some(options.max_steps),
+            max_memory_size: Some(options.max_memory_size),
+        }
+    }
+}
+
 pub(crate) fn run(
     backend: &Backend,
     args: ExecuteCommand,
@@ -58,69 +100,123 @@ pub(crate) fn run(
             package,
             &args.compile_options,
             np_language,
-            &|opcode| opcode_support.is_opcode_supported(opcode),
+            // Calls between ACIR circuits and black box functions are solved by the ACVM, so they
+            // don't need to be inlined or replaced by fallbacks to execute the program even if the
+            // backend can't prove them.
+            &|opcode| {
+                matches!(opcode, Opcode::Call { .. } | Opcode::BlackBoxFuncCall(_))
+                    || opcode_support.is_opcode_supported(opcode)
+            },
         )?;
 
-        let (return_value, solved_witness) =
-            execute_program_and_decode(compiled_program, package, &args.prover_name)?;
+        let (return_value, solved_witness, profiling_samples) = execute_program_and_decode(
+            &compiled_program,
+            package,
+            &args.prover_name,
+            args.profile,
+            args.execution_options.into(),
+        )?;
 
         println!("[{}] Circuit witness successfully solved", package.name);
         if let Some(return_value) = return_value {
             println!("[{}] Circuit output: {return_value:?}", package.name);
         }
         if let Some(witness_name) = &args.witness_name {
-            let witness_path = save_witness_to_dir(solved_witness, witness_name, target_dir)?;
+            let witness_path = save_witness_to_dir_in_format(
+                &solved_witness,
+                &witness_labels(&compiled_program.abi),
+                witness_name,
+                target_dir,
+                args.witness_format,
+            )?;
 
             println!("[{}] Witness saved to {}", package.name, witness_path.display());
         }
+        if let Some(profiling_samples) = profiling_samples {
+            let debug_artifact = DebugArtifact {
+                debug_symbols: vec![compiled_program.debug.clone()],
+                file_map: compiled_program.file_map.clone(),
+                warnings: Vec::new(),
+            };
+            let profile = profile_brillig_execution(
+                &compiled_program.circuit,
+                &compiled_program.unconstrained_functions,
+                &profiling_samples,
+                &compiled_program.debug,
+                &debug_artifact,
+                &package.root_dir,
+            );
+            print_profile(&package.name.to_string(), "Brillig opcodes executed", &profile);
+        }
     }
     Ok(())
 }
 
+/// Names the witnesses of each ABI parameter and of the return value.
+fn witness_labels(abi: &Abi) -> BTreeMap<String, Vec<Witness>> {
+    let mut labels = abi.param_witnesses.clone();
+    if !abi.return_witnesses.is_empty() {
+        labels.insert(MAIN_RETURN_NAME.to_string(), abi.return_witnesses.clone());
+    }
+    labels
+}
+
 fn execute_program_and_decode(
-    program: CompiledProgram,
+    program: &CompiledProgram,
     package: &Package,
     prover_name: &str,
-) -> Result<(Option<InputValue>, WitnessMap), CliError> {
+    profile: bool,
+    execution_limits: ExecutionLimits,
+) -> Result<(Option<InputValue>, WitnessMap, Option<ProfilingSamples>), CliError> {
     // Parse the initial witness values from Prover.toml
     let (inputs_map, _) =
         read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &program.abi)?;
-    let solved_witness = execute_program(&program, &inputs_map)?;
-    let public_abi = program.abi.public_abi();
-    let (_, return_value) = public_abi.decode(&solved_witness)?;
+    let mut profiling_samples = profile.then(ProfilingSamples::default);
+    let solved_witness =
+        execute_program(program, &inputs_map, execution_limits, profiling_samples.as_mut())?;
+    let (_, return_value) = program.abi.decode(&solved_witness)?;
 
-    Ok((return_value, solved_witness))
+    Ok((return_value, solved_witness, profiling_samples))
 }
 
+/// Executes the program on the given inputs, recording the number of times each Brillig opcode
+/// is executed in `profiling_samples` if provided.
 pub(crate) fn execute_program(
     compiled_program: &CompiledProgram,
     inputs_map: &InputMap,
+    execution_limits: ExecutionLimits,
+    profiling_samples: Option<&mut ProfilingSamples>,
 ) -> Result<WitnessMap, CliError> {
-    #[allow(deprecated)]
-    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let blackbox_solver = blackbox_solver();
 
     let initial_witness = compiled_program.abi.encode(inputs_map, None)?;
 
-    let solved_witness_err = nargo::ops::execute_circuit(
+    #[cfg(feature = "parallel")]
+    let execute_circuit = nargo::ops::execute_circuit_in_parallel;
+    #[cfg(not(feature = "parallel"))]
+    let execute_circuit = nargo::ops::execute_circuit_with_options;
+
+    let solved_witness_err = execute_circuit(
         &blackbox_solver,
         &compiled_program.circuit,
+        &compiled_program.functions,
+        &compiled_program.unconstrained_functions,
         initial_witness,
-        true,
+        nargo::ops::ExecutionOptions { show_output: true, execution_limits, profiling_samples },
     );
-    match solved_witness_err {
-        Ok(solved_witness) => Ok(solved_witness),
-        Err(err) => {
-            let debug_artifact = DebugArtifact {
-                debug_symbols: vec![compiled_program.debug.clone()],
-                file_map: compiled_program.file_map.clone(),
-                warnings: compiled_program.warnings.clone(),
-            };
+    solved_witness_err.map_err(|err| report_execution_error(compiled_program, err))
+}
 
-            if let Some(diagnostic) = try_to_diagnose_runtime_error(&err, &compiled_program.debug) {
-                diagnostic.report(&debug_artifact, false);
-            }
+fn report_execution_error(compiled_program: &CompiledProgram, err: NargoError) -> CliError {
+    let debug_artifact = DebugArtifact {
+        debug_symbols: vec![compiled_program.debug.clone()],
+        file_map: compiled_program.file_map.clone(),
+        warnings: compiled_program.warnings.clone(),
+    };
 
-            Err(crate::errors::CliError::NargoError(err))
-        }
+    if let Some(diagnostic) = try_to_diagnose_runtime_error(&err, &compiled_program.debug) {
+        diagnostic.report(&debug_artifact, false);
     }
+
+    CliError::NargoError(err)
 }
@@ -1,20 +1,73 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use acvm::acir::native_types::WitnessMap;
+use acvm::acir::native_types::{Witness, WitnessMap, WitnessMapFormat};
+use clap::ValueEnum;
 use nargo::constants::WITNESS_EXT;
 
 use super::{create_named_dir, write_to_file};
 use crate::errors::FilesystemError;
 
+/// The file formats in which a solved witness can be saved
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum WitnessFormat {
+    /// Compressed bincode, as expected by backends
+    #[default]
+    Bin,
+    /// JSON object of hex encoded values keyed by witness index
+    Json,
+    /// TOML table of hex encoded values keyed by witness index
+    Toml,
+}
+
+impl WitnessFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            WitnessFormat::Bin => WITNESS_EXT,
+            WitnessFormat::Json => "json",
+            WitnessFormat::Toml => "toml",
+        }
+    }
+}
+
+impl From<WitnessFormat> for WitnessMapFormat {
+    fn from(format: WitnessFormat) -> Self {
+        match format {
+            WitnessFormat::Bin => WitnessMapFormat::Binary,
+            WitnessFormat::Json => WitnessMapFormat::Json,
+            WitnessFormat::Toml => WitnessMapFormat::Toml,
+        }
+    }
+}
+
 pub(crate) fn save_witness_to_dir<P: AsRef<Path>>(
     witnesses: WitnessMap,
     witness_name: &str,
     witness_dir: P,
+) -> Result<PathBuf, FilesystemError> {
+    save_witness_to_dir_in_format(
+        &witnesses,
+        &BTreeMap::new(),
+        witness_name,
+        witness_dir,
+        WitnessFormat::Bin,
+    )
+}
+
+/// Saves the witness in the given format.
+///
+/// `labels` names the witnesses belonging to each ABI parameter and are only written to the JSON and TOML formats.
+pub(crate) fn save_witness_to_dir_in_format<P: AsRef<Path>>(
+    witnesses: &WitnessMap,
+    labels: &BTreeMap<String, Vec<Witness>>,
+    witness_name: &str,
+    witness_dir: P,
+    format: WitnessFormat,
 ) -> Result<PathBuf, FilesystemError> {
     create_named_dir(witness_dir.as_ref(), "witness");
-    let witness_path = witness_dir.as_ref().join(witness_name).with_extension(WITNESS_EXT);
+    let witness_path = witness_dir.as_ref().join(witness_name).with_extension(format.extension());
 
-    let buf: Vec<u8> = witnesses.try_into()?;
+    let buf = witnesses.to_format(format.into(), labels)?;
 
     write_to_file(buf.as_slice(), &witness_path);
 
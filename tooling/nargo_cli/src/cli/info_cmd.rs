@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use acvm::Language;
 use backend_interface::BackendError;
 use clap::Args;
 use iter_extended::vecmap;
+use nargo::artifacts::debug::DebugArtifact;
+use nargo::ops::{profile_circuit, CircuitProfile};
 use nargo::package::Package;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{CompileOptions, CompiledContract, CompiledProgram};
@@ -20,6 +24,7 @@ use super::{compile_cmd::compile_workspace, NargoConfig};
 /// Current information provided:
 /// 1. The number of ACIR opcodes
 /// 2. Counts the final number gates in the circuit used by a backend
+/// 3. Optionally, a breakdown of the ACIR opcodes by kind, function and source line
 #[derive(Debug, Clone, Args)]
 pub(crate) struct InfoCommand {
     /// The name of the package to detail
@@ -34,6 +39,14 @@ pub(crate) struct InfoCommand {
     #[clap(long, hide = true)]
     json: bool,
 
+    /// Break down the ACIR opcodes by kind and by the functions and source lines which generated them
+    #[clap(long)]
+    profile: bool,
+
+    /// Print the breakdown of ACIR opcodes in the collapsed stack format used by flamegraph tools
+    #[clap(long, conflicts_with = "json")]
+    collapsed_stacks: bool,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
@@ -56,7 +69,7 @@ pub(crate) fn run(
         .partition(|package| package.is_binary());
 
     let (np_language, opcode_support) = backend.get_backend_info()?;
-    let (compiled_programs, compiled_contracts) = compile_workspace(
+    let (compiled_programs, mut compiled_contracts) = compile_workspace(
         &workspace,
         &binary_packages,
         &contract_packages,
@@ -65,22 +78,47 @@ pub(crate) fn run(
         &args.compile_options,
     )?;
 
+    let profile = args.profile || args.collapsed_stacks;
+
     let program_info = binary_packages
         .into_par_iter()
         .zip(compiled_programs)
         .map(|(package, program)| {
-            count_opcodes_and_gates_in_program(backend, program, &package, np_language)
+            count_opcodes_and_gates_in_program(backend, program, &package, np_language, profile)
         })
         .collect::<Result<_, _>>()?;
 
-    let contract_info = compiled_contracts
+    let contracts = vecmap(contract_packages, |package| {
+        let contract = compiled_contracts
+            .remove(&package.name)
+            .expect("Each contract package should have been compiled");
+        (contract, package)
+    });
+    let contract_info = contracts
         .into_par_iter()
-        .map(|contract| count_opcodes_and_gates_in_contract(backend, contract, np_language))
+        .map(|(contract, package)| {
+            count_opcodes_and_gates_in_contract(backend, contract, &package, np_language, profile)
+        })
         .collect::<Result<_, _>>()?;
 
     let info_report = InfoReport { programs: program_info, contracts: contract_info };
 
-    if args.json {
+    if args.collapsed_stacks {
+        // Expose the opcode breakdown for consumption by flamegraph tools.
+        for program in &info_report.programs {
+            if let Some(profile) = &program.profile {
+                print!("{}", profile.collapsed_stacks(&program.name));
+            }
+        }
+        for contract in &info_report.contracts {
+            for function in &contract.functions {
+                if let Some(profile) = &function.profile {
+                    let root = format!("{}::{}", contract.name, function.name);
+                    print!("{}", profile.collapsed_stacks(&root));
+                }
+            }
+        }
+    } else if args.json {
         // Expose machine-readable JSON data.
         println!("{}", serde_json::to_string(&info_report).unwrap());
     } else {
@@ -88,7 +126,7 @@ pub(crate) fn run(
         if !info_report.programs.is_empty() {
             let mut program_table = table!([Fm->"Package", Fm->"Language", Fm->"ACIR Opcodes", Fm->"Backend Circuit Size"]);
 
-            for program in info_report.programs {
+            for program in &info_report.programs {
                 program_table.add_row(program.into());
             }
             program_table.printstd();
@@ -101,7 +139,7 @@ pub(crate) fn run(
                 Fm->"ACIR Opcodes",
                 Fm->"Backend Circuit Size"
             ]);
-            for contract_info in info_report.contracts {
+            for contract_info in &info_report.contracts {
                 let contract_rows: Vec<Row> = contract_info.into();
                 for row in contract_rows {
                     contract_table.add_row(row);
@@ -110,11 +148,66 @@ pub(crate) fn run(
 
             contract_table.printstd();
         }
+
+        for program in &info_report.programs {
+            if let Some(profile) = &program.profile {
+                print_profile(&program.name, "ACIR opcodes", profile);
+            }
+        }
+        for contract in &info_report.contracts {
+            for function in &contract.functions {
+                if let Some(profile) = &function.profile {
+                    let name = format!("{}::{}", contract.name, function.name);
+                    print_profile(&name, "ACIR opcodes", profile);
+                }
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Prints tables breaking down `profile` by opcode kind, function and source line.
+///
+/// `unit` describes what is being counted, e.g. "ACIR opcodes".
+pub(super) fn print_profile(name: &str, unit: &str, profile: &CircuitProfile) {
+    println!("[{name}] {unit} by kind");
+    let mut kind_table = table!([Fm->"Opcode", Fm->"Count"]);
+    for (kind, count) in &profile.opcodes {
+        kind_table.add_row(row![kind, Fc->format!("{count}")]);
+    }
+    kind_table.printstd();
+
+    println!("[{name}] {unit} by function");
+    let mut function_table = table!([Fm->"Function", Fm->"Count", Fm->"Breakdown"]);
+    for function in &profile.functions {
+        function_table.add_row(row![
+            Fc->function.function,
+            Fc->format!("{}", function.total),
+            format_breakdown(&function.opcodes),
+        ]);
+    }
+    function_table.printstd();
+
+    println!("[{name}] {unit} by source line");
+    let mut line_table =
+        table!([Fm->"Location", Fm->"Function", Fm->"Source", Fm->"Count", Fm->"Breakdown"]);
+    for line in &profile.lines {
+        line_table.add_row(row![
+            format!("{}:{}", line.file, line.line),
+            Fc->line.function,
+            line.source,
+            Fc->format!("{}", line.total),
+            format_breakdown(&line.opcodes),
+        ]);
+    }
+    line_table.printstd();
+}
+
+fn format_breakdown(opcodes: &BTreeMap<String, usize>) -> String {
+    vecmap(opcodes, |(kind, count)| format!("{kind}: {count}")).join(", ")
+}
+
 #[derive(Debug, Default, Serialize)]
 struct InfoReport {
     programs: Vec<ProgramInfo>,
@@ -128,10 +221,12 @@ struct ProgramInfo {
     language: Language,
     acir_opcodes: usize,
     circuit_size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<CircuitProfile>,
 }
 
-impl From<ProgramInfo> for Row {
-    fn from(program_info: ProgramInfo) -> Self {
+impl From<&ProgramInfo> for Row {
+    fn from(program_info: &ProgramInfo) -> Self {
         row![
             Fm->format!("{}", program_info.name),
             format!("{:?}", program_info.language),
@@ -154,11 +249,13 @@ struct FunctionInfo {
     name: String,
     acir_opcodes: usize,
     circuit_size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<CircuitProfile>,
 }
 
-impl From<ContractInfo> for Vec<Row> {
-    fn from(contract_info: ContractInfo) -> Self {
-        vecmap(contract_info.functions, |function| {
+impl From<&ContractInfo> for Vec<Row> {
+    fn from(contract_info: &ContractInfo) -> Self {
+        vecmap(&contract_info.functions, |function| {
             row![
                 Fm->format!("{}", contract_info.name),
                 Fc->format!("{}", function.name),
@@ -175,28 +272,62 @@ fn count_opcodes_and_gates_in_program(
     compiled_program: CompiledProgram,
     package: &Package,
     language: Language,
+    profile: bool,
 ) -> Result<ProgramInfo, CliError> {
+    let profile = profile.then(|| {
+        let debug_artifact = DebugArtifact {
+            debug_symbols: vec![compiled_program.debug.clone()],
+            file_map: compiled_program.file_map.clone(),
+            warnings: Vec::new(),
+        };
+        profile_circuit(
+            &compiled_program.circuit,
+            &compiled_program.debug,
+            &debug_artifact,
+            &package.root_dir,
+        )
+    });
+
     Ok(ProgramInfo {
         name: package.name.to_string(),
         language,
         acir_opcodes: compiled_program.circuit.opcodes.len(),
         circuit_size: backend.get_exact_circuit_size(&compiled_program.circuit)?,
+        profile,
     })
 }
 
 fn count_opcodes_and_gates_in_contract(
     backend: &Backend,
     contract: CompiledContract,
+    package: &Package,
     language: Language,
+    profile: bool,
 ) -> Result<ContractInfo, CliError> {
+    let debug_artifact = DebugArtifact {
+        debug_symbols: vecmap(&contract.functions, |function| function.debug.clone()),
+        file_map: contract.file_map,
+        warnings: Vec::new(),
+    };
+
     let functions = contract
         .functions
         .into_par_iter()
         .map(|function| -> Result<_, BackendError> {
+            let profile = profile.then(|| {
+                profile_circuit(
+                    &function.bytecode,
+                    &function.debug,
+                    &debug_artifact,
+                    &package.root_dir,
+                )
+            });
+
             Ok(FunctionInfo {
                 name: function.name,
                 acir_opcodes: function.bytecode.opcodes.len(),
                 circuit_size: backend.get_exact_circuit_size(&function.bytecode)?,
+                profile,
             })
         })
         .collect::<Result<_, _>>()?;
@@ -7,7 +7,7 @@ use noir_lsp::NargoLspService;
 use tower::ServiceBuilder;
 
 use super::NargoConfig;
-use crate::backends::Backend;
+use crate::backends::{blackbox_solver, Backend};
 use crate::errors::CliError;
 
 /// Starts the Noir LSP server
@@ -30,8 +30,7 @@ pub(crate) fn run(
 
     runtime.block_on(async {
         let (server, _) = async_lsp::MainLoop::new_server(|client| {
-            #[allow(deprecated)]
-            let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+            let blackbox_solver = blackbox_solver();
             let router = NargoLspService::new(&client, blackbox_solver);
 
             ServiceBuilder::new()
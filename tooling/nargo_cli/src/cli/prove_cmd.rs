@@ -13,7 +13,11 @@ use super::fs::{
     proof::save_proof_to_dir,
 };
 use super::NargoConfig;
-use crate::{backends::Backend, cli::execute_cmd::execute_program, errors::CliError};
+use crate::{
+    backends::Backend,
+    cli::execute_cmd::{execute_program, ExecutionOptions},
+    errors::CliError,
+};
 
 /// Create proof for this program. The proof is returned as a hex encoded string.
 #[derive(Debug, Clone, Args)]
@@ -38,6 +42,9 @@ pub(crate) struct ProveCommand {
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    #[clap(flatten)]
+    execution_options: ExecutionOptions,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
@@ -71,12 +78,14 @@ pub(crate) fn run(
             &args.prover_name,
             &args.verifier_name,
             args.verify,
+            args.execution_options,
         )?;
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn prove_package(
     backend: &Backend,
     workspace: &Workspace,
@@ -85,12 +94,14 @@ pub(crate) fn prove_package(
     prover_name: &str,
     verifier_name: &str,
     check_proof: bool,
+    execution_options: ExecutionOptions,
 ) -> Result<(), CliError> {
     // Parse the initial witness values from Prover.toml
     let (inputs_map, _) =
         read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &compiled_program.abi)?;
 
-    let solved_witness = execute_program(&compiled_program, &inputs_map)?;
+    let solved_witness =
+        execute_program(&compiled_program, &inputs_map, execution_options.into(), None)?;
 
     // Write public inputs into Verifier.toml
     let public_abi = compiled_program.abi.public_abi();
@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use acvm::BlackBoxFunctionSolver;
+use acvm::{pwg::ExecutionLimits, BlackBoxFunctionSolver};
 use clap::Args;
 use nargo::{
     ops::{run_test, TestStatus},
@@ -12,7 +12,11 @@ use noirc_driver::CompileOptions;
 use noirc_frontend::{graph::CrateName, hir::FunctionNameMatch};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
-use crate::{backends::Backend, cli::check_cmd::check_crate_and_report_errors, errors::CliError};
+use crate::{
+    backends::{blackbox_solver, Backend},
+    cli::{check_cmd::check_crate_and_report_errors, execute_cmd::ExecutionOptions},
+    errors::CliError,
+};
 
 use super::NargoConfig;
 
@@ -38,6 +42,9 @@ pub(crate) struct TestCommand {
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    #[clap(flatten)]
+    execution_options: ExecutionOptions,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
@@ -64,12 +71,18 @@ pub(crate) fn run(
         None => FunctionNameMatch::Anything,
     };
 
-    #[allow(deprecated)]
-    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let blackbox_solver = blackbox_solver();
     for package in &workspace {
         // By unwrapping here with `?`, we stop the test runner upon a package failing
         // TODO: We should run the whole suite even if there are failures in a package
-        run_tests(&blackbox_solver, package, pattern, args.show_output, &args.compile_options)?;
+        run_tests(
+            &blackbox_solver,
+            package,
+            pattern,
+            args.show_output,
+            args.execution_options.into(),
+            &args.compile_options,
+        )?;
     }
 
     Ok(())
@@ -80,6 +93,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
     package: &Package,
     test_name: FunctionNameMatch,
     show_output: bool,
+    execution_limits: ExecutionLimits,
     compile_options: &CompileOptions,
 ) -> Result<(), CliError> {
     let (mut context, crate_id) =
@@ -104,7 +118,14 @@ fn run_tests<S: BlackBoxFunctionSolver>(
             .expect("Failed to write to stdout");
         writer.flush().expect("Failed to flush writer");
 
-        match run_test(blackbox_solver, &context, test_function, show_output, compile_options) {
+        match run_test(
+            blackbox_solver,
+            &context,
+            test_function,
+            show_output,
+            execution_limits,
+            compile_options,
+        ) {
             TestStatus::Pass { .. } => {
                 writer
                     .set_color(ColorSpec::new().set_fg(Some(Color::Green)))
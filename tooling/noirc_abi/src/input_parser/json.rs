@@ -1,4 +1,4 @@
-use super::{parse_str_to_field, InputValue};
+use super::{is_limb_struct, parse_str_to_field, parse_str_to_limbs, InputValue};
 use crate::{errors::InputParserError, Abi, AbiType, MAIN_RETURN_NAME};
 use acvm::FieldElement;
 use iter_extended::{try_btree_map, try_vecmap};
@@ -156,6 +156,12 @@ impl InputValue {
 
             (JsonTypes::Bool(boolean), AbiType::Boolean) => InputValue::Field(boolean.into()),
 
+            (JsonTypes::String(string), AbiType::Struct { fields, path })
+                if is_limb_struct(path, fields) =>
+            {
+                parse_str_to_limbs(&string, fields)?
+            }
+
             (JsonTypes::Array(array), AbiType::Array { typ, .. }) => {
                 let array_elements =
                     try_vecmap(array, |value| InputValue::try_from_json(value, typ, arg_name))?;
@@ -193,13 +193,17 @@ mod serialization_tests {
     }
 }
 
-fn parse_str_to_field(value: &str) -> Result<FieldElement, InputParserError> {
+fn parse_str_to_big_uint(value: &str) -> Result<BigUint, InputParserError> {
     let big_num = if let Some(hex) = value.strip_prefix("0x") {
         BigUint::from_str_radix(hex, 16)
     } else {
         BigUint::from_str_radix(value, 10)
     };
-    big_num.map_err(|err_msg| InputParserError::ParseStr(err_msg.to_string())).and_then(|bigint| {
+    big_num.map_err(|err_msg| InputParserError::ParseStr(err_msg.to_string()))
+}
+
+fn parse_str_to_field(value: &str) -> Result<FieldElement, InputParserError> {
+    parse_str_to_big_uint(value).and_then(|bigint| {
         if bigint < FieldElement::modulus() {
             Ok(field_from_big_uint(bigint))
         } else {
@@ -211,6 +215,44 @@ fn parse_str_to_field(value: &str) -> Result<FieldElement, InputParserError> {
     })
 }
 
+/// The path of the `U256` type of the standard library, as it appears in the ABI.
+const U256_STRUCT_PATH: &str = "std::uint256::U256";
+
+/// Returns whether the struct is the `std::uint256::U256` type, which can be given as a single
+/// number split into its unsigned integer limbs.
+fn is_limb_struct(path: &str, fields: &[(String, AbiType)]) -> bool {
+    path == U256_STRUCT_PATH
+        && fields
+            .iter()
+            .all(|(_, typ)| matches!(typ, AbiType::Integer { sign: crate::Sign::Unsigned, .. }))
+}
+
+/// Parses a number which may not fit in a field element into the fields of a limb struct,
+/// the first field holding the least significant limb.
+fn parse_str_to_limbs(
+    value: &str,
+    fields: &[(String, AbiType)],
+) -> Result<InputValue, InputParserError> {
+    let mut bigint = parse_str_to_big_uint(value)?;
+    let mut limbs = BTreeMap::new();
+    let mut num_bits = 0;
+    for (name, typ) in fields {
+        let AbiType::Integer { width, .. } = typ else {
+            unreachable!("limb structs only have integer fields");
+        };
+        let limb = &bigint % (BigUint::from(1_u32) << *width);
+        bigint >>= *width;
+        num_bits += width;
+        limbs.insert(name.clone(), InputValue::Field(field_from_big_uint(limb)));
+    }
+
+    if bigint.is_zero() {
+        Ok(InputValue::Struct(limbs))
+    } else {
+        Err(InputParserError::ParseStr(format!("Input does not fit within {num_bits} bits")))
+    }
+}
+
 fn parse_str_to_signed(value: &str, witdh: u32) -> Result<FieldElement, InputParserError> {
     let big_num = if let Some(hex) = value.strip_prefix("0x") {
         BigInt::from_str_radix(hex, 16)
@@ -257,7 +299,10 @@ mod test {
     use acvm::FieldElement;
     use num_bigint::BigUint;
 
-    use super::parse_str_to_field;
+    use std::collections::BTreeMap;
+
+    use super::{parse_str_to_field, parse_str_to_limbs, Format, InputValue, U256_STRUCT_PATH};
+    use crate::{Abi, AbiParameter, AbiType, AbiVisibility, Sign};
 
     fn big_uint_from_field(field: FieldElement) -> BigUint {
         BigUint::from_bytes_be(&field.to_be_bytes())
@@ -290,6 +335,51 @@ mod test {
         }
     }
 
+    #[test]
+    fn parse_limb_structs_from_strings() {
+        let limb = AbiType::Integer { sign: Sign::Unsigned, width: 128 };
+        let fields = vec![("lo".to_string(), limb.clone()), ("hi".to_string(), limb)];
+
+        // 2^255 + 1 does not fit in a field element
+        let value = format!("0x8{}1", "0".repeat(62));
+        let InputValue::Struct(limbs) = parse_str_to_limbs(&value, &fields).unwrap() else {
+            panic!("expected a struct");
+        };
+        assert_eq!(limbs["lo"], InputValue::Field(FieldElement::one()));
+        assert_eq!(limbs["hi"], InputValue::Field(FieldElement::from(1_u128 << 127)));
+
+        let too_large = format!("0x1{}", "0".repeat(64));
+        assert!(parse_str_to_limbs(&too_large, &fields).is_err());
+    }
+
+    #[test]
+    fn only_parses_u256_structs_from_strings() {
+        let struct_abi = |path: &str, limb: AbiType| Abi {
+            parameters: vec![AbiParameter {
+                name: "a".into(),
+                typ: AbiType::Struct {
+                    path: path.into(),
+                    fields: vec![("lo".into(), limb.clone()), ("hi".into(), limb)],
+                },
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+        };
+        let u128_limb = AbiType::Integer { sign: Sign::Unsigned, width: 128 };
+        let u64_limb = AbiType::Integer { sign: Sign::Unsigned, width: 64 };
+
+        let u256_abi = struct_abi(U256_STRUCT_PATH, u128_limb);
+        let inputs = Format::Toml.parse(r#"a = "0x10""#, &u256_abi).unwrap();
+        assert!(matches!(inputs["a"], InputValue::Struct(_)));
+
+        // A user struct with unsigned integer fields must still be given field by field.
+        let point_abi = struct_abi("Point", u64_limb);
+        assert!(Format::Toml.parse(r#"a = "5""#, &point_abi).is_err());
+        assert!(Format::Json.parse(r#"{ "a": "5" }"#, &point_abi).is_err());
+    }
+
     #[test]
     fn rejects_noncanonical_fields() {
         let noncanonical_field = FieldElement::modulus().to_string();
@@ -1,4 +1,6 @@
-use super::{parse_str_to_field, parse_str_to_signed, InputValue};
+use super::{
+    is_limb_struct, parse_str_to_field, parse_str_to_limbs, parse_str_to_signed, InputValue,
+};
 use crate::{errors::InputParserError, Abi, AbiType, MAIN_RETURN_NAME};
 use acvm::FieldElement;
 use iter_extended::{try_btree_map, try_vecmap};
@@ -143,6 +145,12 @@ impl InputValue {
 
             (TomlTypes::Bool(boolean), AbiType::Boolean) => InputValue::Field(boolean.into()),
 
+            (TomlTypes::String(string), AbiType::Struct { fields, path })
+                if is_limb_struct(path, fields) =>
+            {
+                parse_str_to_limbs(&string, fields)?
+            }
+
             (TomlTypes::Array(array), AbiType::Array { typ, .. }) => {
                 let array_elements =
                     try_vecmap(array, |value| InputValue::try_from_toml(value, typ, arg_name))?;